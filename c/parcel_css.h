@@ -22,6 +22,7 @@ typedef struct Targets {
 typedef struct ParseOptions {
   const char *filename;
   bool nesting;
+  bool sass_nesting;
   bool custom_media;
   bool css_modules;
   const char *css_modules_pattern;
@@ -74,6 +74,7 @@ impl_from!(SourceMapError, parcel_sourcemap::SourceMapError);
 pub struct ParseOptions {
   filename: *const c_char,
   nesting: bool,
+  sass_nesting: bool,
   custom_media: bool,
   css_modules: bool,
   css_modules_pattern: *const c_char,
@@ -185,6 +186,7 @@ impl Into<MinifyOptions> for TransformOptions {
         None
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
@@ -261,6 +263,7 @@ pub extern "C" fn parcel_css_stylesheet_parse(
       unsafe { std::str::from_utf8_unchecked(CStr::from_ptr(options.filename).to_bytes()).to_owned() }
     },
     nesting: options.nesting,
+    sass_nesting: options.sass_nesting,
     custom_media: options.custom_media,
     css_modules: if options.css_modules {
       let pattern = if !options.css_modules_pattern.is_null() {
@@ -301,7 +304,13 @@ pub extern "C" fn parcel_css_stylesheet_transform(
   error: *mut *mut CssError,
 ) -> bool {
   let wrapper = unsafe { stylesheet.as_mut() }.unwrap();
-  unwrap!(wrapper.stylesheet.minify(options.into()), error, false);
+  let warnings = Arc::new(RwLock::new(Vec::new()));
+  let options = MinifyOptions {
+    warnings: Some(warnings.clone()),
+    ..options.into()
+  };
+  unwrap!(wrapper.stylesheet.minify(options), error, false);
+  wrapper.warnings.extend(warnings.write().unwrap().drain(..).map(|w| w.into()));
   true
 }
 
@@ -335,6 +344,7 @@ pub extern "C" fn parcel_css_stylesheet_to_css(
     } else {
       None
     },
+    ..PrinterOptions::default()
   };
 
   let res = unwrap!(wrapper.stylesheet.to_css(opts), error, ToCssResult::default());
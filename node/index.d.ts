@@ -35,6 +35,16 @@ export interface TransformOptions {
    * selectors but individual names (without any . or # prefixes).
    */
   unusedSymbols?: string[],
+  /**
+   * The maximum number of decimal places to print for lengths, angles,
+   * percentages and color channels. Other numbers are not rounded.
+   */
+  precision?: number,
+  /**
+   * Whether to convert dimensions to an equivalent unit when it is shorter,
+   * e.g. `96px` to `1in`. Only applies when minifying.
+   */
+  convertUnits?: boolean,
   /**
    * Whether to ignore invalid rules and declarations rather than erroring.
    * When enabled, warnings are returned, and the invalid rule or declaration is
@@ -48,6 +58,8 @@ export type BundleOptions = Omit<TransformOptions, 'code'>;
 export interface Drafts {
   /** Whether to enable CSS nesting. */
   nesting?: boolean,
+  /** Whether to allow Sass-style nesting suffixes, e.g. `&__element`. Requires `nesting`. */
+  sassNesting?: boolean,
   /** Whether to enable @custom-media rules. */
   customMedia?: boolean
 }
@@ -71,7 +83,10 @@ export interface TransformResult {
   references: CSSModuleReferences,
   /** `@import` and `url()` dependencies, if enabled. */
   dependencies: Dependency[] | void,
-  /** Warnings that occurred during compilation. */
+  /**
+   * Warnings that occurred during compilation, including features that
+   * could not be compiled for the configured targets.
+   */
   warnings: Warning[]
 }
 
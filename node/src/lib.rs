@@ -179,6 +179,8 @@ struct Config {
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
   pub error_recovery: Option<bool>,
+  pub precision: Option<u8>,
+  pub convert_units: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -208,6 +210,8 @@ struct BundleConfig {
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
   pub error_recovery: Option<bool>,
+  pub precision: Option<u8>,
+  pub convert_units: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -238,12 +242,15 @@ struct Drafts {
   #[serde(default)]
   nesting: bool,
   #[serde(default)]
+  sass_nesting: bool,
+  #[serde(default)]
   custom_media: bool,
 }
 
 fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, CompileError<'i>> {
   let drafts = config.drafts.as_ref();
   let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+  let minify_warnings = Arc::new(RwLock::new(Vec::new()));
 
   let filename = config.filename.clone().unwrap_or_default();
   let mut source_map = if config.source_map.unwrap_or_default() {
@@ -261,6 +268,7 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
       ParserOptions {
         filename: filename.clone(),
         nesting: matches!(drafts, Some(d) if d.nesting),
+        sass_nesting: matches!(drafts, Some(d) if d.sass_nesting),
         custom_media: matches!(drafts, Some(d) if d.custom_media),
         css_modules: if let Some(css_modules) = &config.css_modules {
           match css_modules {
@@ -289,6 +297,8 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      warnings: Some(minify_warnings.clone()),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -297,6 +307,9 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies.unwrap_or_default(),
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      precision: config.precision,
+      convert_units: config.convert_units.unwrap_or_default(),
+      ..PrinterOptions::default()
     })?
   };
 
@@ -318,15 +331,19 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
     exports: res.exports,
     references: res.references,
     dependencies: res.dependencies,
-    warnings: warnings.map_or(Vec::new(), |w| {
-      Arc::try_unwrap(w)
-        .unwrap()
-        .into_inner()
-        .unwrap()
-        .into_iter()
-        .map(|w| w.into())
-        .collect()
-    }),
+    warnings: warnings
+      .map_or(Vec::new(), |w| Arc::try_unwrap(w).unwrap().into_inner().unwrap())
+      .into_iter()
+      .map(|w| w.into())
+      .chain(
+        Arc::try_unwrap(minify_warnings)
+          .unwrap()
+          .into_inner()
+          .unwrap()
+          .into_iter()
+          .map(|w| w.into()),
+      )
+      .collect(),
   })
 }
 
@@ -340,10 +357,12 @@ fn compile_bundle<'i>(
     None
   };
   let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+  let minify_warnings = Arc::new(RwLock::new(Vec::new()));
   let res = {
     let drafts = config.drafts.as_ref();
     let parser_options = ParserOptions {
       nesting: matches!(drafts, Some(d) if d.nesting),
+      sass_nesting: matches!(drafts, Some(d) if d.sass_nesting),
       custom_media: matches!(drafts, Some(d) if d.custom_media),
       css_modules: if let Some(css_modules) = &config.css_modules {
         match css_modules {
@@ -375,6 +394,8 @@ fn compile_bundle<'i>(
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      warnings: Some(minify_warnings.clone()),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -383,6 +404,9 @@ fn compile_bundle<'i>(
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies.unwrap_or_default(),
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      precision: config.precision,
+      convert_units: config.convert_units.unwrap_or_default(),
+      ..PrinterOptions::default()
     })?
   };
 
@@ -398,15 +422,19 @@ fn compile_bundle<'i>(
     exports: res.exports,
     references: res.references,
     dependencies: res.dependencies,
-    warnings: warnings.map_or(Vec::new(), |w| {
-      Arc::try_unwrap(w)
-        .unwrap()
-        .into_inner()
-        .unwrap()
-        .into_iter()
-        .map(|w| w.into())
-        .collect()
-    }),
+    warnings: warnings
+      .map_or(Vec::new(), |w| Arc::try_unwrap(w).unwrap().into_inner().unwrap())
+      .into_iter()
+      .map(|w| w.into())
+      .chain(
+        Arc::try_unwrap(minify_warnings)
+          .unwrap()
+          .into_inner()
+          .unwrap()
+          .into_iter()
+          .map(|w| w.into()),
+      )
+      .collect(),
   })
 }
 
@@ -472,6 +500,7 @@ fn compile_attr<'i>(code: &'i str, config: &AttrConfig) -> Result<AttrResult<'i>
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies,
       pseudo_classes: None,
+      ..PrinterOptions::default()
     })?
   };
   Ok(AttrResult {
@@ -611,10 +640,17 @@ impl<'i> From<CompileError<'i>> for wasm_bindgen::JsValue {
 struct Warning<'i> {
   message: String,
   #[serde(flatten)]
-  data: ParserError<'i>,
+  data: WarningKind<'i>,
   loc: Option<ErrorLocation>,
 }
 
+#[derive(Serialize)]
+#[serde(untagged)]
+enum WarningKind<'i> {
+  ParserError(ParserError<'i>),
+  MinifyError(MinifyErrorKind),
+}
+
 impl<'i> From<Error<ParserError<'i>>> for Warning<'i> {
   fn from(mut e: Error<ParserError<'i>>) -> Self {
     // Convert to 1-based line numbers.
@@ -623,7 +659,21 @@ impl<'i> From<Error<ParserError<'i>>> for Warning<'i> {
     }
     Warning {
       message: e.kind.to_string(),
-      data: e.kind,
+      data: WarningKind::ParserError(e.kind),
+      loc: e.loc,
+    }
+  }
+}
+
+impl<'i> From<Error<MinifyErrorKind>> for Warning<'i> {
+  fn from(mut e: Error<MinifyErrorKind>) -> Self {
+    // Convert to 1-based line numbers.
+    if let Some(loc) = &mut e.loc {
+      loc.line += 1;
+    }
+    Warning {
+      message: e.kind.to_string(),
+      data: WarningKind::MinifyError(e.kind),
       loc: e.loc,
     }
   }
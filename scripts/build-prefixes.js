@@ -247,7 +247,16 @@ let mdnFeatures = {
       })
   ),
   imageSet: mdn.css.types.image['image-set'].__compat.support,
-  xResolutionUnit: mdn.css.types.resolution.x.__compat.support
+  xResolutionUnit: mdn.css.types.resolution.x.__compat.support,
+  maxFunction: mdn.css.types.max.__compat.support,
+  minFunction: mdn.css.types.min.__compat.support,
+  roundFunction: mdn.css.types.round.__compat.support,
+  cascadeLayers: mdn.css['at-rules'].layer.__compat.support,
+  lightDark: mdn.css.types.color['light-dark'].__compat.support,
+  accentSystemColor: mdn.css.types.color['system-color'].accentcolor_accentcolortext.__compat.support,
+  containerQueryLengthUnits: mdn.css.types.length.container_query_length_units.__compat.support,
+  aspectRatio: mdn.css.properties['aspect-ratio'].__compat.support,
+  viewTransitions: mdn.css.properties['view-transition-name'].__compat.support
 };
 
 for (let feature in mdnFeatures) {
@@ -293,6 +302,18 @@ addValue(compat, {
   ios_saf: parseVersion('10.3')
 }, 'langList');
 
+addValue(compat, {
+  // Level 4 boolean logic, i.e. `not` and `or` within media conditions.
+  chrome: parseVersion('104'),
+  edge: parseVersion('104'),
+  firefox: parseVersion('102'),
+  opera: parseVersion('90'),
+  safari: parseVersion('16.4'),
+  ios_saf: parseVersion('16.4'),
+  samsung: parseVersion('20'),
+  android: parseVersion('104')
+}, 'mediaBooleanLogic');
+
 let prefixMapping = {
   webkit: 'WebKit',
   moz: 'Moz',
@@ -334,7 +334,7 @@ where
       | Component::Namespace(..) => {
         // Does not affect specificity
       }
-      Component::Nesting => {
+      Component::Nesting | Component::NestingSuffix(..) => {
         // TODO
       }
     }
@@ -813,7 +813,7 @@ where
       }
       true
     }),
-    Component::Nesting | Component::Has(..) => unreachable!(),
+    Component::Nesting | Component::NestingSuffix(..) | Component::Has(..) => unreachable!(),
   }
 }
 
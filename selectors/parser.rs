@@ -333,6 +333,11 @@ pub trait Parser<'i> {
   fn is_nesting_allowed(&self) -> bool {
     false
   }
+
+  /// Whether a nesting selector may be directly followed by an identifier suffix, e.g. `&__element`.
+  fn is_nesting_suffix_allowed(&self) -> bool {
+    false
+  }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -354,6 +359,9 @@ pub enum NestingRequirement {
   None,
   Prefixed,
   Contained,
+  /// A relative selector within a nested style rule. A nesting selector is inserted
+  /// at the start if the selector begins with a combinator or does not contain one.
+  Implicit,
 }
 
 impl<'i, Impl: SelectorImpl<'i>> SelectorList<'i, Impl> {
@@ -780,6 +788,14 @@ impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
     self.1.iter_mut()
   }
 
+  /// Returns the underlying sequence of simple selectors and combinators, in
+  /// matching order, for modification. Callers must ensure that the specificity
+  /// and flags of the selector are not affected by the changes they make.
+  #[inline]
+  pub fn raw_match_order_mut(&mut self) -> &mut Vec<Component<'i, Impl>> {
+    &mut self.1
+  }
+
   /// Returns the combinator at index `index` (zero-indexed from the left),
   /// or panics if the component is not a combinator.
   #[inline]
@@ -1164,6 +1180,11 @@ pub enum Component<'i, Impl: SelectorImpl<'i>> {
   ///
   /// NOTE: This is a parcel_css addition.
   Nesting,
+  /// A nesting selector followed by an identifier suffix, which is concatenated
+  /// with the parent selector, e.g. `&__element`. This is non-standard Sass syntax.
+  ///
+  /// NOTE: This is a parcel_css addition.
+  NestingSuffix(Impl::Identifier),
 }
 
 impl<'i, Impl: SelectorImpl<'i>> Component<'i, Impl> {
@@ -1633,6 +1654,10 @@ impl<'i, Impl: SelectorImpl<'i>> ToCss for Component<'i, Impl> {
       }
       NonTSPseudoClass(ref pseudo) => pseudo.to_css(dest),
       Nesting => dest.write_char('&'),
+      NestingSuffix(ref suffix) => {
+        dest.write_char('&')?;
+        suffix.to_css(dest)
+      }
     }
   }
 }
@@ -1706,6 +1731,20 @@ where
     input.reset(&state);
   }
 
+  let mut leading_combinator = None;
+  if nesting_requirement == NestingRequirement::Implicit {
+    let state = input.state();
+    leading_combinator = match input.next() {
+      Ok(Token::Delim('>')) => Some(Combinator::Child),
+      Ok(Token::Delim('+')) => Some(Combinator::NextSibling),
+      Ok(Token::Delim('~')) => Some(Combinator::LaterSibling),
+      _ => {
+        input.reset(&state);
+        None
+      }
+    };
+  }
+
   let mut builder = SelectorBuilder::default();
 
   let mut has_pseudo_element = false;
@@ -1774,7 +1813,19 @@ where
   }
 
   let (spec, components) = builder.build(has_pseudo_element, slotted, part);
-  Ok(Selector(spec, components))
+  let mut selector = Selector(spec, components);
+  if nesting_requirement == NestingRequirement::Implicit
+    && (leading_combinator.is_some() || !state.contains(SelectorParsingState::AFTER_NESTING))
+  {
+    // https://drafts.csswg.org/css-nesting/#syntax
+    // Components are stored in match order, so the leading nesting selector goes at the end.
+    selector.1.push(Component::Combinator(
+      leading_combinator.unwrap_or(Combinator::Descendant),
+    ));
+    selector.1.push(Component::Nesting);
+  }
+
+  Ok(selector)
 }
 
 impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
@@ -2238,7 +2289,20 @@ where
   let mut empty = true;
   if parser.is_nesting_allowed() && input.try_parse(|input| input.expect_delim('&')).is_ok() {
     state.insert(SelectorParsingState::AFTER_NESTING);
-    builder.push_simple_selector(Component::Nesting);
+    let suffix = if parser.is_nesting_suffix_allowed() {
+      input
+        .try_parse(|input| match input.next_including_whitespace() {
+          Ok(Token::Ident(suffix)) => Ok(suffix.clone()),
+          _ => Err(()),
+        })
+        .ok()
+    } else {
+      None
+    };
+    match suffix {
+      Some(suffix) => builder.push_simple_selector(Component::NestingSuffix(suffix.into())),
+      None => builder.push_simple_selector(Component::Nesting),
+    }
     empty = false;
   }
 
@@ -466,6 +466,12 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
             let layer = std::mem::replace(rule, CssRule::Ignored);
             dest.push(layer);
           }
+          CssRule::Comment(_) => {
+            // Preserved comments, e.g. license headers, may also appear before imports.
+            // Keep them before the rules of the imported style sheets.
+            let comment = std::mem::replace(rule, CssRule::Ignored);
+            dest.push(comment);
+          }
           CssRule::Ignored => {}
           _ => break,
         }
@@ -631,6 +637,24 @@ mod tests {
       .code
   }
 
+  fn bundle_with_targets<P: SourceProvider>(fs: P, entry: &str, targets: Browsers) -> String {
+    let mut bundler = Bundler::new(&fs, None, ParserOptions::default());
+    let mut stylesheet = bundler.bundle(Path::new(entry)).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Some(targets),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    stylesheet
+      .to_css(PrinterOptions {
+        targets: Some(targets),
+        ..PrinterOptions::default()
+      })
+      .unwrap()
+      .code
+  }
+
   fn error_test<P: SourceProvider>(fs: P, entry: &str, maybe_cb: Option<Box<dyn FnOnce(BundleErrorKind) -> ()>>) {
     let mut bundler = Bundler::new(&fs, None, ParserOptions::default());
     let res = bundler.bundle(Path::new(entry));
@@ -1270,6 +1294,46 @@ mod tests {
       })),
     );
 
+    let res = bundle_with_targets(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @layer bar, foo;
+          @import "b.css" layer(foo);
+          @import "c.css" layer(bar);
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          .b { color: green }
+        "#,
+          "/c.css": r#"
+          .c { color: blue }
+        "#
+        },
+      },
+      "/a.css",
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .b:not(#\#) {
+        color: green;
+      }
+
+      .c {
+        color: #00f;
+      }
+
+      .a:not(#\#):not(#\#) {
+        color: red;
+      }
+    "#}
+    );
+
     let res = bundle(
       TestProvider {
         map: fs! {
@@ -1393,6 +1457,37 @@ mod tests {
       })),
     );
 
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          /*! a license */
+          @import "b.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          /*! b license */
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      /*! a license */
+      /*! b license */
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
     // let res = bundle(fs! {
     //   "/a.css": r#"
     //     @import "b.css" supports(color: red) (color);
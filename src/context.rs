@@ -1,15 +1,24 @@
-use std::collections::HashSet;
-
 use crate::compat::Feature;
-use crate::declaration::DeclarationBlock;
-use crate::properties::custom::UnparsedProperty;
-use crate::properties::Property;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::logical::{to_physical_side, LogicalSide, PhysicalSide};
+use crate::media_query::{MediaCondition, MediaFeature, MediaFeatureValue, MediaList, MediaQuery, MediaType};
+use crate::properties::content::{Content, ContentItem};
+use crate::properties::custom::{Token, TokenList, TokenOrValue, UnparsedProperty};
+use crate::properties::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use crate::properties::writing_mode::{Direction, WritingMode};
+use crate::properties::{Property, PropertyId};
+use crate::rules::media::MediaRule;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
 use crate::rules::{style::StyleRule, CssRule, CssRuleList};
-use crate::selector::{Direction, PseudoClass};
+use crate::selector::{with_pseudo_element, Direction as Directionality, PseudoClass, PseudoElement};
+use crate::stylesheet::MinifyOptions;
 use crate::targets::Browsers;
+use crate::values::length::{LengthPercentage, LengthPercentageOrAuto};
+use crate::values::percentage::Percentage;
+use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 use parcel_selectors::parser::Component;
+use parcel_selectors::SelectorList;
 
 #[derive(Debug)]
 pub(crate) struct SupportsEntry<'i> {
@@ -33,20 +42,32 @@ pub(crate) struct PropertyHandlerContext<'i, 'o> {
   supports: Vec<SupportsEntry<'i>>,
   ltr: Vec<Property<'i>>,
   rtl: Vec<Property<'i>>,
+  dark: Vec<Property<'i>>,
+  aspect_ratio: Option<Ratio>,
+  writing_mode: WritingMode,
+  direction: Option<Direction>,
+  ambiguous_writing_mode: bool,
+  lowered_logical: bool,
   pub context: DeclarationContext,
-  pub unused_symbols: &'o HashSet<String>,
+  pub options: &'o MinifyOptions,
 }
 
 impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
-  pub fn new(targets: Option<Browsers>, unused_symbols: &'o HashSet<String>) -> Self {
+  pub fn new(options: &'o MinifyOptions) -> Self {
     PropertyHandlerContext {
-      targets,
+      targets: options.targets,
       is_important: false,
       supports: Vec::new(),
       ltr: Vec::new(),
       rtl: Vec::new(),
+      dark: Vec::new(),
+      aspect_ratio: None,
+      writing_mode: WritingMode::default(),
+      direction: None,
+      ambiguous_writing_mode: false,
+      lowered_logical: false,
       context: DeclarationContext::None,
-      unused_symbols,
+      options,
     }
   }
 
@@ -69,6 +90,110 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     self.rtl.push(rtl);
   }
 
+  /// Sets the writing mode and direction used to lower logical properties to the values
+  /// statically declared in the given declaration block, if any.
+  pub fn set_writing_mode(&mut self, declarations: &DeclarationBlock<'i>) {
+    self.writing_mode = WritingMode::default();
+    self.direction = None;
+    self.ambiguous_writing_mode = false;
+    self.lowered_logical = false;
+
+    // Important declarations take precedence over normal ones.
+    for property in declarations
+      .declarations
+      .iter()
+      .chain(declarations.important_declarations.iter())
+    {
+      match property {
+        Property::WritingMode(writing_mode) => {
+          self.writing_mode = *writing_mode;
+          self.ambiguous_writing_mode = false;
+        }
+        Property::Direction(direction) => self.direction = Some(*direction),
+        Property::Unparsed(unparsed) => match &unparsed.property_id {
+          // The writing mode may be anything, e.g. due to var() or CSS-wide keywords.
+          PropertyId::WritingMode => {
+            self.writing_mode = WritingMode::default();
+            self.ambiguous_writing_mode = true;
+          }
+          PropertyId::Direction => self.direction = None,
+          _ => {}
+        },
+        _ => {}
+      }
+    }
+  }
+
+  /// Resets the writing mode, and returns whether logical properties were lowered
+  /// to physical ones even though the writing mode was not statically known.
+  pub fn reset_writing_mode(&mut self) -> bool {
+    let ambiguous = self.ambiguous_writing_mode && self.lowered_logical;
+    self.writing_mode = WritingMode::default();
+    self.direction = None;
+    self.ambiguous_writing_mode = false;
+    self.lowered_logical = false;
+    ambiguous
+  }
+
+  /// Returns whether the inline axis is vertical in the current writing mode.
+  pub fn is_vertical(&mut self) -> bool {
+    self.lowered_logical = true;
+    self.writing_mode.is_vertical()
+  }
+
+  /// Returns the physical side that the given logical side maps to in the current writing mode.
+  pub fn physical_side(&mut self, side: LogicalSide, direction: Direction) -> PhysicalSide {
+    self.lowered_logical = true;
+    to_physical_side(side, self.writing_mode, direction)
+  }
+
+  /// Adds the physical equivalent of a property for the given logical side. If the side depends on
+  /// the direction and it is not statically known, rules using `:dir()` are added instead.
+  pub fn add_physical_property<F>(&mut self, dest: &mut DeclarationList<'i>, side: LogicalSide, f: F)
+  where
+    F: Fn(PhysicalSide) -> Property<'i>,
+  {
+    match self.direction {
+      Some(direction) => dest.push(f(self.physical_side(side, direction))),
+      None if !side.is_inline() => dest.push(f(self.physical_side(side, Direction::Ltr))),
+      None => {
+        let ltr = f(self.physical_side(side, Direction::Ltr));
+        let rtl = f(self.physical_side(side, Direction::Rtl));
+        self.add_logical_rule(ltr, rtl);
+      }
+    }
+  }
+
+  /// Adds the physical equivalent of a property for the corner between the given logical block and
+  /// inline sides. If the direction is not statically known, rules using `:dir()` are added instead.
+  pub fn add_physical_corner_property<F>(
+    &mut self,
+    dest: &mut DeclarationList<'i>,
+    block: LogicalSide,
+    inline: LogicalSide,
+    f: F,
+  ) where
+    F: Fn(PhysicalSide, PhysicalSide) -> Property<'i>,
+  {
+    match self.direction {
+      Some(direction) => dest.push(f(
+        self.physical_side(block, direction),
+        self.physical_side(inline, direction),
+      )),
+      None => {
+        let ltr = f(
+          self.physical_side(block, Direction::Ltr),
+          self.physical_side(inline, Direction::Ltr),
+        );
+        let rtl = f(
+          self.physical_side(block, Direction::Rtl),
+          self.physical_side(inline, Direction::Rtl),
+        );
+        self.add_logical_rule(ltr, rtl);
+      }
+    }
+  }
+
   pub fn get_logical_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
     // TODO: :dir/:lang raises the specificity of the selector. Use :where to lower it?
     let mut dest = Vec::new();
@@ -77,7 +202,9 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       ($dir: ident, $decls: ident) => {
         let mut selectors = style_rule.selectors.clone();
         for selector in &mut selectors.0 {
-          selector.append(Component::NonTSPseudoClass(PseudoClass::Dir(Direction::$dir)));
+          selector.append(Component::NonTSPseudoClass(PseudoClass::Dir(
+            Directionality::$dir,
+          )));
         }
 
         let rule = StyleRule {
@@ -106,6 +233,134 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     dest
   }
 
+  pub fn add_dark_rule(&mut self, property: Property<'i>) {
+    if self.context != DeclarationContext::StyleRule {
+      return;
+    }
+
+    self.dark.push(property);
+  }
+
+  pub fn get_dark_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
+    if self.dark.is_empty() {
+      return Vec::new();
+    }
+
+    vec![CssRule::Media(MediaRule {
+      query: MediaList {
+        media_queries: vec![MediaQuery {
+          qualifier: None,
+          media_type: MediaType::All,
+          condition: Some(MediaCondition::Feature(MediaFeature::Plain {
+            name: "prefers-color-scheme".into(),
+            value: MediaFeatureValue::Ident("dark".into()),
+          })),
+        }],
+      },
+      rules: CssRuleList(vec![CssRule::Style(StyleRule {
+        selectors: style_rule.selectors.clone(),
+        vendor_prefix: VendorPrefix::None,
+        declarations: DeclarationBlock {
+          declarations: std::mem::take(&mut self.dark),
+          important_declarations: vec![],
+        },
+        rules: CssRuleList(vec![]),
+        loc: style_rule.loc.clone(),
+      })]),
+      loc: style_rule.loc.clone(),
+    })]
+  }
+
+  pub fn add_aspect_ratio_fallback(&mut self, ratio: &Ratio) {
+    if self.context != DeclarationContext::StyleRule || !self.options.aspect_ratio_fallback || ratio.0 == 0.0 {
+      return;
+    }
+
+    self.aspect_ratio = Some(ratio.clone());
+  }
+
+  pub fn get_aspect_ratio_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
+    let ratio = match std::mem::take(&mut self.aspect_ratio) {
+      Some(ratio) => ratio,
+      None => return Vec::new(),
+    };
+
+    // The fallback relies on the ::before and ::after pseudo elements of the box itself.
+    if style_rule.selectors.0.iter().any(|selector| selector.has_pseudo_element()) {
+      return Vec::new();
+    }
+
+    macro_rules! rule {
+      ($pseudo_element: ident, $declarations: expr) => {
+        CssRule::Style(StyleRule {
+          selectors: SelectorList(
+            style_rule
+              .selectors
+              .0
+              .iter()
+              .map(|selector| with_pseudo_element(selector, PseudoElement::$pseudo_element))
+              .collect(),
+          ),
+          vendor_prefix: VendorPrefix::None,
+          declarations: DeclarationBlock {
+            declarations: $declarations,
+            important_declarations: vec![],
+          },
+          rules: CssRuleList(vec![]),
+          loc: style_rule.loc.clone(),
+        })
+      };
+    }
+
+    // Neither float nor clear are typed properties.
+    macro_rules! ident_property {
+      ($name: literal, $value: literal) => {
+        Property::Unparsed(UnparsedProperty {
+          property_id: PropertyId::Custom($name.into()),
+          value: TokenList(vec![TokenOrValue::Token(Token::Ident($value.into()))]),
+        })
+      };
+    }
+
+    let empty_content = || {
+      Property::Content(Content::Items {
+        items: vec![ContentItem::String { value: "".into() }],
+        alt: vec![],
+      })
+    };
+
+    // A percentage padding resolves against the width of the containing block, i.e. the box.
+    let before = rule!(
+      Before,
+      vec![
+        ident_property!("float", "left"),
+        empty_content(),
+        Property::PaddingTop(LengthPercentageOrAuto::LengthPercentage(LengthPercentage::Percentage(
+          Percentage(ratio.1 / ratio.0),
+        ))),
+      ]
+    );
+    let after = rule!(
+      After,
+      vec![
+        Property::Display(Display::Pair(DisplayPair {
+          outside: DisplayOutside::Block,
+          inside: DisplayInside::Flow,
+          is_list_item: false,
+        })),
+        ident_property!("clear", "both"),
+        empty_content(),
+      ]
+    );
+
+    // Browsers that support aspect-ratio ignore the fallback.
+    vec![CssRule::Supports(SupportsRule {
+      condition: SupportsCondition::Not(Box::new(SupportsCondition::Declaration("aspect-ratio: 1/1".into()))),
+      rules: CssRuleList(vec![before, after]),
+      loc: style_rule.loc.clone(),
+    })]
+  }
+
   pub fn add_conditional_property(&mut self, condition: SupportsCondition<'i>, property: Property<'i>) {
     if self.context != DeclarationContext::StyleRule {
       return;
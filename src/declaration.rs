@@ -11,13 +11,15 @@ use crate::properties::box_shadow::BoxShadowHandler;
 use crate::properties::masking::MaskHandler;
 use crate::properties::{
   align::AlignHandler,
-  animation::AnimationHandler,
+  animation::{AnimationHandler, TimelineHandler},
   background::BackgroundHandler,
   border::BorderHandler,
+  columns::ColumnsHandler,
   contain::ContainerHandler,
   display::DisplayHandler,
+  effects::{Filter, FilterList},
   flex::FlexHandler,
-  font::FontHandler,
+  font::{FontHandler, FontSize},
   grid::GridHandler,
   list::ListStyleHandler,
   margin_padding::*,
@@ -25,15 +27,19 @@ use crate::properties::{
   overflow::OverflowHandler,
   position::PositionHandler,
   prefix_handler::{FallbackHandler, PrefixHandler},
+  scroll::ScrollHandler,
   size::SizeHandler,
+  svg::{SVGPaint, SVGPaintFallback},
   text::TextDecorationHandler,
   transform::TransformHandler,
   transition::TransitionHandler,
+  ui::{ColorOrAuto, ColorSchemeHandler},
 };
 use crate::properties::{Property, PropertyId};
 use crate::targets::Browsers;
-use crate::traits::{PropertyHandler, ToCss};
+use crate::traits::{IsCompatible, PropertyHandler, ToCss};
 use crate::values::string::CowArcStr;
+use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
 /// A CSS declaration block.
@@ -164,6 +170,7 @@ impl<'i> DeclarationBlock<'i> {
     important_handler: &mut DeclarationHandler<'i>,
     context: &mut PropertyHandlerContext<'i, '_>,
   ) {
+    let merge_longhands = context.options.merge_longhands;
     macro_rules! handle {
       ($decls: expr, $handler: expr, $important: literal) => {
         for decl in $decls.iter() {
@@ -173,6 +180,11 @@ impl<'i> DeclarationBlock<'i> {
           if !handled {
             $handler.decls.push(decl.clone());
           }
+
+          // Flush after each declaration so that the handler cannot combine them.
+          if !merge_longhands {
+            $handler.finalize(context);
+          }
         }
       };
     }
@@ -182,8 +194,24 @@ impl<'i> DeclarationBlock<'i> {
 
     handler.finalize(context);
     important_handler.finalize(context);
-    self.important_declarations = std::mem::take(&mut important_handler.decls);
-    self.declarations = std::mem::take(&mut handler.decls);
+    let important_declarations = std::mem::take(&mut important_handler.decls);
+    let declarations = std::mem::take(&mut handler.decls);
+
+    if !context.options.remove_prefixes && context.targets.is_some() {
+      let important_declarations = std::mem::replace(&mut self.important_declarations, important_declarations);
+      let declarations = std::mem::replace(&mut self.declarations, declarations);
+      restore_prefixes(&important_declarations, &mut self.important_declarations);
+      restore_prefixes(&declarations, &mut self.declarations);
+    } else {
+      self.important_declarations = important_declarations;
+      self.declarations = declarations;
+    }
+
+    if context.options.remove_overridden {
+      let remove_fallbacks = context.options.remove_fallbacks;
+      remove_overridden_declarations(&mut self.important_declarations, remove_fallbacks, context.targets);
+      remove_overridden_declarations(&mut self.declarations, remove_fallbacks, context.targets);
+    }
   }
 
   /// Returns whether the declaration block is empty.
@@ -442,6 +470,7 @@ pub(crate) struct DeclarationHandler<'i> {
   background: BackgroundHandler<'i>,
   border: BorderHandler<'i>,
   outline: OutlineHandler,
+  columns: ColumnsHandler,
   flex: FlexHandler,
   grid: GridHandler<'i>,
   align: AlignHandler,
@@ -455,14 +484,17 @@ pub(crate) struct DeclarationHandler<'i> {
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
+  timeline: TimelineHandler<'i>,
   display: DisplayHandler<'i>,
   position: PositionHandler,
   inset: InsetHandler<'i>,
   overflow: OverflowHandler,
+  scroll: ScrollHandler,
   transform: TransformHandler,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  color_scheme: ColorSchemeHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
   decls: DeclarationList<'i>,
@@ -474,6 +506,7 @@ impl<'i> DeclarationHandler<'i> {
       background: BackgroundHandler::new(targets),
       border: BorderHandler::new(targets),
       outline: OutlineHandler::new(targets),
+      columns: ColumnsHandler::new(targets),
       flex: FlexHandler::new(targets),
       grid: GridHandler::default(),
       align: AlignHandler::new(targets),
@@ -487,14 +520,17 @@ impl<'i> DeclarationHandler<'i> {
       list: ListStyleHandler::new(targets),
       transition: TransitionHandler::new(targets),
       animation: AnimationHandler::new(targets),
+      timeline: TimelineHandler::default(),
       display: DisplayHandler::new(targets),
       position: PositionHandler::new(targets),
       inset: InsetHandler::default(),
       overflow: OverflowHandler::new(targets),
+      scroll: ScrollHandler::new(targets),
       transform: TransformHandler::new(targets),
       box_shadow: BoxShadowHandler::new(targets),
       mask: MaskHandler::default(),
       container: ContainerHandler::default(),
+      color_scheme: ColorSchemeHandler::default(),
       fallback: FallbackHandler::new(targets),
       prefix: PrefixHandler::new(targets),
       decls: DeclarationList::new(),
@@ -506,8 +542,8 @@ impl<'i> DeclarationHandler<'i> {
     property: &Property<'i>,
     context: &mut PropertyHandlerContext<'i, '_>,
   ) -> bool {
-    if !context.unused_symbols.is_empty()
-      && matches!(property, Property::Custom(custom) if context.unused_symbols.contains(custom.name.as_ref()))
+    if !context.options.unused_symbols.is_empty()
+      && matches!(property, Property::Custom(custom) if context.options.unused_symbols.contains(custom.name.as_ref()))
     {
       return true;
     }
@@ -515,6 +551,7 @@ impl<'i> DeclarationHandler<'i> {
     self.background.handle_property(property, &mut self.decls, context)
       || self.border.handle_property(property, &mut self.decls, context)
       || self.outline.handle_property(property, &mut self.decls, context)
+      || self.columns.handle_property(property, &mut self.decls, context)
       || self.flex.handle_property(property, &mut self.decls, context)
       || self.grid.handle_property(property, &mut self.decls, context)
       || self.align.handle_property(property, &mut self.decls, context)
@@ -528,14 +565,17 @@ impl<'i> DeclarationHandler<'i> {
       || self.list.handle_property(property, &mut self.decls, context)
       || self.transition.handle_property(property, &mut self.decls, context)
       || self.animation.handle_property(property, &mut self.decls, context)
+      || self.timeline.handle_property(property, &mut self.decls, context)
       || self.display.handle_property(property, &mut self.decls, context)
       || self.position.handle_property(property, &mut self.decls, context)
       || self.inset.handle_property(property, &mut self.decls, context)
       || self.overflow.handle_property(property, &mut self.decls, context)
+      || self.scroll.handle_property(property, &mut self.decls, context)
       || self.transform.handle_property(property, &mut self.decls, context)
       || self.box_shadow.handle_property(property, &mut self.decls, context)
       || self.mask.handle_property(property, &mut self.decls, context)
       || self.container.handle_property(property, &mut self.decls, context)
+      || self.color_scheme.handle_property(property, &mut self.decls, context)
       || self.fallback.handle_property(property, &mut self.decls, context)
       || self.prefix.handle_property(property, &mut self.decls, context)
   }
@@ -544,6 +584,7 @@ impl<'i> DeclarationHandler<'i> {
     self.background.finalize(&mut self.decls, context);
     self.border.finalize(&mut self.decls, context);
     self.outline.finalize(&mut self.decls, context);
+    self.columns.finalize(&mut self.decls, context);
     self.flex.finalize(&mut self.decls, context);
     self.grid.finalize(&mut self.decls, context);
     self.align.finalize(&mut self.decls, context);
@@ -557,15 +598,208 @@ impl<'i> DeclarationHandler<'i> {
     self.list.finalize(&mut self.decls, context);
     self.transition.finalize(&mut self.decls, context);
     self.animation.finalize(&mut self.decls, context);
+    self.timeline.finalize(&mut self.decls, context);
     self.display.finalize(&mut self.decls, context);
     self.position.finalize(&mut self.decls, context);
     self.inset.finalize(&mut self.decls, context);
     self.overflow.finalize(&mut self.decls, context);
+    self.scroll.finalize(&mut self.decls, context);
     self.transform.finalize(&mut self.decls, context);
     self.box_shadow.finalize(&mut self.decls, context);
     self.mask.finalize(&mut self.decls, context);
     self.container.finalize(&mut self.decls, context);
+    self.color_scheme.finalize(&mut self.decls, context);
     self.fallback.finalize(&mut self.decls, context);
     self.prefix.finalize(&mut self.decls, context);
   }
 }
+
+/// Adds back vendor prefixed declarations from the original declarations that were removed
+/// by the property handlers because they are not needed by the browser targets.
+fn restore_prefixes<'i>(original: &DeclarationList<'i>, decls: &mut DeclarationList<'i>) {
+  for (i, decl) in original.iter().enumerate() {
+    let property_id = decl.property_id();
+    let prefix = property_id.prefix();
+    if prefix.is_empty() || prefix.contains(VendorPrefix::None) {
+      continue;
+    }
+
+    let name = property_id.name();
+    let exists = decls
+      .iter()
+      .any(|d| d.property_id().name() == name && d.property_id().prefix().contains(prefix));
+    if exists {
+      continue;
+    }
+
+    // If the property was merged into a shorthand, there is nothing to restore it next to.
+    let first = match decls.iter().position(|d| d.property_id().name() == name) {
+      Some(first) => first,
+      None => continue,
+    };
+    let last = decls.iter().rposition(|d| d.property_id().name() == name).unwrap();
+
+    // When the value of the last declaration of the same property is unchanged, add the prefix to it.
+    let mut prefixed = decls[last].clone();
+    if let Some(p) = prefixed.vendor_prefix_mut() {
+      *p = prefix;
+    }
+    if prefixed == *decl {
+      if let Some(p) = decls[last].vendor_prefix_mut() {
+        *p |= prefix;
+      }
+      continue;
+    }
+
+    // Otherwise, re-insert the original declaration before or after the declarations
+    // of the same property, matching its original position.
+    let is_after = original[..i].iter().any(|d| d.property_id().name() == name);
+    decls.insert(if is_after { last + 1 } else { first }, decl.clone());
+  }
+}
+
+/// Removes declarations that are fully overridden by later declarations of the same
+/// importance within a declaration list.
+///
+/// Shorthands are expanded into their longhands, so a declaration is only removed when
+/// every longhand it sets is reset again later on. Later declarations whose values are not
+/// supported by all browser targets are treated as progressive enhancements, and the earlier
+/// declaration is kept as a fallback for browsers that do not support them.
+fn remove_overridden_declarations(decls: &mut DeclarationList, remove_fallbacks: bool, targets: Option<Browsers>) {
+  if decls.len() < 2 {
+    return;
+  }
+
+  // Longhands of each declaration that is kept, in reverse order, and whether it overrides earlier ones.
+  let mut kept: Vec<(Vec<PropertyId>, bool)> = Vec::new();
+  let mut remove = vec![false; decls.len()];
+  for (i, decl) in decls.iter().enumerate().rev() {
+    let property_id = decl.property_id();
+    // Multiple `composes` declarations are combined rather than overriding each other.
+    if matches!(
+      property_id,
+      PropertyId::Custom(..) | PropertyId::All | PropertyId::Composes
+    ) {
+      continue;
+    }
+
+    let mut longhands = Vec::new();
+    expand_longhands(property_id, &mut longhands);
+
+    let is_overridden = longhands
+      .iter()
+      .all(|longhand| kept.iter().any(|(ids, overrides)| *overrides && ids.contains(longhand)));
+
+    if is_overridden {
+      remove[i] = true;
+    } else {
+      kept.push((longhands, remove_fallbacks || is_compatible(decl, targets)));
+    }
+  }
+
+  let mut i = 0;
+  decls.retain(|_| {
+    let keep = !remove[i];
+    i += 1;
+    keep
+  });
+}
+
+fn expand_longhands<'i>(property_id: PropertyId<'i>, dest: &mut Vec<PropertyId<'i>>) {
+  if let Some(longhands) = property_id.longhands() {
+    for longhand in longhands {
+      expand_longhands(longhand, dest);
+    }
+  } else if !dest.contains(&property_id) {
+    dest.push(property_id);
+  }
+}
+
+/// Returns whether the value of a declaration is supported by all of the given browser targets.
+/// Unparsed values, e.g. ones that contain `var()`, are never compatible.
+fn is_compatible(decl: &Property, targets: Option<Browsers>) -> bool {
+  if let Some(longhands) = decl.property_id().longhands() {
+    return longhands
+      .iter()
+      .all(|id| decl.longhand(id).map_or(false, |longhand| is_compatible(&longhand, targets)));
+  }
+
+  match decl {
+    Property::Color(color)
+    | Property::BackgroundColor(color)
+    | Property::BorderTopColor(color)
+    | Property::BorderBottomColor(color)
+    | Property::BorderLeftColor(color)
+    | Property::BorderRightColor(color)
+    | Property::BorderBlockStartColor(color)
+    | Property::BorderBlockEndColor(color)
+    | Property::BorderInlineStartColor(color)
+    | Property::BorderInlineEndColor(color)
+    | Property::OutlineColor(color)
+    | Property::ColumnRuleColor(color, _)
+    | Property::TextDecorationColor(color, _)
+    | Property::TextEmphasisColor(color, _)
+    | Property::CaretColor(ColorOrAuto::Color(color))
+    | Property::AccentColor(ColorOrAuto::Color(color))
+    | Property::Fill(SVGPaint::Color(color))
+    | Property::Stroke(SVGPaint::Color(color))
+    | Property::Fill(SVGPaint::Url(_, Some(SVGPaintFallback::Color(color))))
+    | Property::Stroke(SVGPaint::Url(_, Some(SVGPaintFallback::Color(color)))) => color.is_compatible(targets),
+    Property::BoxShadow(shadows, _) => shadows.iter().all(|shadow| shadow.color.is_compatible(targets)),
+    Property::TextShadow(shadows) => shadows.iter().all(|shadow| shadow.color.is_compatible(targets)),
+    Property::Filter(FilterList::Filters(filters), _)
+    | Property::BackdropFilter(FilterList::Filters(filters), _) => filters.iter().all(|filter| match filter {
+      Filter::DropShadow(shadow) => shadow.color.is_compatible(targets),
+      _ => true,
+    }),
+    Property::Width(size)
+    | Property::Height(size)
+    | Property::MinWidth(size)
+    | Property::MinHeight(size)
+    | Property::BlockSize(size)
+    | Property::InlineSize(size)
+    | Property::MinBlockSize(size)
+    | Property::MinInlineSize(size) => size.is_compatible(targets),
+    Property::MaxWidth(size)
+    | Property::MaxHeight(size)
+    | Property::MaxBlockSize(size)
+    | Property::MaxInlineSize(size) => size.is_compatible(targets),
+    Property::Top(length)
+    | Property::Bottom(length)
+    | Property::Left(length)
+    | Property::Right(length)
+    | Property::InsetBlockStart(length)
+    | Property::InsetBlockEnd(length)
+    | Property::InsetInlineStart(length)
+    | Property::InsetInlineEnd(length)
+    | Property::MarginTop(length)
+    | Property::MarginBottom(length)
+    | Property::MarginLeft(length)
+    | Property::MarginRight(length)
+    | Property::MarginBlockStart(length)
+    | Property::MarginBlockEnd(length)
+    | Property::MarginInlineStart(length)
+    | Property::MarginInlineEnd(length)
+    | Property::PaddingTop(length)
+    | Property::PaddingBottom(length)
+    | Property::PaddingLeft(length)
+    | Property::PaddingRight(length)
+    | Property::PaddingBlockStart(length)
+    | Property::PaddingBlockEnd(length)
+    | Property::PaddingInlineStart(length)
+    | Property::PaddingInlineEnd(length)
+    | Property::FlexBasis(length, _) => length.is_compatible(targets),
+    Property::FontSize(FontSize::Length(length)) => length.is_compatible(targets),
+    Property::StrokeWidth(length) | Property::StrokeDashoffset(length) => length.is_compatible(targets),
+    Property::BackgroundImage(images) | Property::MaskImage(images, _) => images.is_compatible(targets),
+    Property::BorderImageSource(image)
+    | Property::ListStyleImage(image)
+    | Property::MaskBorderSource(image)
+    | Property::WebKitMaskBoxImageSource(image, _) => image.is_compatible(targets),
+    Property::WebKitMaskBoxImage(border_image, _) => border_image.source.is_compatible(targets),
+    Property::Display(display) => display.is_compatible(targets),
+    Property::Position(position) => position.is_compatible(targets),
+    Property::Unparsed(..) => false,
+    _ => true,
+  }
+}
@@ -73,6 +73,8 @@ pub enum ParserError<'i> {
   InvalidDeclaration,
   /// A media query was invalid.
   InvalidMediaQuery,
+  /// A container query was invalid.
+  InvalidContainerQuery,
   /// Invalid CSS nesting.
   InvalidNesting,
   /// An invalid selector in an `@page` rule.
@@ -102,6 +104,7 @@ impl<'i> fmt::Display for ParserError<'i> {
       EndOfInput => write!(f, "Unexpected end of input"),
       InvalidDeclaration => write!(f, "Invalid declaration"),
       InvalidMediaQuery => write!(f, "Invalid media query"),
+      InvalidContainerQuery => write!(f, "Invalid container query"),
       InvalidNesting => write!(f, "Invalid nesting"),
       InvalidPageSelector => write!(f, "Invalid page selector"),
       InvalidValue => write!(f, "Invalid value"),
@@ -306,6 +309,20 @@ pub enum MinifyErrorKind {
     /// The source location of the `@custom-media` rule with unsupported boolean logic.
     custom_media_loc: Location,
   },
+  /// A `:not()` selector list containing complex selectors could not be compiled
+  /// for browser targets that only support a single simple selector in `:not()`.
+  UnsupportedNotSelectorList,
+  /// A selector uses `:has()`, which is not supported by all browser targets.
+  UnsupportedHasSelector,
+  /// A media query uses level 4 boolean logic, e.g. `not` or `or` within a condition, that
+  /// has no equivalent level 3 media query list for the configured browser targets.
+  UnsupportedMediaBooleanLogic,
+  /// Logical properties were converted to physical properties for the configured targets,
+  /// but the `writing-mode` declared in the same rule could not be statically determined.
+  AmbiguousWritingMode,
+  /// View transition rules, properties, or pseudo-elements are used, but view transitions
+  /// are not supported by all browser targets.
+  UnsupportedViewTransitions,
 }
 
 impl fmt::Display for MinifyErrorKind {
@@ -318,6 +335,26 @@ impl fmt::Display for MinifyErrorKind {
         f,
         "Boolean logic with media types in @custom-media rules is not supported by Parcel CSS"
       ),
+      UnsupportedNotSelectorList => write!(
+        f,
+        "Complex selectors in a :not() selector list cannot be compiled for the configured targets"
+      ),
+      UnsupportedHasSelector => write!(
+        f,
+        "The :has() selector is not supported by all of the configured targets"
+      ),
+      UnsupportedMediaBooleanLogic => write!(
+        f,
+        "Boolean logic in this media query cannot be compiled for the configured targets"
+      ),
+      AmbiguousWritingMode => write!(
+        f,
+        "Logical properties were converted assuming a horizontal writing mode, but the writing-mode of this rule is not statically known"
+      ),
+      UnsupportedViewTransitions => write!(
+        f,
+        "View transitions are not supported by all of the configured targets"
+      ),
     }
   }
 }
@@ -350,6 +387,8 @@ pub enum PrinterErrorKind {
   InvalidComposesSelector,
   /// The CSS modules pattern must end with `[local]` for use in CSS grid.
   InvalidCssModulesPatternInGrid,
+  /// A nesting suffix (e.g. `&__element`) could not be concatenated with the parent selector.
+  InvalidNestingSuffix,
 }
 
 impl From<fmt::Error> for PrinterError {
@@ -370,6 +409,7 @@ impl fmt::Display for PrinterErrorKind {
       InvalidComposesNesting => write!(f, "The `composes` property cannot be used within nested rules"),
       InvalidComposesSelector => write!(f, "The `composes` property cannot be used with a simple class selector"),
       InvalidCssModulesPatternInGrid => write!(f, "The CSS modules `pattern` config must end with `[local]` for use in CSS grid line names."),
+      InvalidNestingSuffix => write!(f, "A nesting suffix can only be used when the parent is a single selector ending with a class, id, or type selector"),
     }
   }
 }
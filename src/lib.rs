@@ -43,10 +43,12 @@ mod tests {
   use crate::css_modules::{CssModuleExport, CssModuleExports, CssModuleReference, CssModuleReferences};
   use crate::dependencies::Dependency;
   use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind, SelectorError};
+  use crate::printer::Printer;
   use crate::properties::custom::Token;
   use crate::properties::Property;
   use crate::rules::CssRule;
   use crate::rules::Location;
+  use crate::rules::ToCssWithContext;
   use crate::stylesheet::*;
   use crate::targets::Browsers;
   use crate::traits::{Parse, ToCss};
@@ -54,6 +56,7 @@ mod tests {
   use cssparser::SourceLocation;
   use indoc::indoc;
   use std::collections::HashMap;
+  use std::sync::{Arc, RwLock};
 
   fn test(source: &str, expected: &str) {
     test_with_options(source, expected, ParserOptions::default())
@@ -67,7 +70,11 @@ mod tests {
   }
 
   fn minify_test(source: &str, expected: &str) {
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    minify_test_with_parser_options(source, expected, ParserOptions::default())
+  }
+
+  fn minify_test_with_parser_options<'i, 'o>(source: &'i str, expected: &'i str, options: ParserOptions<'o, 'i>) {
+    let mut stylesheet = StyleSheet::parse(&source, options).unwrap();
     stylesheet.minify(MinifyOptions::default()).unwrap();
     let res = stylesheet
       .to_css(PrinterOptions {
@@ -78,6 +85,55 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  fn minify_test_with_options(source: &str, expected: &str, options: MinifyOptions) {
+    let (code, _) = minify_with_warnings(source, options);
+    assert_eq!(code, expected);
+  }
+
+  fn minify_with_warnings(source: &str, options: MinifyOptions) -> (String, Vec<Error<MinifyErrorKind>>) {
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    let targets = options.targets;
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        warnings: Some(warnings.clone()),
+        ..options
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let warnings = std::mem::take(&mut *warnings.write().unwrap());
+    (res.code, warnings)
+  }
+
+  fn minify_warnings_test(
+    source: &str,
+    expected: &str,
+    options: MinifyOptions,
+    kind: MinifyErrorKind,
+    expected_warnings: usize,
+  ) -> Vec<Error<MinifyErrorKind>> {
+    let (code, warnings) = minify_with_warnings(source, options);
+    assert_eq!(code, expected);
+    assert_eq!(warnings.len(), expected_warnings);
+    for warning in &warnings {
+      assert_eq!(warning.kind, kind);
+    }
+    warnings
+  }
+
+  fn printer_test_with_options(source: &str, expected: &str, options: PrinterOptions) {
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet.to_css(options).unwrap();
+    assert_eq!(res.code, expected);
+  }
+
   fn prefix_test(source: &str, expected: &str, targets: Browsers) {
     let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
     stylesheet
@@ -198,6 +254,12 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  fn printer_error_test<'i, 'o>(source: &'i str, error: PrinterErrorKind, options: ParserOptions<'o, 'i>) {
+    let stylesheet = StyleSheet::parse(&source, options).unwrap();
+    let err = stylesheet.to_css(PrinterOptions::default()).unwrap_err();
+    assert_eq!(err.kind, error);
+  }
+
   fn error_test(source: &str, error: ParserError) {
     let res = StyleSheet::parse(&source, ParserOptions::default());
     match res {
@@ -2513,6 +2575,129 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_columns() {
+    minify_test(".foo { column-width: 10em; column-count: 3 }", ".foo{columns:10em 3}");
+    minify_test(".foo { columns: 3 auto }", ".foo{columns:3}");
+    minify_test(".foo { columns: auto 10em }", ".foo{columns:10em}");
+    minify_test(".foo { columns: auto auto }", ".foo{columns:auto}");
+    minify_test(".foo { columns: auto; column-count: 4 }", ".foo{columns:4}");
+    minify_test(".foo { column-count: 0 }", ".foo{column-count:0}");
+    minify_test(".foo { column-width: -1px }", ".foo{column-width:-1px}");
+    minify_test(
+      ".foo { column-rule-width: 1px; column-rule-style: solid; column-rule-color: red }",
+      ".foo{column-rule:1px solid red}",
+    );
+    minify_test(
+      ".foo { column-rule: thin dotted red; column-rule-color: blue }",
+      ".foo{column-rule:thin dotted #00f}",
+    );
+    minify_test(".foo { column-rule: none }", ".foo{column-rule:none}");
+    minify_test(".foo { column-span: ALL }", ".foo{column-span:all}");
+    minify_test(".foo { column-fill: balance-all }", ".foo{column-fill:balance-all}");
+    minify_test(
+      ".foo { -webkit-columns: 2; -moz-columns: 2; columns: 2 }",
+      ".foo{-webkit-columns:2;-moz-columns:2;columns:2}",
+    );
+    minify_test(
+      ".foo { -webkit-column-count: 2; column-count: 3 }",
+      ".foo{-webkit-column-count:2;column-count:3}",
+    );
+    minify_test(
+      ".foo { -webkit-columns: 10px 2; columns: 10px 3 }",
+      ".foo{-webkit-columns:10px 2;columns:10px 3}",
+    );
+    minify_test(
+      ".foo { -webkit-column-rule: thin solid red; column-rule: thin solid blue }",
+      ".foo{-webkit-column-rule:thin solid red;column-rule:thin solid #00f}",
+    );
+
+    prefix_test(
+      ".foo { column-width: 10em; column-count: 3 }",
+      indoc! { r#"
+        .foo {
+          -webkit-columns: 10em 3;
+          -moz-columns: 10em 3;
+          columns: 10em 3;
+        }
+      "#},
+      Browsers {
+        chrome: Some(46 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { -webkit-columns: 2; columns: 2 }",
+      indoc! { r#"
+        .foo {
+          columns: 2;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { column-span: all }",
+      indoc! { r#"
+        .foo {
+          -webkit-column-span: all;
+          column-span: all;
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { column-rule: 2px solid lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          column-rule: 2px solid #b32323;
+          column-rule: 2px solid lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { column-rule-color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          column-rule-color: #b32323;
+          column-rule-color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { column-rule: var(--width) solid red }",
+      indoc! { r#"
+        .foo {
+          -webkit-column-rule: var(--width) solid red;
+          column-rule: var(--width) solid red;
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   pub fn test_margin() {
     test(
@@ -3306,107 +3491,505 @@ mod tests {
   }
 
   #[test]
-  pub fn test_background() {
-    test(
+  fn test_aspect_ratio() {
+    minify_test(".foo { aspect-ratio: 16 / 9 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: 2 / 1 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 1.5 }", ".foo{aspect-ratio:1.5}");
+    minify_test(".foo { aspect-ratio: auto }", ".foo{aspect-ratio:auto}");
+    minify_test(".foo { aspect-ratio: auto 4 / 3 }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: 4 / 3 auto }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: -1 / 2 }", ".foo{aspect-ratio:-1/2}");
+    minify_test(".foo { aspect-ratio: auto auto }", ".foo{aspect-ratio:auto auto}");
+
+    fn fallback_test(source: &str, expected: &str, targets: Browsers, fallback: bool) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: Some(targets),
+          aspect_ratio_fallback: fallback,
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    let safari = Browsers {
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    };
+
+    fallback_test(
+      ".foo { aspect-ratio: 16 / 9 }",
+      ".foo{aspect-ratio:16/9}@supports not (aspect-ratio: 1/1){.foo:before{float:left;content:\"\";padding-top:56.25%}.foo:after{clear:both;content:\"\";display:block}}",
+      safari,
+      true,
+    );
+    fallback_test(
+      ".foo, .bar > .baz { aspect-ratio: auto 2 }",
+      ".foo,.bar>.baz{aspect-ratio:auto 2}@supports not (aspect-ratio: 1/1){.foo:before,.bar>.baz:before{float:left;content:\"\";padding-top:50%}.foo:after,.bar>.baz:after{clear:both;content:\"\";display:block}}",
+      safari,
+      true,
+    );
+    fallback_test(
+      ".foo { aspect-ratio: 16 / 9 }",
+      ".foo{aspect-ratio:16/9}",
+      safari,
+      false,
+    );
+    fallback_test(".foo { aspect-ratio: auto }", ".foo{aspect-ratio:auto}", safari, true);
+    fallback_test(
+      ".foo::marker { aspect-ratio: 1 }",
+      ".foo::marker{aspect-ratio:1}",
+      safari,
+      true,
+    );
+    fallback_test(
+      ".foo { aspect-ratio: 16 / 9 }",
+      ".foo{aspect-ratio:16/9}",
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+      true,
+    );
+  }
+
+  #[test]
+  fn test_object_fit() {
+    minify_test(".foo { object-fit: scale-down }", ".foo{object-fit:scale-down}");
+    minify_test(".foo { object-fit: COVER }", ".foo{object-fit:cover}");
+    minify_test(".foo { object-position: center }", ".foo{object-position:50%}");
+    minify_test(
+      ".foo { object-position: right 10px top }",
+      ".foo{object-position:right 10px top}",
+    );
+    minify_test(".foo { object-position: left bottom }", ".foo{object-position:0 100%}");
+
+    prefix_test(
       r#"
       .foo {
-        background: url(img.png);
-        background-position-x: 20px;
-        background-position-y: 10px;
-        background-size: 50px 100px;
-        background-repeat: repeat no-repeat;
+        object-fit: cover;
+        object-position: left top;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: url("img.png") 20px 10px / 50px 100px repeat-x;
+        -o-object-fit: cover;
+        object-fit: cover;
+        -o-object-position: 0 0;
+        object-position: 0 0;
       }
     "#
       },
+      Browsers {
+        opera: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        background-color: red;
-        background-position: 0% 0%;
-        background-size: auto;
-        background-repeat: repeat;
-        background-clip: border-box;
-        background-origin: padding-box;
-        background-attachment: scroll;
-        background-image: none
+        -o-object-fit: cover;
+        object-fit: cover;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: red;
+        object-fit: cover;
       }
     "#
       },
+      Browsers {
+        opera: Some(40 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    test(
+  #[test]
+  fn test_image_orientation() {
+    minify_test(
+      ".foo { image-orientation: from-image }",
+      ".foo{image-orientation:from-image}",
+    );
+    minify_test(".foo { image-orientation: none }", ".foo{image-orientation:none}");
+    minify_test(".foo { image-orientation: 90deg }", ".foo{image-orientation:90deg}");
+    minify_test(".foo { image-orientation: flip }", ".foo{image-orientation:flip}");
+    minify_test(".foo { image-orientation: 0deg flip }", ".foo{image-orientation:flip}");
+    minify_test(
+      ".foo { image-orientation: flip 180deg }",
+      ".foo{image-orientation:180deg flip}",
+    );
+    minify_test(".foo { image-orientation: 0deg }", ".foo{image-orientation:0deg}");
+    minify_test(
+      ".foo { image-orientation: flip flip }",
+      ".foo{image-orientation:flip flip}",
+    );
+
+    minify_test(
+      ".foo { image-resolution: from-image }",
+      ".foo{image-resolution:from-image}",
+    );
+    minify_test(".foo { image-resolution: 300dpi }", ".foo{image-resolution:300dpi}");
+    minify_test(".foo { image-resolution: 2dppx }", ".foo{image-resolution:2x}");
+    minify_test(
+      ".foo { image-resolution: snap 2x from-image }",
+      ".foo{image-resolution:from-image 2x snap}",
+    );
+    minify_test(
+      ".foo { image-resolution: from-image snap }",
+      ".foo{image-resolution:from-image snap}",
+    );
+    minify_test(".foo { image-resolution: snap }", ".foo{image-resolution:snap}");
+  }
+
+  #[test]
+  fn test_writing_mode() {
+    minify_test(".foo { writing-mode: vertical-rl }", ".foo{writing-mode:vertical-rl}");
+    minify_test(".foo { writing-mode: Sideways-LR }", ".foo{writing-mode:sideways-lr}");
+    minify_test(".foo { direction: rtl }", ".foo{direction:rtl}");
+    minify_test(
+      ".foo { unicode-bidi: isolate-override }",
+      ".foo{unicode-bidi:isolate-override}",
+    );
+    minify_test(".foo { unicode-bidi: plaintext }", ".foo{unicode-bidi:plaintext}");
+    minify_test(".foo { text-orientation: upright }", ".foo{text-orientation:upright}");
+    minify_test(".foo { writing-mode: tb-rl }", ".foo{writing-mode:tb-rl}");
+
+    prefix_test(
       r#"
       .foo {
-        background-color: gray;
-        background-position: 40% 50%;
-        background-size: 10em auto;
-        background-repeat: round;
-        background-clip: border-box;
-        background-origin: border-box;
-        background-attachment: fixed;
-        background-image: url('chess.png');
+        writing-mode: vertical-rl;
+        margin-block-start: 2px;
+        margin-inline: 4px;
+        min-inline-size: 10px;
+        border-block-end: 1px solid red;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: gray url("chess.png") 40% / 10em round fixed border-box;
+        writing-mode: vertical-rl;
+        min-height: 10px;
+        border-left: 1px solid red;
+        margin-right: 2px;
+        margin-top: 4px;
+        margin-bottom: 4px;
       }
     "#
       },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        background: url(img.png), url(test.jpg) gray;
-        background-position-x: right 20px, 10px;
-        background-position-y: top 20px, 15px;
-        background-size: 50px 50px, auto;
-        background-repeat: repeat no-repeat, no-repeat;
+        writing-mode: vertical-lr;
+        inset-inline-start: 2px;
+        border-inline-end-width: 3px;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: url("img.png") right 20px top 20px / 50px 50px repeat-x, gray url("test.jpg") 10px 15px no-repeat;
+        writing-mode: vertical-lr;
+      }
+
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        border-bottom-width: 3px;
+        top: 2px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        border-top-width: 3px;
+        bottom: 2px;
       }
     "#
       },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
       .foo {
-        background-position: center center;
+        writing-mode: sideways-lr;
+        direction: rtl;
+        padding-inline-start: 2px;
+        padding-inline-end: 4px;
+        max-block-size: 5px;
       }
     "#,
-      indoc! {".foo{background-position:50%}"
+      indoc! {r#"
+      .foo {
+        writing-mode: sideways-lr;
+        direction: rtl;
+        max-width: 5px;
+        padding-top: 2px;
+        padding-bottom: 4px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
       },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        background: url(img.png) gray;
-        background-clip: content-box;
-        -webkit-background-clip: text;
+        writing-mode: vertical-rl;
+        border-start-start-radius: 2px;
+        border-end-start-radius: 3px;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: gray url("img.png") padding-box content-box;
-        -webkit-background-clip: text;
+        writing-mode: vertical-rl;
+      }
+
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        border-top-left-radius: 3px;
+        border-top-right-radius: 2px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        border-bottom-left-radius: 3px;
+        border-bottom-right-radius: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        writing-mode: vertical-lr;
+        direction: rtl;
+        border-start-end-radius: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        writing-mode: vertical-lr;
+        direction: rtl;
+        border-top-left-radius: 4px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        direction: ltr;
+        margin-inline-start: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        direction: ltr;
+        margin-left: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_writing_mode_warnings() {
+    let targets = Some(Browsers {
+      safari: Some(12 << 16),
+      ..Browsers::default()
+    });
+
+    minify_warnings_test(
+      ".foo { writing-mode: var(--wm); margin-block-start: 2px }",
+      ".foo{writing-mode:var(--wm);margin-top:2px}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      1,
+    );
+    minify_warnings_test(
+      ".foo { writing-mode: inherit; block-size: 2px }",
+      ".foo{writing-mode:inherit;height:2px}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      1,
+    );
+    minify_warnings_test(
+      ".foo { writing-mode: var(--wm); writing-mode: vertical-rl !important; margin-block-start: 2px }",
+      ".foo{writing-mode:var(--wm);margin-right:2px;writing-mode:vertical-rl!important}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      0,
+    );
+    minify_warnings_test(
+      ".foo { writing-mode: var(--wm); margin-top: 2px }",
+      ".foo{writing-mode:var(--wm);margin-top:2px}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      0,
+    );
+    minify_warnings_test(
+      ".foo { margin-block-start: 2px }",
+      ".foo{margin-top:2px}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      0,
+    );
+    minify_warnings_test(
+      ".foo { writing-mode: var(--wm); direction: ltr; border-start-start-radius: 2px }",
+      ".foo{writing-mode:var(--wm);direction:ltr;border-top-left-radius:2px}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::AmbiguousWritingMode,
+      1,
+    );
+  }
+
+  #[test]
+  pub fn test_background() {
+    test(
+      r#"
+      .foo {
+        background: url(img.png);
+        background-position-x: 20px;
+        background-position-y: 10px;
+        background-size: 50px 100px;
+        background-repeat: repeat no-repeat;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("img.png") 20px 10px / 50px 100px repeat-x;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        background-color: red;
+        background-position: 0% 0%;
+        background-size: auto;
+        background-repeat: repeat;
+        background-clip: border-box;
+        background-origin: padding-box;
+        background-attachment: scroll;
+        background-image: none
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: red;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        background-color: gray;
+        background-position: 40% 50%;
+        background-size: 10em auto;
+        background-repeat: round;
+        background-clip: border-box;
+        background-origin: border-box;
+        background-attachment: fixed;
+        background-image: url('chess.png');
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: gray url("chess.png") 40% / 10em round fixed border-box;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        background: url(img.png), url(test.jpg) gray;
+        background-position-x: right 20px, 10px;
+        background-position-y: top 20px, 15px;
+        background-size: 50px 50px, auto;
+        background-repeat: repeat no-repeat, no-repeat;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("img.png") right 20px top 20px / 50px 50px repeat-x, gray url("test.jpg") 10px 15px no-repeat;
+      }
+    "#
+      },
+    );
+
+    minify_test(
+      r#"
+      .foo {
+        background-position: center center;
+      }
+    "#,
+      indoc! {".foo{background-position:50%}"
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        background: url(img.png) gray;
+        background-clip: content-box;
+        -webkit-background-clip: text;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: gray url("img.png") padding-box content-box;
+        -webkit-background-clip: text;
       }
     "#
       },
@@ -5042,93 +5625,279 @@ mod tests {
   }
 
   #[test]
-  fn test_vertical_align() {
-    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
-    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
-  }
-
-  #[test]
-  fn test_selectors() {
-    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
-    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
-    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
-    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
+  fn test_font_variant() {
     minify_test(
-      ".test:not([foo=\"bar\"]) {color:red}",
-      ".test:not([foo=bar]){color:red}",
+      ".foo { font-variant-ligatures: normal }",
+      ".foo{font-variant-ligatures:normal}",
     );
-    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
-    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
-    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
     minify_test(
-      ".custom-range::-webkit-slider-thumb:active {color:red}",
-      ".custom-range::-webkit-slider-thumb:active{color:red}",
+      ".foo { font-variant-ligatures: none }",
+      ".foo{font-variant-ligatures:none}",
     );
-    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
-    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-variant-ligatures: no-contextual common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-contextual}",
     );
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-variant-ligatures: no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual }",
+      ".foo{font-variant-ligatures:none}",
     );
-    minify_test(":host {color:red}", ":host{color:red}");
-    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
-    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
     minify_test(
-      "custom-element::part(foo) {color:red}",
-      "custom-element::part(foo){color:red}",
+      ".foo { font-variant-ligatures: common-ligatures no-common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-common-ligatures}",
     );
-    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
-    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
-    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
     minify_test(
-      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
-      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
+      ".foo { font-variant-numeric: slashed-zero tabular-nums oldstyle-nums }",
+      ".foo{font-variant-numeric:oldstyle-nums tabular-nums slashed-zero}",
     );
     minify_test(
-      ":has(.sibling ~ .target) {color:red}",
-      ":has(.sibling~.target){color:red}",
+      ".foo { font-variant-numeric: ordinal }",
+      ".foo{font-variant-numeric:ordinal}",
     );
-    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
-    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
-    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
-    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
-    for element in [
-      "-webkit-scrollbar",
-      "-webkit-scrollbar-button",
-      "-webkit-scrollbar-track",
-      "-webkit-scrollbar-track-piece",
-      "-webkit-scrollbar-thumb",
-      "-webkit-scrollbar-corner",
-      "-webkit-resizer",
-    ] {
-      for class in [
-        "enabled",
-        "disabled",
-        "hover",
-        "active",
-        "horizontal",
-        "vertical",
-        "decrement",
-        "increment",
-        "start",
-        "end",
-        "double-button",
-        "single-button",
-        "no-button",
-        "corner-present",
-        "window-inactive",
-      ] {
-        minify_test(
-          &format!("::{}:{} {{color:red}}", element, class),
-          &format!("::{}:{}{{color:red}}", element, class),
-        );
-      }
-    }
-    for class in [
+    minify_test(
+      ".foo { font-variant-east-asian: ruby full-width jis04 }",
+      ".foo{font-variant-east-asian:jis04 full-width ruby}",
+    );
+    minify_test(
+      ".foo { font-variant-alternates: swash(fancy) historical-forms styleset(a, b) }",
+      ".foo{font-variant-alternates:historical-forms styleset(a,b) swash(fancy)}",
+    );
+    minify_test(
+      ".foo { font-variant-position: super }",
+      ".foo{font-variant-position:super}",
+    );
+    minify_test(".foo { font-variant: normal }", ".foo{font-variant:normal}");
+    minify_test(".foo { font-variant: none }", ".foo{font-variant:none}");
+    minify_test(
+      ".foo { font-variant: small-caps slashed-zero no-contextual }",
+      ".foo{font-variant:no-contextual small-caps slashed-zero}",
+    );
+    minify_test(
+      ".foo { font-variant: none small-caps }",
+      ".foo{font-variant:none small-caps}",
+    );
+
+    test(
+      r#"
+      .foo {
+        font-variant-ligatures: none;
+        font-variant-caps: small-caps;
+        font-variant-alternates: normal;
+        font-variant-numeric: tabular-nums;
+        font-variant-east-asian: normal;
+        font-variant-position: normal;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant: no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual small-caps tabular-nums;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        font-variant: small-caps;
+        font-variant-numeric: lining-nums;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant: small-caps lining-nums;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        font-variant: small-caps;
+        font-variant-numeric: var(--numeric);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant: small-caps;
+        font-variant-numeric: var(--numeric);
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        font-variant-numeric: tabular-nums;
+        font-kerning: none;
+        font-feature-settings: "liga" 0;
+        font: 12px Helvetica;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: 12px Helvetica;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        font: small-caps 12px Helvetica;
+        font-variant-numeric: tabular-nums;
+        font-kerning: none;
+        font-variant-position: normal;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: small-caps 12px Helvetica;
+        font-variant-numeric: tabular-nums;
+        font-kerning: none;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        font: 12px Helvetica;
+        font-variant-caps: all-small-caps;
+        font-variant-numeric: tabular-nums;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: 12px Helvetica;
+        font-variant: all-small-caps tabular-nums;
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_font_settings() {
+    minify_test(
+      ".foo { font-feature-settings: normal }",
+      ".foo{font-feature-settings:normal}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: 'liga' on, \"smcp\" off, 'swsh' 2, 'kern' 1 }",
+      ".foo{font-feature-settings:\"liga\",\"smcp\" 0,\"swsh\" 2,\"kern\"}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: 'toolong' }",
+      ".foo{font-feature-settings:\"toolong\"}",
+    );
+    minify_test(
+      ".foo { font-variation-settings: 'wght' 400.0, 'wdth' 75 }",
+      ".foo{font-variation-settings:\"wght\" 400,\"wdth\" 75}",
+    );
+    minify_test(".foo { font-kerning: none }", ".foo{font-kerning:none}");
+    minify_test(".foo { font-optical-sizing: auto }", ".foo{font-optical-sizing:auto}");
+    minify_test(".foo { font-synthesis: none }", ".foo{font-synthesis:none}");
+    minify_test(
+      ".foo { font-synthesis: small-caps weight }",
+      ".foo{font-synthesis:weight small-caps}",
+    );
+    minify_test(".foo { font-size-adjust: none }", ".foo{font-size-adjust:none}");
+    minify_test(".foo { font-size-adjust: 0.5 }", ".foo{font-size-adjust:.5}");
+    minify_test(".foo { font-size-adjust: ex-height 0.5 }", ".foo{font-size-adjust:.5}");
+    minify_test(
+      ".foo { font-size-adjust: cap-height from-font }",
+      ".foo{font-size-adjust:cap-height from-font}",
+    );
+    minify_test(".foo { font-size-adjust: -1 }", ".foo{font-size-adjust:-1}");
+  }
+
+  #[test]
+  fn test_vertical_align() {
+    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
+    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
+  }
+
+  #[test]
+  fn test_selectors() {
+    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
+    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
+    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
+    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
+    minify_test(
+      ".test:not([foo=\"bar\"]) {color:red}",
+      ".test:not([foo=bar]){color:red}",
+    );
+    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
+    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
+    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
+    minify_test(
+      ".custom-range::-webkit-slider-thumb:active {color:red}",
+      ".custom-range::-webkit-slider-thumb:active{color:red}",
+    );
+    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
+    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
+    minify_test(
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
+    );
+    minify_test(
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
+    );
+    minify_test(":host {color:red}", ":host{color:red}");
+    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
+    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
+    minify_test(
+      "custom-element::part(foo) {color:red}",
+      "custom-element::part(foo){color:red}",
+    );
+    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
+    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
+    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
+    minify_test(
+      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
+      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
+    );
+    minify_test(
+      ":has(.sibling ~ .target) {color:red}",
+      ":has(.sibling~.target){color:red}",
+    );
+    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
+    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
+    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
+    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
+    for element in [
+      "-webkit-scrollbar",
+      "-webkit-scrollbar-button",
+      "-webkit-scrollbar-track",
+      "-webkit-scrollbar-track-piece",
+      "-webkit-scrollbar-thumb",
+      "-webkit-scrollbar-corner",
+      "-webkit-resizer",
+    ] {
+      for class in [
+        "enabled",
+        "disabled",
+        "hover",
+        "active",
+        "horizontal",
+        "vertical",
+        "decrement",
+        "increment",
+        "start",
+        "end",
+        "double-button",
+        "single-button",
+        "no-button",
+        "corner-present",
+        "window-inactive",
+      ] {
+        minify_test(
+          &format!("::{}:{} {{color:red}}", element, class),
+          &format!("::{}:{}{{color:red}}", element, class),
+        );
+      }
+    }
+    for class in [
       "horizontal",
       "vertical",
       "decrement",
@@ -5229,7 +5998,7 @@ mod tests {
     prefix_test(
       "a:is(.foo > .bar) {color:red}",
       indoc! {r#"
-      a:is(.foo > .bar) {
+      .foo > a.bar {
         color: red;
       }
       "#},
@@ -5353,6 +6122,20 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ":lang(en, fr) .foo {color:red}",
+      indoc! {r#"
+      :is(:lang(en), :lang(fr)) .foo {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       "a:dir(rtl) {color:red}",
       indoc! {r#"
@@ -5390,75 +6173,400 @@ mod tests {
     minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
     minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
     minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
-  }
-
-  #[test]
-  fn test_keyframes() {
+    minify_test("::view-transition {color: red}", "::view-transition{color:red}");
     minify_test(
-      r#"
-      @keyframes test {
-        from {
-          background: green;
-        }
-
-        50% {
-          background: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{0%{background:green}50%{background:red}to{background:#00f}}",
+      "::view-transition-group(*) {color: red}",
+      "::view-transition-group(*){color:red}",
     );
     minify_test(
-      r#"
-      @keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{0%{background:red}to{background:#00f}}",
+      "::view-transition-group( card ) {color: red}",
+      "::view-transition-group(card){color:red}",
     );
     minify_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
+      "::view-transition-image-pair(card.fancy) {color: red}",
+      "::view-transition-image-pair(card.fancy){color:red}",
     );
     minify_test(
-      r#"
-      @-moz-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
+      "::view-transition-old(*.fancy.big) {color: red}",
+      "::view-transition-old(.fancy.big){color:red}",
     );
-    minify_test(r#"
-      @-webkit-keyframes test {
+    minify_test(
+      "html::view-transition-new(root) {color: red}",
+      "html::view-transition-new(root){color:red}",
+    );
+  }
+
+  #[test]
+  fn test_selector_minify() {
+    minify_test(".foo, .bar, .foo { color: red }", ".foo,.bar{color:red}");
+    minify_test(".foo:is(.bar, .bar) { color: red }", ".foo.bar{color:red}");
+    minify_test(":is(.foo) .bar { color: red }", ".foo .bar{color:red}");
+    minify_test(".foo:is(:hover) { color: red }", ".foo:hover{color:red}");
+    minify_test(".foo:is(div) { color: red }", "div.foo{color:red}");
+    minify_test(":is(div).foo, .foo:is(div) { color: red }", "div.foo{color:red}");
+    minify_test("span:is(div) { color: red }", "span:is(div){color:red}");
+    minify_test(".foo:is(.bar, #baz) { color: red }", ".foo:is(.bar,#baz){color:red}");
+    minify_test(".foo:is(.bar .baz) { color: red }", ".foo:is(.bar .baz){color:red}");
+    minify_test(".foo:where(.bar) { color: red }", ".foo:where(.bar){color:red}");
+    minify_test(".foo:not(:is(.bar)) { color: red }", ".foo:not(.bar){color:red}");
+    minify_test(".foo:not(.bar, .bar) { color: red }", ".foo:not(.bar){color:red}");
+    minify_test(".foo:not(.bar):not(*) { color: red }", ".foo:not(.bar,*){color:red}");
+    minify_test(
+      ".foo:not(.bar):not(:where(.baz)) { color: red }",
+      ".foo:not(.bar,:where(.baz)){color:red}",
+    );
+    // Combining these would reduce specificity.
+    minify_test(
+      ".foo:not(.bar):not(.baz) { color: red }",
+      ".foo:not(.bar):not(.baz){color:red}",
+    );
+    minify_test(":not(.a):not(.b) { color: red }", ":not(.a):not(.b){color:red}");
+    minify_test_with_options(
+      ":not(.a):not(.b) { color: red }",
+      ":not(.a,.b){color:red}",
+      MinifyOptions {
+        allow_specificity_changes: true,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test("*.foo, [foo=\"bar\"] { color: red }", ".foo,[foo=bar]{color:red}");
+    minify_test("[foo='bar'] { color: red }", "[foo=bar]{color:red}");
+    minify_test("[foo='bar baz'] { color: red }", "[foo=bar\\ baz]{color:red}");
+    minify_test(".foo:not([foo='bar']) { color: red }", ".foo:not([foo=bar]){color:red}");
+    minify_test("*:hover, .foo > *[foo] { color: red }", ":hover,.foo>[foo]{color:red}");
+    minify_test("* { color: red }", "*{color:red}");
+    minify_test(".foo > * { color: red }", ".foo>*{color:red}");
+    minify_test(".foo { color: red } .bar, .foo { color: red }", ".foo,.bar{color:red}");
+    prefix_test(
+      ".foo:not(.bar):not(*) { color: red }",
+      indoc! {r#"
+      .foo:not(.bar):not(*) {
+        color: red;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_is_expansion() {
+    let ie = || MinifyOptions {
+      targets: Some(Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      }),
+      ..MinifyOptions::default()
+    };
+
+    minify_test_with_options(
+      ":is(.a, .b) :is(.c, .d) { color: red }",
+      ".a .c,.a .d,.b .c,.b .d{color:red}",
+      ie(),
+    );
+    minify_test_with_options(
+      ":is(.a, .b):is(.c, .d) > .e { color: red }",
+      ".a.c>.e,.a.d>.e,.b.c>.e,.b.d>.e{color:red}",
+      ie(),
+    );
+    minify_test_with_options(".x:is(span, p) { color: red }", "span.x,p.x{color:red}", ie());
+    minify_test_with_options(
+      ":is(.a > .b, .c .d) .e { color: red }",
+      ".a>.b .e,.c .d .e{color:red}",
+      ie(),
+    );
+    minify_test_with_options(":is(.a, .a) .b { color: red }", ".a .b{color:red}", ie());
+
+    // Complex arguments cannot be inlined after a combinator.
+    minify_test_with_options(".x :is(.a .b) { color: red }", ".x :is(.a .b){color:red}", ie());
+    // Only a single type selector is allowed in a compound selector.
+    minify_test_with_options("div:is(span, .a) { color: red }", "div:is(span,.a){color:red}", ie());
+    // Specificity must be preserved unless explicitly allowed.
+    minify_test_with_options(":is(.a, #b) .c { color: red }", ":is(.a,#b) .c{color:red}", ie());
+    minify_test_with_options(":where(.a, .b) .c { color: red }", ":where(.a,.b) .c{color:red}", ie());
+    minify_test_with_options(
+      ":is(.a, #b) .c { color: red }",
+      ".a .c,#b .c{color:red}",
+      MinifyOptions {
+        allow_specificity_changes: true,
+        ..ie()
+      },
+    );
+    minify_test_with_options(
+      ":where(.a, .b) .c { color: red }",
+      ".a .c,.b .c{color:red}",
+      MinifyOptions {
+        allow_specificity_changes: true,
+        ..ie()
+      },
+    );
+
+    // The expansion is limited.
+    minify_test_with_options(
+      ":is(.a, .b) :is(.c, .d) { color: red }",
+      ":is(.a,.b) :is(.c,.d){color:red}",
+      MinifyOptions {
+        max_selector_expansion: 3,
+        ..ie()
+      },
+    );
+
+    // Simple arguments are prefixed rather than expanded where possible.
+    minify_test_with_options(
+      ":is(.a, .b) .c { color: red }",
+      ":-webkit-any(.a,.b) .c{color:red}:is(.a,.b) .c{color:red}",
+      MinifyOptions {
+        targets: Some(Browsers {
+          safari: Some(11 << 16),
+          ..Browsers::default()
+        }),
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ":is(.a .b, .c .d) .e { color: red }",
+      ".a .b .e,.c .d .e{color:red}",
+      MinifyOptions {
+        targets: Some(Browsers {
+          safari: Some(11 << 16),
+          ..Browsers::default()
+        }),
+        ..MinifyOptions::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_not_selector_list() {
+    let safari = Browsers {
+      safari: Some(8 << 16),
+      ..Browsers::default()
+    };
+
+    minify_warnings_test(
+      ".foo:not(.a, .b) { color: red }",
+      ".foo:not(.a):not(.b){color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedNotSelectorList,
+      0,
+    );
+    minify_warnings_test(
+      "a:not(:hover, [href]) { color: red }",
+      "a:not(:hover):not([href]){color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedNotSelectorList,
+      0,
+    );
+    minify_warnings_test(
+      ":not(.a, .b) .foo { color: red }",
+      ":not(.a):not(.b) .foo{color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedNotSelectorList,
+      0,
+    );
+    minify_warnings_test(
+      ".foo:not(.a .b, .c) { color: red }",
+      ".foo:not(.a .b):not(.c){color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedNotSelectorList,
+      1,
+    );
+    minify_warnings_test(
+      ".foo:not(.a, .b) { color: red }",
+      ".foo:not(.a,.b){color:red}",
+      MinifyOptions {
+        targets: Some(Browsers {
+          safari: Some(9 << 16),
+          ..Browsers::default()
+        }),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedNotSelectorList,
+      0,
+    );
+  }
+
+  #[test]
+  fn test_has_selector() {
+    let firefox = Browsers {
+      firefox: Some(100 << 16),
+      ..Browsers::default()
+    };
+    let safari = Browsers {
+      safari: Some(16 << 16),
+      ..Browsers::default()
+    };
+
+    let warnings = minify_warnings_test(
+      ".foo:has(> img) { color: red }",
+      ".foo:has(>img){color:red}",
+      MinifyOptions {
+        targets: Some(firefox),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      1,
+    );
+    assert_eq!(warnings[0].loc.as_ref().map(|loc| loc.line), Some(0));
+    minify_warnings_test(
+      ".foo:has(> img) { color: red }",
+      ".foo:has(>img){color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+    let warnings = minify_warnings_test(
+      ":is(.a, .b:has(img)) { color: red }",
+      ":is(.a,.b:has(img)){color:red}",
+      MinifyOptions {
+        targets: Some(firefox),
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      1,
+    );
+    assert_eq!(warnings[0].loc.as_ref().map(|loc| loc.line), Some(0));
+    minify_warnings_test(
+      ".foo:has(> img) { color: red }",
+      "@supports selector(:has(a)){.foo:has(>img){color:red}}",
+      MinifyOptions {
+        targets: Some(firefox),
+        wrap_unsupported_has: true,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+    minify_warnings_test(
+      ".a, .foo:has(> img) { color: red }",
+      ".a{color:red}@supports selector(:has(a)){.foo:has(>img){color:red}}",
+      MinifyOptions {
+        targets: Some(firefox),
+        wrap_unsupported_has: true,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+    minify_warnings_test(
+      "@media print { .a, .foo:has(img) { color: red } }",
+      "@media print{.a{color:red}@supports selector(:has(a)){.foo:has(img){color:red}}}",
+      MinifyOptions {
+        targets: Some(firefox),
+        wrap_unsupported_has: true,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+    minify_warnings_test(
+      "@supports selector(:has(a)) { .foo:has(img) { color: red } }",
+      "@supports selector(:has(a)){.foo:has(img){color:red}}",
+      MinifyOptions {
+        targets: Some(firefox),
+        wrap_unsupported_has: true,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+    minify_warnings_test(
+      ".a, .foo:has(> img) { color: red }",
+      ".a,.foo:has(>img){color:red}",
+      MinifyOptions {
+        targets: Some(safari),
+        wrap_unsupported_has: true,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedHasSelector,
+      0,
+    );
+  }
+
+  #[test]
+  fn test_keyframes() {
+    minify_test(
+      r#"
+      @keyframes test {
+        from {
+          background: green;
+        }
+
+        50% {
+          background: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{0%{background:green}50%{background:red}to{background:#00f}}",
+    );
+    minify_test(
+      r#"
+      @keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{0%{background:red}to{background:#00f}}",
+    );
+    minify_test(
+      r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
+    );
+    minify_test(
+      r#"
+      @-moz-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
+    );
+    minify_test(r#"
+      @-webkit-keyframes test {
         from {
           background: green;
           background-color: red;
@@ -5752,6 +6860,127 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_overridden_declarations() {
+    minify_test(
+      ".foo { color: red; background: blue; color: green }",
+      ".foo{color:green;background:#00f}",
+    );
+    minify_test(
+      ".foo { opacity: .5; z-index: 1; opacity: 1 }",
+      ".foo{z-index:1;opacity:1}",
+    );
+    minify_test(".foo { cursor: pointer; cursor: default }", ".foo{cursor:default}");
+    minify_test(".foo { display: block; display: inline }", ".foo{display:inline}");
+    minify_test(".foo { width: 10px; width: 2em }", ".foo{width:2em}");
+    minify_test(
+      ".foo { margin: 0; margin-left: 5px; margin: 10px }",
+      ".foo{margin:10px}",
+    );
+    minify_test(".foo { width: 10px; width: 20px }", ".foo{width:20px}");
+    minify_test(".foo { width: calc(100% - 10px); width: 100% }", ".foo{width:100%}");
+    minify_test(
+      ".foo { transition: opacity 1s; transition-duration: 2s }",
+      ".foo{transition:opacity 2s}",
+    );
+    minify_test(
+      ".foo { transform-origin: 0 0; transform-origin: 10px 10px }",
+      ".foo{transform-origin:10px 10px}",
+    );
+    minify_test(".foo { color: var(--x); color: red }", ".foo{color:red}");
+    minify_test(".foo { --x: 1; --x: 2 }", ".foo{--x:1;--x:2}");
+
+    // Different importance.
+    minify_test(
+      ".foo { color: red !important; color: green }",
+      ".foo{color:green;color:red!important}",
+    );
+    minify_test(
+      ".foo { color: red !important; color: green !important }",
+      ".foo{color:green!important}",
+    );
+
+    // Fallbacks for browsers that don't support newer features are preserved.
+    minify_test(
+      ".foo { color: red; color: lab(40% 56.6 39) }",
+      ".foo{color:red;color:lab(40% 56.6 39)}",
+    );
+    minify_test(
+      ".foo { width: 10px; width: max-content }",
+      ".foo{width:10px;width:max-content}",
+    );
+    minify_test(".foo { width: 10px; width: 10cqw }", ".foo{width:10px;width:10cqw}");
+    minify_test(
+      ".foo { width: 10px; width: min(10px, 5vw) }",
+      ".foo{width:10px;width:min(10px,5vw)}",
+    );
+    minify_test(".foo { color: red; color: var(--x) }", ".foo{color:red;color:var(--x)}");
+    minify_test(
+      ".foo { display: -webkit-box; display: flex }",
+      ".foo{display:-webkit-box;display:flex}",
+    );
+    minify_test(
+      ".foo { -webkit-transition: opacity 1s; transition: opacity 2s }",
+      ".foo{-webkit-transition:opacity 1s;transition:opacity 2s}",
+    );
+    minify_test(
+      ".foo { width: 10px; inline-size: 20px }",
+      ".foo{width:10px;inline-size:20px}",
+    );
+
+    // Values that are supported by all targets override earlier fallbacks.
+    minify_test(".foo { width: 10px; width: 10vw }", ".foo{width:10vw}");
+    prefix_test(
+      ".foo { width: 10px; width: 10cqw }",
+      indoc! { r#"
+        .foo {
+          width: 10cqw;
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { width: 10px; width: 10cqw }",
+      indoc! { r#"
+        .foo {
+          width: 10vw;
+          width: 10cqw;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { width: 10px; width: min(10px, 5vw) }",
+      indoc! { r#"
+        .foo {
+          width: min(10px, 5vw);
+        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: red; color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_calc() {
     minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
@@ -5967,30 +7196,57 @@ mod tests {
     );
     minify_test(
       ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+      ".foo{top:clamp(.875rem,4vw,2rem)}",
     );
     minify_test(
       ".foo { top: calc(min(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(min(1.75rem,8vw)/2)}",
+      ".foo{top:min(.875rem,4vw)}",
     );
     minify_test(
       ".foo { top: calc(max(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(max(4rem,8vw)/2)}",
+      ".foo{top:max(2rem,4vw)}",
     );
     minify_test(
       ".foo { top: calc(0.5 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+      ".foo{top:clamp(.875rem,4vw,2rem)}",
     );
     minify_test(
       ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      ".foo{top:clamp(1.75rem,8vw,4rem)}",
     );
     minify_test(
       ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      ".foo{top:clamp(1.75rem,8vw,4rem)}",
     );
 
     minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
+    minify_test(".foo { width: min(1px, min(2em, 3px)) }", ".foo{width:min(1px,2em)}");
+    minify_test(
+      ".foo { width: max(1px, max(2em, 3px), 4px) }",
+      ".foo{width:max(4px,2em)}",
+    );
+    minify_test(
+      ".foo { width: min(1px, max(2em, 3px)) }",
+      ".foo{width:min(1px,max(2em,3px))}",
+    );
+    minify_test(".foo { width: calc(1px + 2px * min(1, 2)) }", ".foo{width:3px}");
+    minify_test(".foo { width: calc(min(1px, 2em) * 2) }", ".foo{width:min(2px,4em)}");
+    minify_test(
+      ".foo { width: calc(100% - min(10px, 2em)) }",
+      ".foo{width:calc(100% - min(10px,2em))}",
+    );
+    minify_test(
+      ".foo { width: calc(100% - 2 * max(10px, 2em)) }",
+      ".foo{width:calc(100% - max(20px,4em))}",
+    );
+    minify_test(".foo { width: clamp(10px, 1em, 5px) }", ".foo{width:10px}");
+    minify_test(".foo { width: clamp(10px, 1em, 10px) }", ".foo{width:10px}");
+    minify_test(".foo { width: calc(infinity * 1px) }", ".foo{width:calc(infinity*1px)}");
+    minify_test(
+      ".foo { width: calc(-infinity * 1px) }",
+      ".foo{width:calc(-infinity*1px)}",
+    );
+    minify_test(".foo { width: calc(1px * 1e39) }", ".foo{width:calc(infinity*1px)}");
 
     prefix_test(
       ".foo { border-width: clamp(1em, 2px, 4vh) }",
@@ -6072,17 +7328,183 @@ mod tests {
       ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
     );
     minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
-  }
 
-  #[test]
-  fn test_trig() {
-    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
-    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
-    // minify_test(
-    //   ".foo { width: calc(2px * infinity); }",
-    //   ".foo{width:calc(2px*infinity)}",
-    // );
-    // minify_test(
+    prefix_test(
+      r#"
+      .foo {
+        width: clamp(1rem, 2.5vw, 2rem);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        width: 2.5vw;
+        width: max(1rem, min(2.5vw, 2rem));
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(70 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        width: clamp(1rem, 2.5vw, 2rem);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        width: clamp(1rem, 2.5vw, 2rem);
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        width: max(1rem, min(2.5vw, 2rem));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        width: max(1rem, min(2.5vw, 2rem));
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        width: max(1rem, min(2.5vw, 2rem));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        width: max(1rem, min(2.5vw, 2rem));
+      }
+    "#
+      },
+      Browsers {
+        firefox: Some(75 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        margin-left: min(10px, 5vw);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        margin-left: min(10px, 5vw);
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        margin-left: min(10px, round(up, 15px, 1vw));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        margin-left: 10px;
+        margin-left: min(10px, round(up, 15px, 1vw));
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-size: max(1rem, round(2rem, 1vw));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-size: 2rem;
+        font-size: max(1rem, round(2rem, 1vw));
+      }
+    "#
+      },
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font: min(20px, 3vw) / 1.5 serif;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: min(20px, 3vw) / 1.5 serif;
+      }
+    "#
+      },
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        width: round(10px, 1vw);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        width: 10px;
+        width: round(10px, 1vw);
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_trig() {
+    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
+    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
+    // minify_test(
+    //   ".foo { width: calc(2px * infinity); }",
+    //   ".foo{width:calc(2px*infinity)}",
+    // );
+    // minify_test(
     //   ".foo { width: calc(2px * -infinity); }",
     //   ".foo{width:calc(2px*-infinity)}",
     // );
@@ -6121,17 +7543,571 @@ mod tests {
     minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
     minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
 
-    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
-    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
-    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
-    // incompatible units
-    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
+    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
+    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
+    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
+    // incompatible units
+    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
+  }
+
+  #[test]
+  fn test_precision() {
+    printer_test_with_options(
+      ".foo { width: 1.23456px }",
+      ".foo{width:1.23456px}",
+      PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1.23456px }",
+      ".foo{width:1.23px}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1.005px }",
+      ".foo{width:1px}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(1),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: -0.001px }",
+      ".foo{width:0}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 33.33333% }",
+      ".foo{width:33.3%}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(1),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 0.33333% }",
+      ".foo{width:.33%}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { opacity: 0.123456 }",
+      ".foo{opacity:.123456}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(3),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 12.3456deg }",
+      ".foo{rotate:12deg}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(0),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { transition-duration: 1.23456s }",
+      ".foo{transition-duration:1.23456s}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { transition-timing-function: cubic-bezier(.123, .456, .789, 1) }",
+      ".foo{transition-timing-function:cubic-bezier(.123,.456,.789,1)}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(1),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { color: lab(29.2345% 39.3825 20.0664) }",
+      ".foo{color:lab(29.23% 39.38 20.07)}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { line-height: 1.5 }",
+      ".foo{line-height:1.5}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { z-index: 100 }",
+      ".foo{z-index:100}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(0),
+        ..PrinterOptions::default()
+      },
+    );
+
+    printer_test_with_options(
+      ".foo { width: 96px }",
+      ".foo{width:96px}",
+      PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 96px }",
+      ".foo{width:1in}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 24px }",
+      ".foo{width:24px}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 0.25in }",
+      ".foo{width:24px}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 72pt }",
+      ".foo{width:1in}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 12pt }",
+      ".foo{width:1pc}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 16px }",
+      ".foo{width:1pc}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1pc }",
+      ".foo{width:1pc}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 10mm }",
+      ".foo{width:1cm}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1000Q }",
+      ".foo{width:25cm}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 2mm }",
+      ".foo{width:8Q}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1.5px }",
+      ".foo{width:1.5px}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1em }",
+      ".foo{width:1em}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { width: 1.33333px }",
+      ".foo{width:1pt}",
+      PrinterOptions {
+        minify: true,
+        precision: Some(2),
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 180deg }",
+      ".foo{rotate:180deg}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 360deg }",
+      ".foo{rotate:1turn}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 90deg }",
+      ".foo{rotate:90deg}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 3.14159rad }",
+      ".foo{rotate:180deg}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 0.25turn }",
+      ".foo{rotate:90deg}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { rotate: 100grad }",
+      ".foo{rotate:90deg}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+    printer_test_with_options(
+      ".foo { transition-duration: 500ms }",
+      ".foo{transition-duration:.5s}",
+      PrinterOptions {
+        minify: true,
+        convert_units: true,
+        ..PrinterOptions::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_minify_options() {
+    let source = ".a { color: red } .a { background: blue }";
+    minify_test_with_options(source, ".a{color:red;background:#00f}", MinifyOptions::default());
+    minify_test_with_options(
+      source,
+      ".a{color:red}.a{background:#00f}",
+      MinifyOptions {
+        merge_rules: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      "@media print { .a { color: red } } @media print { .b { color: red } }",
+      "@media print{.a{color:red}}@media print{.b{color:red}}",
+      MinifyOptions {
+        merge_rules: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let source = ".a { margin-top: 1px; margin-right: 2px; margin-bottom: 3px; margin-left: 4px }";
+    minify_test_with_options(source, ".a{margin:1px 2px 3px 4px}", MinifyOptions::default());
+    minify_test_with_options(
+      source,
+      ".a{margin-top:1px;margin-right:2px;margin-bottom:3px;margin-left:4px}",
+      MinifyOptions {
+        merge_longhands: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { margin: 1px; margin-top: 2px }",
+      ".a{margin:1px;margin-top:2px}",
+      MinifyOptions {
+        merge_longhands: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let source = ".a { color: red; color: blue }";
+    minify_test_with_options(source, ".a{color:#00f}", MinifyOptions::default());
+    minify_test_with_options(
+      source,
+      ".a{color:red;color:#00f}",
+      MinifyOptions {
+        merge_longhands: false,
+        remove_overridden: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let source = ".a { width: 10px; width: max-content }";
+    minify_test_with_options(source, ".a{width:10px;width:max-content}", MinifyOptions::default());
+    minify_test_with_options(source, ".a{width:max-content}", MinifyOptions::aggressive());
+
+    let source = ".a { color: #ff0000; background: #ff000088; border-color: #aabbcc }";
+    minify_test_with_options(
+      source,
+      ".a{color:red;background:#f008;border-color:#abc}",
+      MinifyOptions::default(),
+    );
+
+    let source = ".a {} @media print { .b {} } .c { color: red }";
+    minify_test_with_options(source, ".c{color:red}", MinifyOptions::default());
+    minify_test_with_options(
+      source,
+      ".a{}@media print{.b{}}.c{color:red}",
+      MinifyOptions {
+        remove_empty_rules: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let source = ".a, .a, :is(.b) { color: red }";
+    minify_test_with_options(source, ".a,.b{color:red}", MinifyOptions::default());
+    minify_test_with_options(
+      source,
+      ".a,.a,:is(.b){color:red}",
+      MinifyOptions {
+        remove_duplicate_selectors: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let targets = Some(Browsers {
+      chrome: Some(95 << 16),
+      ..Browsers::default()
+    });
+    let source = ".a { -webkit-transition: opacity 1s; transition: opacity 1s }";
+    minify_test_with_options(
+      source,
+      ".a{transition:opacity 1s}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      source,
+      ".a{-webkit-transition:opacity 1s;transition:opacity 1s}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { -webkit-transition: opacity 1000ms; transition: opacity 1000ms }",
+      ".a{-webkit-transition:opacity 1s;transition:opacity 1s}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { -webkit-transition: opacity 1s; transition: opacity 2s }",
+      ".a{-webkit-transition:opacity 1s;transition:opacity 2s}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { -webkit-box-shadow: 0 0 2px red; box-shadow: 0 0 2px blue }",
+      ".a{-webkit-box-shadow:0 0 2px red;box-shadow:0 0 2px #00f}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { box-shadow: 0 0 2px blue; -webkit-box-shadow: 0 0 2px red }",
+      ".a{box-shadow:0 0 2px #00f;-webkit-box-shadow:0 0 2px red}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+    minify_test_with_options(
+      ".a { -webkit-box-shadow: 0 0 2px red; box-shadow: 0 0 2px red }",
+      ".a{-webkit-box-shadow:0 0 2px red;box-shadow:0 0 2px red}",
+      MinifyOptions {
+        targets,
+        remove_prefixes: false,
+        ..MinifyOptions::default()
+      },
+    );
+
+    let source = ".a { color: red } .a { -webkit-transition: opacity 1s; transition: opacity 1s; margin-top: 1px; margin-bottom: 1px; margin-left: 1px; margin-right: 1px; color: red }";
+    minify_test_with_options(
+      source,
+      ".a{color:red}.a{-webkit-transition:opacity 1s;transition:opacity 1s;margin-top:1px;margin-bottom:1px;margin-left:1px;margin-right:1px;color:red}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::safe()
+      },
+    );
+    minify_test_with_options(".a {} .b { color: red }", ".a{}.b{color:red}", MinifyOptions::safe());
+  }
+
+  #[test]
+  fn test_shorten_colors() {
+    let options = || PrinterOptions {
+      minify: true,
+      shorten_colors: false,
+      ..PrinterOptions::default()
+    };
+    let source = ".a { color: #ff0000; background: #ff000088; border-color: #aabbcc }";
+    minify_test(source, ".a{color:red;background:#f008;border-color:#abc}");
+    printer_test_with_options(
+      source,
+      ".a{color:#ff0000;background:#ff000088;border-color:#aabbcc}",
+      options(),
+    );
+    printer_test_with_options(
+      ".a { color: red; background: #f00; border-color: rgb(170, 187, 204) }",
+      ".a{color:#ff0000;background:#ff0000;border-color:#aabbcc}",
+      options(),
+    );
+    printer_test_with_options(".a { color: #f008 }", ".a{color:#ff000088}", options());
+    printer_test_with_options(
+      ".a { color: #ff000088 }",
+      ".a{color:rgba(255,0,0,.533)}",
+      PrinterOptions {
+        targets: Some(Browsers {
+          ie: Some(11 << 16),
+          ..Browsers::default()
+        }),
+        ..options()
+      },
+    );
+  }
+
+  #[test]
+  fn test_preserved_comments() {
+    minify_test(
+      "/*! license */ .a { color: red } /* regular */ .b { color: red }",
+      "/*! license */.a,.b{color:red}",
+    );
+    minify_test(
+      ".a { color: red } /*! license */ .b { color: red }",
+      ".a{color:red}/*! license */.b{color:red}",
+    );
+    minify_test(
+      "@media print { /*! license */ .a { color: red } }",
+      "@media print{/*! license */.a{color:red}}",
+    );
+    minify_test("/*! a */ /*! b */", "/*! a *//*! b */");
+    minify_test(
+      ".a { color: red } /* #__KEEP__ */ .a { background: blue } .a { margin: 0 }",
+      ".a{color:red}/* #__KEEP__ */.a{background:#00f}.a{margin:0}",
+    );
+    minify_test(
+      ".a { color: red } /*#__KEEP__*/ .a { background: blue } /* #__KEEP__ */ .a { margin: 0 }",
+      ".a{color:red}/*#__KEEP__*/.a{background:#00f}/* #__KEEP__ */.a{margin:0}",
+    );
+    minify_test("/* @__KEEP__ */ .a {} .b {}", "/* @__KEEP__ */.a{}");
+    minify_test(
+      "/* #__KEEP__ */ @media print { .a { color: red } } @media print { .b { color: red } }",
+      "/* #__KEEP__ */@media print{.a{color:red}}@media print{.b{color:red}}",
+    );
+    test(
+      indoc! {r#"
+        /*!
+         * License
+         */
+        .a { color: red }
+      "#},
+      indoc! {r#"
+        /*!
+         * License
+         */
+        .a {
+          color: red;
+        }
+      "#},
+    );
   }
 
   #[test]
@@ -6657,6 +8633,176 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      r#"
+      @media (hover) or (width > 100px) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (hover), (min-width: 100.001px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media screen and ((hover) or (not (min-width: 100px))) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media screen and (hover), screen and (max-width: 99.999px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media not (100px < width <= 200px) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (max-width: 100px), (min-width: 200.001px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media (not (hover)) or (not (width <= 100px)) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (hover: none), (min-width: 100.001px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media (hover) or (width > 100px) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (hover) or (width > 100px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(104 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_media_boolean_logic_warnings() {
+    let targets = Some(Browsers {
+      chrome: Some(85 << 16),
+      ..Browsers::default()
+    });
+
+    minify_warnings_test(
+      "@media (hover) or (pointer: fine) { .a { color: red } }",
+      "@media (hover),(pointer:fine){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      0,
+    );
+    minify_warnings_test(
+      "@media (not (hover)) and (width > 500px) { .a { color: red } }",
+      "@media (hover:none) and (min-width:500.001px){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      0,
+    );
+    minify_warnings_test(
+      "@media print and (not (hover)) { .a { color: red } }",
+      "@media print and (hover:none){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      0,
+    );
+    minify_warnings_test(
+      "@media (not (color)) or (not (prefers-reduced-motion)) { .a { color: red } }",
+      "@media (color:0),(prefers-reduced-motion:no-preference){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      0,
+    );
+    minify_warnings_test(
+      "@media not ((hover) or (pointer: fine)) { .a { color: red } }",
+      "@media not ((hover) or (pointer:fine)){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      1,
+    );
+    minify_warnings_test(
+      "@media print and (not (pointer: fine)) { .a { color: red } }",
+      "@media print and (not (pointer:fine)){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      1,
+    );
+    minify_warnings_test(
+      "@media (not (foo)) and (width > 500px) { .a { color: red } }",
+      "@media (not (foo)) and (min-width:500.001px){.a{color:red}}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedMediaBooleanLogic,
+      1,
+    );
   }
 
   #[test]
@@ -8287,43 +10433,236 @@ mod tests {
       },
     );
 
-    prefix_test(
-      r#"
-      .foo {
-        -webkit-animation: bar .2s ease-in-out;
-        -moz-animation: bar .2s ease-in-out;
-        animation: bar .2s ease-in-out;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        animation: bar .2s ease-in-out;
-      }
-    "#},
-      Browsers {
-        firefox: Some(20 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-animation: bar .2s ease-in-out;
+        -moz-animation: bar .2s ease-in-out;
+        animation: bar .2s ease-in-out;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: bar .2s ease-in-out;
+      }
+    "#},
+      Browsers {
+        firefox: Some(20 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        animation: bar 200ms var(--ease);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation: bar 200ms var(--ease);
+        -moz-animation: bar 200ms var(--ease);
+        animation: bar 200ms var(--ease);
+      }
+    "#},
+      Browsers {
+        firefox: Some(6 << 16),
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_animation_timeline() {
+    minify_test(".foo { animation-timeline: auto }", ".foo{animation-timeline:auto}");
+    minify_test(
+      ".foo { animation-timeline: --foo, none }",
+      ".foo{animation-timeline:--foo,none}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll() }",
+      ".foo{animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(nearest block) }",
+      ".foo{animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(inline root) }",
+      ".foo{animation-timeline:scroll(root inline)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(self) }",
+      ".foo{animation-timeline:scroll(self)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(block auto) }",
+      ".foo{animation-timeline:view()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(10px 20% x) }",
+      ".foo{animation-timeline:view(x 10px 20%)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(20px 20px) }",
+      ".foo{animation-timeline:view(20px)}",
+    );
+    minify_test(".foo { animation-timeline: foo }", ".foo{animation-timeline:foo}");
+    minify_test(
+      ".foo { animation-timeline: scroll(x y) }",
+      ".foo{animation-timeline:scroll(x y)}",
+    );
+
+    minify_test(
+      ".foo { animation-range-start: entry 0% }",
+      ".foo{animation-range-start:entry}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry 10% }",
+      ".foo{animation-range-start:entry 10%}",
+    );
+    minify_test(
+      ".foo { animation-range-end: exit 100% }",
+      ".foo{animation-range-end:exit}",
+    );
+    minify_test(
+      ".foo { animation-range-end: normal, 50px }",
+      ".foo{animation-range-end:normal,50px}",
+    );
+    minify_test(
+      ".foo { animation-range: normal normal }",
+      ".foo{animation-range:normal}",
+    );
+    minify_test(
+      ".foo { animation-range: cover 0% cover 100% }",
+      ".foo{animation-range:cover}",
+    );
+    minify_test(
+      ".foo { animation-range: entry exit }",
+      ".foo{animation-range:entry exit}",
+    );
+    minify_test(
+      ".foo { animation-range: entry-crossing 10% contain 90%, 10% }",
+      ".foo{animation-range:entry-crossing 10% contain 90%,10%}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry; animation-range-end: exit }",
+      ".foo{animation-range:entry exit}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry, cover; animation-range-end: exit }",
+      ".foo{animation-range-start:entry,cover;animation-range-end:exit}",
+    );
+
+    // The animation shorthand resets animation-timeline and animation-range.
+    minify_test(
+      ".foo { animation-timeline: scroll(); animation-range: entry; animation: foo 1s }",
+      ".foo{animation:foo 1s}",
+    );
+    minify_test(
+      ".foo { animation: foo 1s; animation-timeline: scroll(); animation-range: entry }",
+      ".foo{animation:foo 1s;animation-timeline:scroll();animation-range:entry}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(); -webkit-animation: foo 1s }",
+      ".foo{-webkit-animation:foo 1s;animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(); animation: var(--foo) }",
+      ".foo{animation-timeline:scroll();animation:var(--foo)}",
+    );
+
+    minify_test(
+      ".foo { scroll-timeline-name: --foo }",
+      ".foo{scroll-timeline-name:--foo}",
+    );
+    minify_test(
+      ".foo { scroll-timeline-axis: block, x }",
+      ".foo{scroll-timeline-axis:block,x}",
+    );
+    minify_test(".foo { scroll-timeline: --foo block }", ".foo{scroll-timeline:--foo}");
+    minify_test(
+      ".foo { scroll-timeline: --foo inline, none y }",
+      ".foo{scroll-timeline:--foo inline,none y}",
+    );
+    minify_test(
+      ".foo { scroll-timeline-name: --foo; scroll-timeline-axis: x }",
+      ".foo{scroll-timeline:--foo x}",
+    );
+    minify_test(
+      ".foo { scroll-timeline-name: --foo, --bar; scroll-timeline-axis: x }",
+      ".foo{scroll-timeline-name:--foo,--bar;scroll-timeline-axis:x}",
+    );
+    minify_test(
+      ".foo { scroll-timeline: --foo; scroll-timeline-axis: y }",
+      ".foo{scroll-timeline:--foo y}",
+    );
+    minify_test(
+      ".foo { view-timeline-inset: auto 10% }",
+      ".foo{view-timeline-inset:auto 10%}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo 10px x }",
+      ".foo{view-timeline:--foo x 10px}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo block auto auto }",
+      ".foo{view-timeline:--foo}",
+    );
+    minify_test(
+      ".foo { view-timeline-name: --foo; view-timeline-axis: inline; view-timeline-inset: 10px 20px }",
+      ".foo{view-timeline:--foo inline 10px 20px}",
+    );
+    minify_test(
+      ".foo { view-timeline-name: --foo; view-timeline-axis: inline }",
+      ".foo{view-timeline-name:--foo;view-timeline-axis:inline}",
+    );
+    minify_test(".foo { view-timeline-name: foo }", ".foo{view-timeline-name:foo}");
+    minify_test(".foo { timeline-scope: none }", ".foo{timeline-scope:none}");
+    minify_test(".foo { timeline-scope: all }", ".foo{timeline-scope:all}");
+    minify_test(
+      ".foo { timeline-scope: --foo, --bar }",
+      ".foo{timeline-scope:--foo,--bar}",
+    );
+  }
+
+  #[test]
+  fn test_scroll_timeline_rule() {
+    minify_test(
+      "@scroll-timeline --foo { source: selector(#bar); orientation: VERTICAL; }",
+      "@scroll-timeline --foo{source:selector(#bar);orientation:vertical}",
     );
-    prefix_test(
+    minify_test(
+      "@scroll-timeline --foo { source: auto; orientation: inline }",
+      "@scroll-timeline --foo{source:auto;orientation:inline}",
+    );
+    minify_test(
+      "@scroll-timeline --foo { source: none; scroll-offsets: 0px, 100px }",
+      "@scroll-timeline --foo{source:none;scroll-offsets:0px,100px}",
+    );
+    minify_test(
+      "@scroll-timeline --foo { source: selector(.bar) }",
+      "@scroll-timeline --foo{source:selector(.bar)}",
+    );
+    minify_test("@scroll-timeline --foo {}", "@scroll-timeline --foo{}");
+    test(
       r#"
-      .foo {
-        animation: bar 200ms var(--ease);
+      @scroll-timeline --foo {
+        source: selector(#bar);
+        orientation: block;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-animation: bar 200ms var(--ease);
-        -moz-animation: bar 200ms var(--ease);
-        animation: bar 200ms var(--ease);
+      @scroll-timeline --foo {
+        source: selector(#bar);
+        orientation: block;
       }
     "#},
-      Browsers {
-        firefox: Some(6 << 16),
-        safari: Some(6 << 16),
-        ..Browsers::default()
-      },
+    );
+    error_test(
+      "@scroll-timeline foo {}",
+      ParserError::UnexpectedToken(Token::Ident("foo".into())),
     );
   }
 
@@ -9640,6 +11979,120 @@ mod tests {
     minify_test(".foo { font-palette: --Custom; }", ".foo{font-palette:--Custom}");
   }
 
+  #[test]
+  fn test_view_transitions() {
+    minify_test(".foo { view-transition-name: card }", ".foo{view-transition-name:card}");
+    minify_test(".foo { view-transition-name: none }", ".foo{view-transition-name:none}");
+    minify_test(
+      ".foo { view-transition-name: match-element }",
+      ".foo{view-transition-name:match-element}",
+    );
+    minify_test(
+      ".foo { view-transition-class: fancy  big }",
+      ".foo{view-transition-class:fancy big}",
+    );
+    minify_test(
+      ".foo { view-transition-class: none }",
+      ".foo{view-transition-class:none}",
+    );
+    minify_test(
+      ".foo { view-transition-name: inherit }",
+      ".foo{view-transition-name:inherit}",
+    );
+
+    minify_test(
+      "@view-transition { navigation: auto; }",
+      "@view-transition{navigation:auto}",
+    );
+    minify_test(
+      "@view-transition { navigation: none; types: slide forwards }",
+      "@view-transition{navigation:none;types:slide forwards}",
+    );
+    minify_test(
+      "@view-transition { types: none; navigation: auto }",
+      "@view-transition{types:none;navigation:auto}",
+    );
+    minify_test(
+      "@view-transition { navigation: var(--navigation) }",
+      "@view-transition{navigation:var(--navigation)}",
+    );
+    test(
+      r#"
+      @view-transition {
+        navigation: auto;
+        types: slide;
+      }
+    "#,
+      indoc! {r#"
+      @view-transition {
+        navigation: auto;
+        types: slide;
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_view_transition_warnings() {
+    let safari = Some(Browsers {
+      safari: Some(17 << 16),
+      ..Browsers::default()
+    });
+    let chrome = Some(Browsers {
+      chrome: Some(111 << 16),
+      ..Browsers::default()
+    });
+
+    let source = r#"
+      @view-transition { navigation: auto }
+      .foo { view-transition-name: foo }
+      .bar { view-transition-class: bar }
+      ::view-transition-group(foo) { animation-duration: 1s }
+      .baz { color: red }
+    "#;
+    let expected = "@view-transition{navigation:auto}.foo{view-transition-name:foo}.bar{view-transition-class:bar}::view-transition-group(foo){animation-duration:1s}.baz{color:red}";
+    minify_warnings_test(
+      source,
+      expected,
+      MinifyOptions {
+        targets: safari,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedViewTransitions,
+      4,
+    );
+    minify_warnings_test(
+      source,
+      expected,
+      MinifyOptions {
+        targets: chrome,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedViewTransitions,
+      0,
+    );
+    minify_warnings_test(
+      ".foo { view-transition-name: var(--foo) }",
+      ".foo{view-transition-name:var(--foo)}",
+      MinifyOptions {
+        targets: safari,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedViewTransitions,
+      1,
+    );
+    minify_warnings_test(
+      ".foo { color: red }",
+      ".foo{color:red}",
+      MinifyOptions {
+        targets: safari,
+        ..MinifyOptions::default()
+      },
+      MinifyErrorKind::UnsupportedViewTransitions,
+      0,
+    );
+  }
+
   #[test]
   fn test_page_rule() {
     minify_test("@page {margin: 0.5cm}", "@page{margin:.5cm}");
@@ -9788,6 +12241,34 @@ mod tests {
       }
     "#},
     );
+    minify_test(
+      "@supports selector(a  >  b) { .test { foo: bar } }",
+      "@supports selector(a>b){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports selector(.a   .b) { .test { foo: bar } }",
+      "@supports selector(.a .b){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports not selector(:has(a)) { .test { foo: bar } }",
+      "@supports not selector(:has(a)){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports selector(a, b) { .test { foo: bar } }",
+      "@supports selector(a, b){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports selector(:foo) { .test { foo: bar } }",
+      "@supports selector(:foo){.test{foo:bar}}",
+    );
+    minify_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); @supports selector(svg|a  >  b) { .test { foo: bar } }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";@supports selector(svg|a>b){.test{foo:bar}}",
+    );
+    minify_test(
+      "@import url(foo.css) supports(selector(a  >  b));",
+      "@import \"foo.css\" supports(selector(a>b));",
+    );
   }
 
   #[test]
@@ -11757,35 +14238,210 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        top: 1px;
-        bottom: 3px;
-        left: 2px;
-        right: 4px;
+        top: 1px;
+        bottom: 3px;
+        left: 2px;
+        right: 4px;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_overflow() {
+    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+
+    test(
+      r#"
+      .foo {
+        overflow-x: hidden;
+        overflow-y: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: var(--y);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: var(--y);
+      }
+    "#},
+    );
+    prefix_test(
+      r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow-x: hidden;
+        overflow-y: auto;
+      }
+    "#},
+      Browsers {
+        chrome: Some(67 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        overflow: hidden hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden;
+      }
+    "#},
+      Browsers {
+        chrome: Some(67 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
+      Browsers {
+        chrome: Some(68 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
+    prefix_test(
+      r#"
+      .foo {
+        text-overflow: ellipsis;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
+      }
+    "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-overflow: ellipsis;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(4 << 16),
+        opera: Some(14 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_overflow() {
-    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+  fn test_scroll() {
+    minify_test(".foo { scroll-behavior: smooth }", ".foo{scroll-behavior:smooth}");
+    minify_test(
+      ".foo { overscroll-behavior: contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: none none }",
+      ".foo{overscroll-behavior:none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: auto contain }",
+      ".foo{overscroll-behavior:auto contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-inline: none }",
+      ".foo{overscroll-behavior-inline:none}",
+    );
+    minify_test(".foo { overscroll-behavior: foo }", ".foo{overscroll-behavior:foo}");
+    minify_test(".foo { scroll-snap-type: none }", ".foo{scroll-snap-type:none}");
+    minify_test(".foo { scroll-snap-type: y proximity }", ".foo{scroll-snap-type:y}");
+    minify_test(
+      ".foo { scroll-snap-type: both mandatory }",
+      ".foo{scroll-snap-type:both mandatory}",
+    );
+    minify_test(
+      ".foo { -webkit-scroll-snap-type: mandatory }",
+      ".foo{-webkit-scroll-snap-type:mandatory}",
+    );
+    minify_test(
+      ".foo { scroll-snap-type: mandatory }",
+      ".foo{scroll-snap-type:mandatory}",
+    );
+    minify_test(
+      ".foo { scroll-snap-align: start start }",
+      ".foo{scroll-snap-align:start}",
+    );
+    minify_test(
+      ".foo { scroll-snap-align: center end }",
+      ".foo{scroll-snap-align:center end}",
+    );
+    minify_test(".foo { scroll-snap-stop: always }", ".foo{scroll-snap-stop:always}");
 
     test(
       r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        overscroll-behavior: contain none;
       }
     "#},
     );
@@ -11793,96 +14449,118 @@ mod tests {
     test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: auto;
+        overscroll-behavior: none;
+        overscroll-behavior-y: contain;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        overscroll-behavior: none contain;
       }
     "#},
     );
+
     test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-inline: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-inline: none;
       }
     "#},
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden auto;
+        scroll-snap-type: y mandatory;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        -webkit-scroll-snap-type: mandatory;
+        -ms-scroll-snap-type: mandatory;
+        scroll-snap-type: y mandatory;
       }
     "#},
       Browsers {
-        chrome: Some(67 << 16),
+        safari: Some(9 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden hidden;
+        -ms-scroll-snap-type: proximity;
+        scroll-snap-type: x;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
+        -ms-scroll-snap-type: proximity;
+        scroll-snap-type: x;
       }
     "#},
       Browsers {
-        chrome: Some(67 << 16),
+        edge: Some(16 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden auto;
+        -ms-scroll-snap-type: mandatory;
+        scroll-snap-type: x mandatory;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        scroll-snap-type: x mandatory;
       }
     "#},
       Browsers {
-        chrome: Some(68 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
     prefix_test(
       r#"
       .foo {
-        text-overflow: ellipsis;
+        scroll-snap-type: mandatory;
       }
     "#,
       indoc! {r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        scroll-snap-type: mandatory;
       }
     "#},
       Browsers {
-        safari: Some(4 << 16),
-        opera: Some(10 << 16),
+        safari: Some(9 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
@@ -11890,18 +14568,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        scroll-snap-type: var(--type);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-overflow: ellipsis;
+        scroll-snap-type: var(--type);
       }
     "#},
       Browsers {
-        safari: Some(4 << 16),
-        opera: Some(14 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
@@ -12258,6 +14934,92 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_content() {
+    minify_test(".foo { content: normal }", ".foo{content:normal}");
+    minify_test(".foo { content: none }", ".foo{content:none}");
+    minify_test(".foo { content: 'foo' }", ".foo{content:\"foo\"}");
+    minify_test(".foo { content: \"foo\" 'bar' }", ".foo{content:\"foobar\"}");
+    minify_test(
+      ".foo { content: \"a\" attr(title) \"b\" }",
+      ".foo{content:\"a\" attr(title) \"b\"}",
+    );
+    minify_test(".foo { content: counter(foo) }", ".foo{content:counter(foo)}");
+    minify_test(".foo { content: counter(foo, decimal) }", ".foo{content:counter(foo)}");
+    minify_test(
+      ".foo { content: counter(foo, upper-roman) }",
+      ".foo{content:counter(foo,upper-roman)}",
+    );
+    minify_test(
+      ".foo { content: counters(foo, '.', decimal) }",
+      ".foo{content:counters(foo,\".\")}",
+    );
+    minify_test(
+      ".foo { content: counters(foo, \".\", lower-alpha) \" \" }",
+      ".foo{content:counters(foo,\".\",lower-alpha) \" \"}",
+    );
+    minify_test(
+      ".foo { content: open-quote \"x\" no-close-quote }",
+      ".foo{content:open-quote \"x\" no-close-quote}",
+    );
+    minify_test(".foo { content: url(foo.png) }", ".foo{content:url(foo.png)}");
+    minify_test(
+      ".foo { content: linear-gradient(#ff0000, #0000ff) }",
+      ".foo{content:linear-gradient(red,#00f)}",
+    );
+    minify_test(
+      ".foo { content: url(star.png) / \"New!\" }",
+      ".foo{content:url(star.png)/\"New!\"}",
+    );
+    minify_test(
+      ".foo { content: \"→\" / \"next\" ' ' attr(title) }",
+      ".foo{content:\"→\"/\"next \" attr(title)}",
+    );
+    minify_test(
+      ".foo { content: url(a.png) / url(b.png) }",
+      ".foo{content:url(a.png)/url(b.png)}",
+    );
+    minify_test(".foo { content: leader(dotted) }", ".foo{content:leader(dotted)}");
+    minify_test(".foo { content: var(--foo) }", ".foo{content:var(--foo)}");
+
+    minify_test(".foo { quotes: auto }", ".foo{quotes:auto}");
+    minify_test(".foo { quotes: none }", ".foo{quotes:none}");
+    minify_test(".foo { quotes: match-parent }", ".foo{quotes:match-parent}");
+    minify_test(".foo { quotes: '«' '»' }", ".foo{quotes:\"«\" \"»\"}");
+    minify_test(
+      ".foo { quotes: \"“\" \"”\" \"‘\" \"’\" }",
+      ".foo{quotes:\"“\" \"”\" \"‘\" \"’\"}",
+    );
+    minify_test(".foo { quotes: \"“\" \"”\" \"‘\" }", ".foo{quotes:\"“\" \"”\" \"‘\"}");
+  }
+
+  #[test]
+  fn test_counters() {
+    minify_test(".foo { counter-reset: none }", ".foo{counter-reset:none}");
+    minify_test(".foo { counter-reset: foo }", ".foo{counter-reset:foo}");
+    minify_test(".foo { counter-reset: foo 0 }", ".foo{counter-reset:foo}");
+    minify_test(".foo { counter-reset: foo 1 bar }", ".foo{counter-reset:foo 1 bar}");
+    minify_test(
+      ".foo { counter-reset: reversed(foo) }",
+      ".foo{counter-reset:reversed(foo)}",
+    );
+    minify_test(
+      ".foo { counter-reset: reversed(foo) 0 }",
+      ".foo{counter-reset:reversed(foo) 0}",
+    );
+    minify_test(".foo { counter-increment: foo 1 }", ".foo{counter-increment:foo}");
+    minify_test(
+      ".foo { counter-increment: foo 2 bar -1 }",
+      ".foo{counter-increment:foo 2 bar -1}",
+    );
+    minify_test(".foo { counter-set: foo 0 bar 3 }", ".foo{counter-set:foo bar 3}");
+    minify_test(".foo { counter-set: reversed(foo) }", ".foo{counter-set:reversed(foo)}");
+    minify_test(
+      ".foo { counter-increment: foo none }",
+      ".foo{counter-increment:foo none}",
+    );
+  }
+
   #[test]
   fn test_image_set() {
     // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
@@ -14519,70 +17281,259 @@ mod tests {
         &format!(".foo{{color:color({}/0)}}", result_color_space),
       );
 
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} none none none), color({0} none none none)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} none none none)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} none none none), color({0} .5 .6 .7)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .5 .6 .7)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3), color({0} none none none)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .1 .2 .3)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 none), color({0} .5 .6 .7)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .3 .4 .7)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3), color({0} .5 .6 none)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .3 .4 .3)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} none .2 .3), color({0} .5 none .7)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .5 .2 .5)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .3 .4 .5)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7 / 0.5)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .3 .4 .5/.5)}}", result_color_space),
-      );
-      minify_test(
-        &format!(
-          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7 / none)) }}",
-          color_space
-        ),
-        &format!(".foo{{color:color({} .3 .4 .5/none)}}", result_color_space),
-      );
-    }
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} none none none), color({0} none none none)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} none none none)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} none none none), color({0} .5 .6 .7)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .5 .6 .7)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3), color({0} none none none)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .1 .2 .3)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 none), color({0} .5 .6 .7)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .3 .4 .7)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3), color({0} .5 .6 none)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .3 .4 .3)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} none .2 .3), color({0} .5 none .7)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .5 .2 .5)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .3 .4 .5)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7 / 0.5)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .3 .4 .5/.5)}}", result_color_space),
+      );
+      minify_test(
+        &format!(
+          ".foo {{ color: color-mix(in {0}, color({0} .1 .2 .3 / none), color({0} .5 .6 .7 / none)) }}",
+          color_space
+        ),
+        &format!(".foo{{color:color({} .3 .4 .5/none)}}", result_color_space),
+      );
+    }
+  }
+
+  #[test]
+  fn test_relative_color() {
+    minify_test(".foo { color: rgb(from #f00 r g b / 50%) }", ".foo{color:#ff000080}");
+    minify_test(".foo { color: rgb(from rgb(255 0 0) b g r) }", ".foo{color:#00f}");
+    minify_test(".foo { color: hsl(from red calc(h + 120) s l) }", ".foo{color:#0f0}");
+    minify_test(".foo { color: hsl(from red h 50% l) }", ".foo{color:#bf4040}");
+    minify_test(".foo { color: hwb(from #0f0 h w b / .5) }", ".foo{color:#00ff0080}");
+    minify_test(
+      ".foo { color: lab(from #f00 l a b) }",
+      ".foo{color:lab(54.2905% 80.8049 69.891)}",
+    );
+    minify_test(
+      ".foo { color: oklch(from #f00 calc(l * .8) c h) }",
+      ".foo{color:oklch(50.2364% .257683 29.2339)}",
+    );
+    minify_test(
+      ".foo { color: color(from red srgb r g b / alpha) }",
+      ".foo{color:color(srgb 1)}",
+    );
+    minify_test(
+      ".foo { color: color(from red xyz x y z) }",
+      ".foo{color:color(xyz .412391 .212639 .0193308)}",
+    );
+    minify_test(
+      ".foo { color: rgba(from rgb(from #123456 b g r) r 0 b) }",
+      ".foo{color:#560012}",
+    );
+    minify_test(".foo { --x: rgb(from #00f r g b / 25%) }", ".foo{--x:#0000ff40}");
+
+    // Relative colors that depend on the element are preserved.
+    minify_test(
+      ".foo { color: rgb(from currentColor r g b / 50%) }",
+      ".foo{color:rgb(from currentColor r g b/.5)}",
+    );
+    minify_test(
+      ".foo { color: lch(from currentColor calc(l + 10) c h / calc(alpha / 2)) }",
+      ".foo{color:lch(from currentColor calc(l + 10) c h/calc(alpha/2))}",
+    );
+    minify_test(
+      ".foo { color: rgb(from currentColor 50% g min(b, 100)) }",
+      ".foo{color:rgb(from currentColor 127.5 g min(b,100))}",
+    );
+    minify_test(
+      ".foo { color: rgb(from var(--x) r g b) }",
+      ".foo{color:rgb(from var(--x)r g b)}",
+    );
+
+    // Channel keywords must be valid for the color function.
+    minify_test(".foo { color: rgb(from red h s l) }", ".foo{color:rgb(from red h s l)}");
+
+    prefix_test(
+      ".foo { color: oklch(from #f00 calc(l * .8) c h) }",
+      indoc! {r#"
+      .foo {
+        color: #c40000;
+        color: lab(39.6314% 81.5697 83.9913);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_light_dark() {
+    minify_test(
+      ".foo { color: light-dark(red, blue) }",
+      ".foo{color:light-dark(red,#00f)}",
+    );
+    minify_test(".foo { color: light-dark(red, rgb(255 0 0)) }", ".foo{color:red}");
+    minify_test(
+      ".foo { color: light-dark(currentColor, Canvas) }",
+      ".foo{color:light-dark(currentColor,canvas)}",
+    );
+    minify_test(".foo { color-scheme: normal }", ".foo{color-scheme:normal}");
+    minify_test(".foo { color-scheme: dark light }", ".foo{color-scheme:light dark}");
+    minify_test(".foo { color-scheme: only dark }", ".foo{color-scheme:dark only}");
+    minify_test(".foo { color-scheme: light custom }", ".foo{color-scheme:light}");
+    minify_test(".foo { color-scheme: only }", ".foo{color-scheme:only}");
+
+    prefix_test(
+      r#"
+      :root {
+        color-scheme: light dark;
+      }
+
+      .foo {
+        color: light-dark(red, lab(40% 20 30));
+      }
+    "#,
+      indoc! {r#"
+      :root {
+        --parcel-light: initial;
+        --parcel-dark: ;
+        color-scheme: light dark;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        :root {
+          --parcel-light: ;
+          --parcel-dark: initial;
+        }
+      }
+
+      .foo {
+        color: var(--parcel-light, red) var(--parcel-dark, #85512e);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { color-scheme: dark; color: light-dark(red, blue) }",
+      indoc! {r#"
+      .foo {
+        --parcel-light: ;
+        --parcel-dark: initial;
+        color-scheme: dark;
+        color: var(--parcel-light, red) var(--parcel-dark, #00f);
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { color-scheme: light dark; color: light-dark(red, lab(40% 20 30)) }",
+      indoc! {r#"
+      .foo {
+        color-scheme: light dark;
+        color: light-dark(red, #85512e);
+        color: light-dark(red, lab(40% 20 30));
+      }
+    "#},
+      Browsers {
+        chrome: Some(123 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_system_colors() {
+    minify_test(".foo { color: CanvasText }", ".foo{color:canvastext}");
+    minify_test(".foo { background: Canvas }", ".foo{background:canvas}");
+    minify_test(
+      ".foo { color: rgb(from Canvas r g b / 50%) }",
+      ".foo{color:rgb(from canvas r g b/.5)}",
+    );
+    minify_test(
+      ".foo { color: color-mix(in srgb, Canvas, red) }",
+      ".foo{color:color-mix(in srgb,Canvas,red)}",
+    );
+
+    prefix_test(
+      ".foo { color: AccentColor }",
+      indoc! {r#"
+      .foo {
+        color: highlight;
+        color: accentcolor;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { color: AccentColor }",
+      indoc! {r#"
+      .foo {
+        color: accentcolor;
+      }
+    "#},
+      Browsers {
+        firefox: Some(103 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[cfg(feature = "grid")]
@@ -16096,7 +19047,7 @@ mod tests {
           color: #00f;
         }
 
-        :is(.foo, .bar) + .baz, :is(.foo, .bar).qux {
+        .foo + .baz, .bar + .baz, .foo.qux, .bar.qux {
           color: red;
         }
       "#},
@@ -16163,7 +19114,22 @@ mod tests {
         }
       "#,
       indoc! {r#"
-        :is(.error, .invalid):hover > .baz {
+        .error:hover > .baz, .invalid:hover > .baz {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .a, .b, .c {
+          .d, .e, .f {
+            .g & { color: red; }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .g :is(.a .d, .b .d, .c .d, .a .e, .b .e, .c .e, .a .f, .b .f, .c .f) {
           color: red;
         }
       "#},
@@ -16772,20 +19738,271 @@ mod tests {
       "#},
     );
 
-    nesting_test_no_targets(
+    nesting_test_no_targets(
+      r#"
+        .error, .invalid {
+          &:hover > .baz { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        .error, .invalid {
+          &:hover > .baz {
+            color: red;
+          }
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: blue;
+          .bar { color: red; }
+          > .baz { color: green; }
+          + .qux { color: yellow; }
+          div span { color: purple; }
+          .parent & { color: pink; }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .foo .bar {
+          color: red;
+        }
+
+        .foo > .baz {
+          color: green;
+        }
+
+        .foo + .qux {
+          color: #ff0;
+        }
+
+        .foo div span {
+          color: purple;
+        }
+
+        .parent .foo {
+          color: pink;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: blue;
+          a:hover { color: red; }
+          background: green;
+          .bar { color: yellow; }
+          padding: 0;
+          margin: 0;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .foo a:hover {
+          color: red;
+        }
+
+        .foo {
+          background: green;
+        }
+
+        .foo .bar {
+          color: #ff0;
+        }
+
+        .foo {
+          margin: 0;
+          padding: 0;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          @container card (width > 100px) {
+            width: 10px;
+            .bar { color: red; }
+          }
+          @layer base {
+            height: 10px;
+          }
+        }
+      "#,
+      indoc! {r#"
+        @container card (width > 100px) {
+          .foo {
+            width: 10px;
+          }
+
+          .foo .bar {
+            color: red;
+          }
+        }
+
+        @layer base {
+          .foo {
+            height: 10px;
+          }
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo, .bar > .baz {
+          .qux { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        :is(.foo, .bar > .baz) .qux {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo, .bar {
+          :not(&) { color: red; }
+          @media (min-width: 100px) {
+            color: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :not(.foo, .bar) {
+          color: red;
+        }
+
+        @media (min-width: 100px) {
+          .foo, .bar {
+            color: green;
+          }
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .foo {
+          color: blue;
+          .bar { color: red; }
+          > .baz { color: green; }
+          background: yellow;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          & .bar {
+            color: red;
+          }
+
+          & > .baz {
+            color: green;
+          }
+
+          & {
+            background: #ff0;
+          }
+        }
+      "#},
+    );
+  }
+
+  #[test]
+  fn test_sass_nesting() {
+    let options = || ParserOptions {
+      nesting: true,
+      sass_nesting: true,
+      ..ParserOptions::default()
+    };
+
+    minify_test_with_parser_options(
       r#"
-        .error, .invalid {
-          &:hover > .baz { color: red; }
+        .block {
+          color: red;
+          &__element { color: blue; }
+          &--modifier { color: green; }
+          .theme-dark & { color: white; }
         }
       "#,
-      indoc! {r#"
-        .error, .invalid {
-          &:hover > .baz {
-            color: red;
-          }
-        }
-      "#},
-    );
+      ".block{color:red}.block__element{color:#00f}.block--modifier{color:green}.theme-dark .block{color:#fff}",
+      options(),
+    );
+    minify_test_with_parser_options(
+      ".block { &__element { &--modifier { color: red } } }",
+      ".block__element--modifier{color:red}",
+      options(),
+    );
+    minify_test_with_parser_options(
+      ".a, .b > .c { &__x:hover { color: red } }",
+      ".a__x:hover,.b>.c__x:hover{color:red}",
+      options(),
+    );
+    minify_test_with_parser_options("#foo { &-bar { color: red } }", "#foo-bar{color:red}", options());
+    minify_test_with_parser_options("div { &-foo { color: red } }", "div-foo{color:red}", options());
+    minify_test_with_parser_options(
+      ".foo { @media print { &__bar { color: red } } }",
+      "@media print{.foo__bar{color:red}}",
+      options(),
+    );
+    minify_test_with_parser_options(".foo { & .bar { color: red } }", ".foo{& .bar{color:red}}", options());
+    minify_test_with_parser_options(
+      ".foo { .bar & { &__baz { color: red } } }",
+      ".bar .foo__baz{color:red}",
+      options(),
+    );
+    minify_test_with_parser_options(
+      ".a, .b { & { &__x { color: red } } }",
+      ".a__x,.b__x{color:red}",
+      options(),
+    );
+    minify_test_with_parser_options(
+      ".a, .b { & { & { &__x .c { color: red } } } }",
+      ".a__x .c,.b__x .c{color:red}",
+      options(),
+    );
+    minify_test_with_parser_options(
+      ".a, .b { .c & { &__x { color: red } } }",
+      ".c .a__x,.c .b__x{color:red}",
+      options(),
+    );
+    printer_error_test(
+      ".foo:hover { &__bar { color: red } }",
+      PrinterErrorKind::InvalidNestingSuffix,
+      options(),
+    );
+    printer_error_test(
+      ".foo::before { &__bar { color: red } }",
+      PrinterErrorKind::InvalidNestingSuffix,
+      options(),
+    );
+
+    // Suffixes are escaped when printed without the parent selectors.
+    let stylesheet = StyleSheet::parse(".foo { &__x\\.y { color: red } }", options()).unwrap();
+    let selectors = match &stylesheet.rules.0[0] {
+      CssRule::Style(style) => match &style.rules.0[0] {
+        CssRule::Style(style) => &style.selectors,
+        _ => unreachable!(),
+      },
+      _ => unreachable!(),
+    };
+    let mut code = String::new();
+    let mut printer = Printer::new(&mut code, PrinterOptions::default());
+    selectors.to_css_with_context(&mut printer, None).unwrap();
+    assert_eq!(code, "&__x\\.y");
   }
 
   #[test]
@@ -16926,6 +20143,118 @@ mod tests {
       Default::default(),
     );
 
+    css_modules_test(
+      r#"
+      .scroller {
+        scroll-timeline: --scroll inline;
+      }
+
+      .subject {
+        view-timeline-name: --view;
+      }
+
+      .parent {
+        timeline-scope: --view;
+      }
+
+      .target {
+        animation: fade 1s;
+        animation-timeline: --scroll, --view, scroll(), --timeline;
+      }
+
+      @scroll-timeline --timeline {
+        source: selector(#scroller);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_scroller {
+        scroll-timeline: --EgL3uq_scroll inline;
+      }
+
+      .EgL3uq_subject {
+        view-timeline-name: --EgL3uq_view;
+      }
+
+      .EgL3uq_parent {
+        timeline-scope: --EgL3uq_view;
+      }
+
+      .EgL3uq_target {
+        animation: EgL3uq_fade 1s;
+        animation-timeline: --EgL3uq_scroll, --EgL3uq_view, scroll(), --EgL3uq_timeline;
+      }
+
+      @scroll-timeline --EgL3uq_timeline {
+        source: selector(#EgL3uq_scroller);
+      }
+    "#},
+      map! {
+        "scroller" => "EgL3uq_scroller",
+        "subject" => "EgL3uq_subject",
+        "parent" => "EgL3uq_parent",
+        "target" => "EgL3uq_target",
+        "--scroll" => "--EgL3uq_scroll" referenced: true,
+        "--view" => "--EgL3uq_view" referenced: true,
+        "--timeline" => "--EgL3uq_timeline" referenced: true,
+        "fade" => "EgL3uq_fade" referenced: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .card {
+        view-transition-name: card;
+        view-transition-class: fancy;
+      }
+
+      .page {
+        view-transition-name: root;
+      }
+
+      ::view-transition-group(card) {
+        animation-duration: 1s;
+      }
+
+      ::view-transition-old(root) {
+        animation: none;
+      }
+
+      ::view-transition-new(*.fancy) {
+        animation: none;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_card {
+        view-transition-name: EgL3uq_card;
+        view-transition-class: fancy;
+      }
+
+      .EgL3uq_page {
+        view-transition-name: root;
+      }
+
+      ::view-transition-group(EgL3uq_card) {
+        animation-duration: 1s;
+      }
+
+      ::view-transition-old(root) {
+        animation: none;
+      }
+
+      ::view-transition-new(.fancy) {
+        animation: none;
+      }
+    "#},
+      map! {
+        "card" => "EgL3uq_card" referenced: true,
+        "page" => "EgL3uq_page"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
     #[cfg(feature = "grid")]
     css_modules_test(
       r#"
@@ -17368,6 +20697,63 @@ mod tests {
         ..Default::default()
       },
     );
+
+    css_modules_test(
+      r#"
+      .foo {
+        counter-reset: section reversed(item) 5;
+        counter-increment: item -1;
+      }
+
+      .foo::before {
+        content: counters(section, ".") ": " counter(item, circles);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        counter-reset: EgL3uq_section reversed(EgL3uq_item) 5;
+        counter-increment: EgL3uq_item -1;
+      }
+
+      .EgL3uq_foo:before {
+        content: counters(EgL3uq_section, ".") ": " counter(EgL3uq_item, EgL3uq_circles);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "section" => "EgL3uq_section" referenced: true,
+        "item" => "EgL3uq_item" referenced: true,
+        "circles" => "EgL3uq_circles" referenced: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      ol {
+        counter-reset: list-item 2;
+        counter-increment: list-item;
+      }
+
+      li::marker {
+        content: counter(list-item) ". ";
+      }
+    "#,
+      indoc! {r#"
+      ol {
+        counter-reset: list-item 2;
+        counter-increment: list-item;
+      }
+
+      li::marker {
+        content: counter(list-item) ". ";
+      }
+    "#},
+      map! {},
+      HashMap::new(),
+      Default::default(),
+    );
   }
 
   #[test]
@@ -17667,6 +21053,40 @@ mod tests {
       .unwrap();
     let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
     assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo {
+        scroll-timeline: --foo x, --bar;
+        view-timeline: --baz block 10px;
+        animation-timeline: --foo;
+      }
+
+      .bar {
+        scroll-timeline-name: --baz;
+        scroll-timeline-axis: inline;
+      }
+
+      @scroll-timeline --bar {
+        source: auto;
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .foo {
+        animation-timeline: --foo;
+        scroll-timeline: --foo x, none;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["--bar", "--baz"].iter().map(|s| String::from(*s)).collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
   }
 
   #[test]
@@ -18143,8 +21563,6 @@ mod tests {
       indoc! { r#"
         .foo {
           -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
           mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
           -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
           -webkit-mask-composite: source-out;
@@ -18624,7 +22042,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media ((color) or (hover)) and (width > 1024px) {
+      @media (color) and (width > 1024px), (hover) and (width > 1024px) {
         .a {
           color: green;
         }
@@ -18895,7 +22313,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media print and ((color) or (script)) {
+      @media print and (color), print and (script) {
         .a {
           color: green;
         }
@@ -19286,6 +22704,12 @@ mod tests {
       vec![("foo.png", "Vwkwkq")],
     );
 
+    dep_test(
+      ".foo::before { content: url(foo.png) \"bar\" }",
+      ".foo:before{content:url(\"Vwkwkq\") \"bar\"}",
+      vec![("foo.png", "Vwkwkq")],
+    );
+
     dep_error_test(
       ".foo { --test: url(\"foo.png\") }",
       PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
@@ -19469,6 +22893,102 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_layer_polyfill() {
+    prefix_test(
+      r#"
+      @layer reset, base;
+      @layer base {
+        .a { color: red }
+      }
+      @layer reset {
+        .a { color: green }
+      }
+      .a { color: blue }
+    "#,
+      indoc! {r#"
+      .a:not(#\#) {
+        color: red;
+      }
+
+      .a {
+        color: green;
+      }
+
+      .a:not(#\#):not(#\#) {
+        color: #00f;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    // Nested layers have lower precedence than their parent, and each layer
+    // must outweigh the maximum number of ids in any selector.
+    prefix_test(
+      r#"
+      @layer base {
+        #foo .a { color: red }
+        @layer inner {
+          .b { color: green }
+        }
+      }
+      @layer {
+        .c::before { color: blue }
+      }
+      @media print {
+        @layer base.inner {
+          .d { color: yellow }
+        }
+      }
+    "#,
+      indoc! {r#"
+      #foo .a:not(#\#):not(#\#) {
+        color: red;
+      }
+
+      .b {
+        color: green;
+      }
+
+      .c:not(#\#):not(#\#):not(#\#):not(#\#):before {
+        color: #00f;
+      }
+
+      @media print {
+        .d {
+          color: #ff0;
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @layer base {
+        .a { color: red }
+      }
+    "#,
+      indoc! {r#"
+      @layer base {
+        .a {
+          color: red;
+        }
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_property() {
     minify_test(
@@ -20010,6 +23530,36 @@ mod tests {
     minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
     minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
     minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
+    prefix_test(
+      ".foo { width: 10cqi; height: 20cqb; margin-left: 5cqmin; max-width: 50% }",
+      indoc! { r#"
+        .foo {
+          width: 10vw;
+          width: 10cqi;
+          height: 20vh;
+          height: 20cqb;
+          max-width: 50%;
+          margin-left: 5vmin;
+          margin-left: 5cqmin;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { width: 10cqi }",
+      indoc! { r#"
+        .foo {
+          width: 10cqi;
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
+    );
 
     // Unlike in @media, there is no need to convert the range syntax in @container,
     // because browsers all support this syntax.
@@ -20050,6 +23600,41 @@ mod tests {
       },
     );
 
+    minify_test(
+      "@container style(--theme: dark) { .foo { color: red } }",
+      "@container style(--theme:dark){.foo{color:red}}",
+    );
+    minify_test(
+      "@container card (min-width: 400px) and style(--theme) { .foo { color: red } }",
+      "@container card (min-width:400px) and style(--theme){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style((--a: 1) or (not (--b))) { .foo { color: red } }",
+      "@container style((--a:1) or (not (--b))){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(color: #ff0000) { .foo { color: red } }",
+      "@container style(color:red){.foo{color:red}}",
+    );
+    minify_test(
+      "@container not scroll-state(stuck: top) { .foo { color: red } }",
+      "@container not scroll-state(stuck:top){.foo{color:red}}",
+    );
+    minify_test(
+      "@container scroll-state((snapped: x) and (scrollable)) or (inline-size > 30em) { .foo { color: red } }",
+      "@container scroll-state((snapped:x) and (scrollable)) or (inline-size>30em){.foo{color:red}}",
+    );
+    minify_test(
+      "@container (not (width > 100px)) and (orientation: portrait) { .foo { color: red } }",
+      "@container (not (width>100px)) and (orientation:portrait){.foo{color:red}}",
+    );
+    error_test(
+      "@container (min-resolution: 2dppx) {}",
+      ParserError::InvalidContainerQuery,
+    );
+    error_test("@container scroll-state(snapped: top) {}", ParserError::InvalidValue);
+    error_test("@container foo(--a) {}", ParserError::InvalidContainerQuery);
+
     // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
     // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
     // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
@@ -1,3 +1,5 @@
+use crate::properties::writing_mode::{Direction, WritingMode};
+
 #[derive(Debug, PartialEq)]
 pub enum PropertyCategory {
   Logical,
@@ -25,3 +27,73 @@ pub enum LogicalGroup {
   MinSize,
   MaxSize,
 }
+
+/// A logical side of a box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum LogicalSide {
+  BlockStart,
+  BlockEnd,
+  InlineStart,
+  InlineEnd,
+}
+
+impl LogicalSide {
+  pub fn is_inline(&self) -> bool {
+    matches!(self, LogicalSide::InlineStart | LogicalSide::InlineEnd)
+  }
+}
+
+/// A physical side of a box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PhysicalSide {
+  Top,
+  Right,
+  Bottom,
+  Left,
+}
+
+impl PhysicalSide {
+  fn opposite(&self) -> PhysicalSide {
+    match self {
+      PhysicalSide::Top => PhysicalSide::Bottom,
+      PhysicalSide::Right => PhysicalSide::Left,
+      PhysicalSide::Bottom => PhysicalSide::Top,
+      PhysicalSide::Left => PhysicalSide::Right,
+    }
+  }
+}
+
+/// Maps a logical side to a physical side for the given writing mode and direction.
+/// See https://drafts.csswg.org/css-writing-modes-4/#logical-to-physical.
+pub(crate) fn to_physical_side(
+  side: LogicalSide,
+  writing_mode: WritingMode,
+  direction: Direction,
+) -> PhysicalSide {
+  let start = match side {
+    LogicalSide::BlockStart | LogicalSide::BlockEnd => match writing_mode {
+      WritingMode::HorizontalTb => PhysicalSide::Top,
+      WritingMode::VerticalRl | WritingMode::SidewaysRl => PhysicalSide::Right,
+      WritingMode::VerticalLr | WritingMode::SidewaysLr => PhysicalSide::Left,
+    },
+    LogicalSide::InlineStart | LogicalSide::InlineEnd => {
+      let start = match writing_mode {
+        WritingMode::HorizontalTb => PhysicalSide::Left,
+        WritingMode::VerticalRl | WritingMode::SidewaysRl | WritingMode::VerticalLr => PhysicalSide::Top,
+        // Lines in sideways-lr run from bottom to top.
+        WritingMode::SidewaysLr => PhysicalSide::Bottom,
+      };
+
+      if direction == Direction::Rtl {
+        start.opposite()
+      } else {
+        start
+      }
+    }
+  };
+
+  match side {
+    LogicalSide::BlockStart | LogicalSide::InlineStart => start,
+    LogicalSide::BlockEnd | LogicalSide::InlineEnd => start.opposite(),
+  }
+}
@@ -26,6 +26,9 @@ struct CliArgs {
   /// Enable parsing CSS nesting
   #[clap(long, value_parser)]
   nesting: bool,
+  /// Enable Sass-style nesting suffixes, e.g. `&__element`
+  #[clap(long, requires = "nesting", value_parser)]
+  sass_nesting: bool,
   /// Enable parsing custom media queries
   #[clap(long, value_parser)]
   custom_media: bool,
@@ -95,6 +98,7 @@ pub fn main() -> Result<(), std::io::Error> {
   } else {
     None
   };
+  let minify_warnings = Arc::new(RwLock::new(Vec::new()));
 
   let mut source_map = if cli_args.sourcemap {
     Some(SourceMap::new("/"))
@@ -105,6 +109,7 @@ pub fn main() -> Result<(), std::io::Error> {
   let res = {
     let mut options = ParserOptions {
       nesting: cli_args.nesting,
+      sass_nesting: cli_args.sass_nesting,
       css_modules,
       custom_media: cli_args.custom_media,
       error_recovery: cli_args.error_recovery,
@@ -132,6 +137,7 @@ pub fn main() -> Result<(), std::io::Error> {
     stylesheet
       .minify(MinifyOptions {
         targets,
+        warnings: Some(minify_warnings.clone()),
         ..MinifyOptions::default()
       })
       .unwrap();
@@ -172,6 +178,11 @@ pub fn main() -> Result<(), std::io::Error> {
     }
   }
 
+  let minify_warnings = Arc::try_unwrap(minify_warnings).unwrap().into_inner().unwrap();
+  for warning in minify_warnings {
+    eprintln!("{}", warning);
+  }
+
   if let Some(output_file) = &cli_args.output_file {
     let mut code = res.code;
     if cli_args.sourcemap {
@@ -97,6 +97,28 @@ impl<'i> MediaList<'i> {
     Ok(())
   }
 
+  /// Rewrites media queries that use level 4 boolean logic, e.g. `not` or `or` within a condition,
+  /// into an equivalent list of level 3 media queries.
+  ///
+  /// Returns an error if no equivalent exists, in which case the media query list is left unchanged.
+  pub(crate) fn downlevel_conditions(&mut self) -> Result<(), ()> {
+    if self.media_queries.iter().all(|mq| mq.is_level_3()) {
+      return Ok(());
+    }
+
+    let mut media_queries = Vec::new();
+    for query in &self.media_queries {
+      for mq in query.downlevel_conditions()? {
+        if !media_queries.contains(&mq) {
+          media_queries.push(mq);
+        }
+      }
+    }
+
+    self.media_queries = media_queries;
+    Ok(())
+  }
+
   /// Combines the given media query list into this one. The resulting media query list
   /// matches if either of the original media query lists would have matched.
   pub fn or(&mut self, b: &MediaList<'i>) {
@@ -236,6 +258,62 @@ impl<'i> MediaQuery<'i> {
     Ok(())
   }
 
+  /// Returns whether the condition can be expressed with level 3 syntax, i.e. a list of
+  /// media features joined by `and`.
+  fn is_level_3(&self) -> bool {
+    match &self.condition {
+      None | Some(MediaCondition::Feature(..)) => true,
+      Some(MediaCondition::Operation(conditions, Operator::And)) => {
+        conditions.iter().all(|c| matches!(c, MediaCondition::Feature(..)))
+      }
+      _ => false,
+    }
+  }
+
+  fn downlevel_conditions(&self) -> Result<Vec<MediaQuery<'i>>, ()> {
+    if self.is_level_3() {
+      return Ok(vec![self.clone()]);
+    }
+
+    // `not` negates the whole query, so it cannot be distributed over a list.
+    if self.qualifier == Some(Qualifier::Not) {
+      return Err(());
+    }
+
+    let condition = self.condition.as_ref().unwrap();
+    let mut media_queries = Vec::new();
+    for conjunction in condition.to_dnf(false)? {
+      if conjunction.iter().any(|(_, negated)| *negated) {
+        // A single negated feature can be written as `not all and (feature)`.
+        if conjunction.len() != 1 || self.qualifier.is_some() || self.media_type != MediaType::All {
+          return Err(());
+        }
+
+        let (feature, _) = conjunction.into_iter().next().unwrap();
+        media_queries.push(MediaQuery {
+          qualifier: Some(Qualifier::Not),
+          media_type: MediaType::All,
+          condition: Some(MediaCondition::Feature(feature)),
+        });
+      } else {
+        let mut features: Vec<_> = conjunction.into_iter().map(|(f, _)| MediaCondition::Feature(f)).collect();
+        let condition = if features.len() == 1 {
+          features.pop().unwrap()
+        } else {
+          MediaCondition::Operation(features, Operator::And)
+        };
+
+        media_queries.push(MediaQuery {
+          qualifier: self.qualifier,
+          media_type: self.media_type.clone(),
+          condition: Some(condition),
+        });
+      }
+    }
+
+    Ok(media_queries)
+  }
+
   /// Returns whether the media query is guaranteed to always match.
   pub fn always_matches(&self) -> bool {
     self.qualifier == None && self.media_type == MediaType::All && self.condition == None
@@ -361,6 +439,12 @@ enum_property! {
   }
 }
 
+/// A list of media features joined by `and`, each of which may be negated.
+type Conjunction<'i> = Vec<(MediaFeature<'i>, bool)>;
+
+/// The maximum number of media queries a condition may expand to when lowering boolean logic.
+const MAX_CONJUNCTIONS: usize = 32;
+
 /// Represents a media condition.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(
@@ -424,6 +508,53 @@ impl<'i> MediaCondition<'i> {
     }
   }
 
+  /// Converts the condition to disjunctive normal form, i.e. a list of conjunctions, any of which
+  /// match. Negations are pushed down to individual features using De Morgan's laws, and removed
+  /// entirely where a feature has an equivalent inverse.
+  fn to_dnf(&self, negated: bool) -> Result<Vec<Conjunction<'i>>, ()> {
+    match self {
+      MediaCondition::Feature(feature) => {
+        if negated {
+          if let Some(features) = feature.negate() {
+            return Ok(features.into_iter().map(|f| vec![(f, false)]).collect());
+          }
+        }
+        Ok(vec![vec![(feature.clone(), negated)]])
+      }
+      MediaCondition::Not(condition) => condition.to_dnf(!negated),
+      MediaCondition::InParens(condition) => condition.to_dnf(negated),
+      MediaCondition::Operation(conditions, operator) => {
+        let is_and = (*operator == Operator::And) != negated;
+        let mut result = if is_and { vec![vec![]] } else { vec![] };
+        for condition in conditions {
+          let dnf = condition.to_dnf(negated)?;
+          if is_and {
+            let mut product = Vec::with_capacity(result.len() * dnf.len());
+            for a in &result {
+              for b in &dnf {
+                let mut conjunction: Conjunction<'i> = a.clone();
+                for item in b {
+                  if !conjunction.contains(item) {
+                    conjunction.push(item.clone());
+                  }
+                }
+                product.push(conjunction);
+              }
+            }
+            result = product;
+          } else {
+            result.extend(dnf);
+          }
+
+          if result.len() > MAX_CONJUNCTIONS {
+            return Err(());
+          }
+        }
+        Ok(result)
+      }
+    }
+  }
+
   /// Parse a media condition in parentheses.
   pub fn parse_in_parens<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     input.expect_parenthesis_block()?;
@@ -527,6 +658,17 @@ impl MediaFeatureComparison {
       MediaFeatureComparison::Equal => MediaFeatureComparison::Equal,
     }
   }
+
+  /// Returns the comparison that matches exactly when this one does not.
+  fn negate(&self) -> Option<MediaFeatureComparison> {
+    match self {
+      MediaFeatureComparison::GreaterThan => Some(MediaFeatureComparison::LessThanEqual),
+      MediaFeatureComparison::GreaterThanEqual => Some(MediaFeatureComparison::LessThan),
+      MediaFeatureComparison::LessThan => Some(MediaFeatureComparison::GreaterThanEqual),
+      MediaFeatureComparison::LessThanEqual => Some(MediaFeatureComparison::GreaterThan),
+      MediaFeatureComparison::Equal => None,
+    }
+  }
 }
 
 /// A [media feature](https://drafts.csswg.org/mediaqueries/#typedef-media-feature)
@@ -633,6 +775,66 @@ impl<'i> MediaFeature<'i> {
       Ok(MediaFeature::Range { name, operator, value })
     }
   }
+
+  /// Returns a list of features, any of which match exactly when this feature does not.
+  fn negate(&self) -> Option<Vec<MediaFeature<'i>>> {
+    match self {
+      MediaFeature::Boolean(name) => {
+        // Discrete features evaluate to false in a boolean context for exactly one value.
+        let value = match_ignore_ascii_case! { &name,
+          "hover" | "any-hover" | "pointer" | "any-pointer" | "update" | "overflow-block" | "overflow-inline"
+            | "scripting" | "forced-colors" | "inverted-colors" => MediaFeatureValue::Ident("none".into()),
+          "prefers-reduced-motion" | "prefers-reduced-transparency" | "prefers-reduced-data"
+            | "prefers-contrast" => MediaFeatureValue::Ident("no-preference".into()),
+          "color" | "color-index" | "monochrome" | "grid" => MediaFeatureValue::Number(0.0),
+          _ => return None
+        };
+
+        Some(vec![MediaFeature::Plain {
+          name: name.clone(),
+          value,
+        }])
+      }
+      MediaFeature::Plain { name, value } => {
+        let (name, operator) = if name.len() > 4 && name[..4].eq_ignore_ascii_case("min-") {
+          (&name[4..], MediaFeatureComparison::LessThan)
+        } else if name.len() > 4 && name[..4].eq_ignore_ascii_case("max-") {
+          (&name[4..], MediaFeatureComparison::GreaterThan)
+        } else {
+          return None;
+        };
+
+        Some(vec![MediaFeature::Range {
+          name: name.to_owned().into(),
+          operator,
+          value: value.clone(),
+        }])
+      }
+      MediaFeature::Range { name, operator, value } => Some(vec![MediaFeature::Range {
+        name: name.clone(),
+        operator: operator.negate()?,
+        value: value.clone(),
+      }]),
+      MediaFeature::Interval {
+        name,
+        start,
+        start_operator,
+        end,
+        end_operator,
+      } => Some(vec![
+        MediaFeature::Range {
+          name: name.clone(),
+          operator: start_operator.opposite().negate()?,
+          value: start.clone(),
+        },
+        MediaFeature::Range {
+          name: name.clone(),
+          operator: end_operator.negate()?,
+          value: end.clone(),
+        },
+      ]),
+    }
+  }
 }
 
 impl<'i> ToCss for MediaFeature<'i> {
@@ -2,12 +2,15 @@ use crate::declaration::{parse_declaration, DeclarationBlock, DeclarationList};
 use crate::error::{Error, ParserError};
 use crate::media_query::*;
 use crate::properties::custom::TokenList;
-use crate::rules::container::{ContainerName, ContainerRule};
+use crate::rules::container::{ContainerCondition, ContainerName, ContainerRule};
 use crate::rules::font_palette_values::FontPaletteValuesRule;
 use crate::rules::layer::{LayerBlockRule, LayerStatementRule};
 use crate::rules::property::PropertyRule;
+use crate::rules::scroll_timeline::ScrollTimelineRule;
+use crate::rules::view_transition::ViewTransitionRule;
 use crate::rules::viewport::ViewportRule;
 use crate::rules::{
+  comment::CommentRule,
   counter_style::CounterStyleRule,
   custom_media::CustomMediaRule,
   document::MozDocumentRule,
@@ -25,7 +28,7 @@ use crate::rules::{
   CssRule, CssRuleList, Location,
 };
 use crate::selector::{SelectorParser, Selectors};
-use crate::traits::Parse;
+use crate::traits::{Parse, ParseWithOptions};
 use crate::values::ident::{CustomIdent, DashedIdent};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
@@ -39,8 +42,12 @@ use std::sync::{Arc, RwLock};
 pub struct ParserOptions<'o, 'i> {
   /// Filename to use in error messages.
   pub filename: String,
-  /// Whether the enable the [CSS nesting](https://www.w3.org/TR/css-nesting-1/) draft syntax.
+  /// Whether to enable [CSS nesting](https://drafts.csswg.org/css-nesting/) syntax.
   pub nesting: bool,
+  /// Whether to allow Sass-style suffixes after the nesting selector, e.g. `&__element` or `&--modifier`,
+  /// which are concatenated with the parent selector. Rules using them are always compiled away.
+  /// Requires `nesting` to be enabled.
+  pub sass_nesting: bool,
   /// Whether to enable the [custom media](https://drafts.csswg.org/mediaqueries-5/#custom-mq) draft syntax.
   pub custom_media: bool,
   /// Whether the enable [CSS modules](https://github.com/css-modules/css-modules).
@@ -145,7 +152,11 @@ pub enum AtRulePrelude<'i> {
   /// An @property prelude.
   Property(DashedIdent<'i>),
   /// A @container prelude.
-  Container(Option<ContainerName<'i>>, MediaCondition<'i>),
+  Container(Option<ContainerName<'i>>, ContainerCondition<'i>),
+  /// A @view-transition prelude.
+  ViewTransition,
+  /// A @scroll-timeline prelude, with its name.
+  ScrollTimeline(DashedIdent<'i>),
   /// An unknown prelude.
   Unknown(CowArcStr<'i>, TokenList<'i>),
 }
@@ -178,8 +189,16 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a, 'o, 'i> {
         };
 
         let supports = if input.try_parse(|input| input.expect_function_matching("supports")).is_ok() {
+          let selector_parser = SelectorParser {
+            default_namespace: &self.default_namespace,
+            namespace_prefixes: &self.namespace_prefixes,
+            is_nesting_allowed: false,
+            options: self.options,
+          };
           Some(input.parse_nested_block(|input| {
-            input.try_parse(SupportsCondition::parse).or_else(|_| SupportsCondition::parse_declaration(input))
+            input
+              .try_parse(|input| SupportsCondition::parse_with_selector_parser(input, &selector_parser))
+              .or_else(|_| SupportsCondition::parse_declaration(input))
           })?)
         } else {
           None
@@ -339,7 +358,13 @@ impl<'a, 'o, 'b, 'i> NestedRuleParser<'a, 'o, 'i> {
 
     let mut iter = RuleListParser::new_for_nested_rule(input, nested_parser);
     let mut rules = Vec::new();
-    while let Some(result) = iter.next() {
+    loop {
+      parse_comments(iter.input, self.options.source_index, &mut rules);
+      let result = match iter.next() {
+        Some(result) => result,
+        None => break,
+      };
+
       match result {
         Ok(CssRule::Ignored) => {}
         Ok(rule) => rules.push(rule),
@@ -366,6 +391,32 @@ impl<'a, 'o, 'b, 'i> NestedRuleParser<'a, 'o, 'i> {
   }
 }
 
+/// Consumes any whitespace and comments before the next rule, and adds
+/// those that should be preserved in the output to the list of rules.
+pub(crate) fn parse_comments<'i, 't>(input: &mut Parser<'i, 't>, source_index: u32, rules: &mut Vec<CssRule<'i>>) {
+  loop {
+    let state = input.state();
+    match input.next_including_whitespace_and_comments() {
+      Ok(&Token::WhiteSpace(..)) => {}
+      Ok(&Token::Comment(comment)) => {
+        let loc = state.source_location();
+        let loc = Location {
+          source_index,
+          line: loc.line,
+          column: loc.column,
+        };
+        if let Some(comment) = CommentRule::new(comment, loc) {
+          rules.push(CssRule::Comment(comment));
+        }
+      }
+      _ => {
+        input.reset(&state);
+        break;
+      }
+    }
+  }
+}
+
 impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
   type Prelude = AtRulePrelude<'i>;
   type AtRule = CssRule<'i>;
@@ -382,7 +433,13 @@ impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
         Ok(AtRulePrelude::Media(media))
       },
       "supports" => {
-        let cond = SupportsCondition::parse(input)?;
+        let selector_parser = SelectorParser {
+          default_namespace: self.default_namespace,
+          namespace_prefixes: self.namespace_prefixes,
+          is_nesting_allowed: false,
+          options: self.options,
+        };
+        let cond = SupportsCondition::parse_with_selector_parser(input, &selector_parser)?;
         Ok(AtRulePrelude::Supports(cond))
       },
       "font-face" => {
@@ -468,9 +525,16 @@ impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
       },
       "container" => {
         let name = input.try_parse(ContainerName::parse).ok();
-        let condition = MediaCondition::parse(input, true)?;
+        let condition = ContainerCondition::parse_with_options(input, &self.options)?;
         Ok(AtRulePrelude::Container(name, condition))
       },
+      "view-transition" => {
+        Ok(AtRulePrelude::ViewTransition)
+      },
+      "scroll-timeline" => {
+        let name = DashedIdent::parse(input)?;
+        Ok(AtRulePrelude::ScrollTimeline(name))
+      },
       _ => {
         self.options.warn(input.new_error(BasicParseErrorKind::AtRuleInvalid(name.clone())));
         input.skip_whitespace();
@@ -582,6 +646,10 @@ impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
         }))
       }
       AtRulePrelude::Property(name) => Ok(CssRule::Property(PropertyRule::parse(name, input, loc)?)),
+      AtRulePrelude::ViewTransition => Ok(CssRule::ViewTransition(ViewTransitionRule::parse(input, loc)?)),
+      AtRulePrelude::ScrollTimeline(name) => {
+        Ok(CssRule::ScrollTimeline(ScrollTimelineRule::parse(name, input, loc)?))
+      }
       AtRulePrelude::Import(..)
       | AtRulePrelude::Namespace(..)
       | AtRulePrelude::CustomMedia(..)
@@ -670,7 +738,7 @@ fn parse_declarations_and_nested_rules<'a, 'o, 'i, 't>(
   let mut important_declarations = DeclarationList::new();
   let mut declarations = DeclarationList::new();
   let mut rules = CssRuleList(vec![]);
-  let parser = StyleRuleParser {
+  let mut parser = StyleRuleParser {
     default_namespace,
     namespace_prefixes,
     options,
@@ -679,23 +747,39 @@ fn parse_declarations_and_nested_rules<'a, 'o, 'i, 't>(
     rules: &mut rules,
   };
 
-  let mut declaration_parser = DeclarationListParser::new(input, parser);
-  let mut last = declaration_parser.input.state();
-  while let Some(decl) = declaration_parser.next() {
-    match decl {
-      Ok(_) => {}
-      _ => {
-        declaration_parser.input.reset(&last);
-        break;
+  // https://drafts.csswg.org/css-syntax/#consume-block-contents
+  // Declarations and nested rules may be interleaved. Rules may begin with an identifier,
+  // so declarations are tried first, falling back to parsing a nested rule.
+  loop {
+    let start = input.state();
+    let result = match input.next_including_whitespace_and_comments() {
+      Ok(&Token::WhiteSpace(_)) | Ok(&Token::Comment(_)) | Ok(&Token::Semicolon) => continue,
+      Ok(&Token::Ident(ref name)) => {
+        let name = name.clone();
+        let result = input.parse_until_after(Delimiter::Semicolon, |input| {
+          input.expect_colon()?;
+          parser.parse_declaration(name, input, &start)
+        });
+        match result {
+          Ok(()) => Ok(()),
+          Err(err) => {
+            input.reset(&start);
+            parser.parse_nested_qualified_rule(input, &start).map_err(|_| err)
+          }
+        }
       }
-    }
-
-    last = declaration_parser.input.state();
-  }
+      Ok(&Token::AtKeyword(ref name)) => {
+        let name = name.clone();
+        parser.parse_nested_at_rule(name, input, &start)
+      }
+      Ok(_) => {
+        input.reset(&start);
+        parser.parse_nested_qualified_rule(input, &start)
+      }
+      Err(_) => break,
+    };
 
-  let mut iter = RuleListParser::new_for_nested_rule(declaration_parser.input, declaration_parser.parser);
-  while let Some(result) = iter.next() {
-    if let Err((err, _)) = result {
+    if let Err(err) = result {
       if options.error_recovery {
         options.warn(err);
         continue;
@@ -722,30 +806,129 @@ pub struct StyleRuleParser<'a, 'o, 'i> {
   rules: &'a mut CssRuleList<'i>,
 }
 
-/// Parse a declaration within {} block: `color: blue`
-impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for StyleRuleParser<'a, 'o, 'i> {
-  type Declaration = ();
-  type Error = ParserError<'i>;
-
-  fn parse_value<'t>(
+impl<'a, 'o, 'i> StyleRuleParser<'a, 'o, 'i> {
+  /// Parses a declaration within a style rule: `color: blue`.
+  fn parse_declaration<'t>(
     &mut self,
     name: CowRcStr<'i>,
-    input: &mut cssparser::Parser<'i, 't>,
-  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
-    if !self.rules.0.is_empty() {
-      // Declarations cannot come after nested rules.
-      return Err(input.new_custom_error(ParserError::InvalidNesting));
+    input: &mut Parser<'i, 't>,
+    start: &ParserState,
+  ) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    // A declaration containing a {} block is a nested rule instead, e.g. `a:hover { ... }`.
+    // Custom properties are an exception, as they may contain arbitrary blocks.
+    if !name.starts_with("--") {
+      let state = input.state();
+      while let Ok(token) = input.next() {
+        if matches!(token, Token::CurlyBracketBlock) {
+          return Err(input.new_custom_error(ParserError::InvalidNesting));
+        }
+      }
+      input.reset(&state);
+    }
+
+    if self.rules.0.is_empty() {
+      return parse_declaration(
+        name,
+        input,
+        self.declarations,
+        self.important_declarations,
+        self.options,
+      );
     }
+
+    // Declarations that come after nested rules are wrapped in a `& { ... }` rule,
+    // so that they still apply after the preceding rules.
+    let mut declarations = DeclarationList::new();
+    let mut important_declarations = DeclarationList::new();
     parse_declaration(
       name,
       input,
-      &mut self.declarations,
-      &mut self.important_declarations,
-      &self.options,
-    )
+      &mut declarations,
+      &mut important_declarations,
+      self.options,
+    )?;
+
+    if !matches!(self.rules.0.last(), Some(CssRule::Style(style)) if is_nested_declarations(style)) {
+      let loc = start.source_location();
+      self.rules.0.push(CssRule::Style(StyleRule {
+        selectors: SelectorList(smallvec::smallvec![parcel_selectors::parser::Selector::from_vec2(
+          vec![parcel_selectors::parser::Component::Nesting]
+        )]),
+        declarations: DeclarationBlock {
+          declarations: vec![],
+          important_declarations: vec![],
+        },
+        vendor_prefix: VendorPrefix::empty(),
+        rules: CssRuleList(vec![]),
+        loc: Location {
+          source_index: self.options.source_index,
+          line: loc.line,
+          column: loc.column,
+        },
+      }));
+    }
+
+    if let Some(CssRule::Style(style)) = self.rules.0.last_mut() {
+      style.declarations.declarations.extend(declarations);
+      style.declarations.important_declarations.extend(important_declarations);
+    }
+
+    Ok(())
+  }
+
+  fn parse_nested_qualified_rule<'t>(
+    &mut self,
+    input: &mut Parser<'i, 't>,
+    start: &ParserState,
+  ) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    // Nested qualified rules end at a semicolon, so an invalid declaration does not consume the following rules.
+    let prelude = input.parse_until_before(Delimiter::CurlyBracketBlock | Delimiter::Semicolon, |input| {
+      QualifiedRuleParser::parse_prelude(self, input)
+    });
+    match input.next() {
+      Ok(&Token::CurlyBracketBlock) => {
+        let prelude = prelude?;
+        input.parse_nested_block(|input| QualifiedRuleParser::parse_block(self, prelude, start, input))
+      }
+      _ => {
+        prelude?;
+        Err(start.source_location().new_custom_error(ParserError::InvalidNesting))
+      }
+    }
+  }
+
+  fn parse_nested_at_rule<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+    start: &ParserState,
+  ) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    let prelude = input.parse_until_before(Delimiter::CurlyBracketBlock | Delimiter::Semicolon, |input| {
+      AtRuleParser::parse_prelude(self, name, input)
+    });
+    match input.next() {
+      Ok(&Token::CurlyBracketBlock) => {
+        let prelude = prelude?;
+        input.parse_nested_block(|input| AtRuleParser::parse_block(self, prelude, start, input))
+      }
+      _ => {
+        prelude?;
+        Err(input.new_error(BasicParseErrorKind::AtRuleBodyInvalid))
+      }
+    }
   }
 }
 
+/// Returns whether a style rule was created to hold declarations following nested rules.
+fn is_nested_declarations(style: &StyleRule) -> bool {
+  style.rules.0.is_empty()
+    && style.selectors.0.len() == 1
+    && matches!(
+      style.selectors.0[0].iter_raw_match_order().as_slice(),
+      [parcel_selectors::parser::Component::Nesting]
+    )
+}
+
 impl<'a, 'o, 'i> AtRuleParser<'i> for StyleRuleParser<'a, 'o, 'i> {
   type Prelude = AtRulePrelude<'i>;
   type AtRule = ();
@@ -762,9 +945,25 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for StyleRuleParser<'a, 'o, 'i> {
         Ok(AtRulePrelude::Media(media))
       },
       "supports" => {
-        let cond = SupportsCondition::parse(input)?;
+        let selector_parser = SelectorParser {
+          default_namespace: self.default_namespace,
+          namespace_prefixes: self.namespace_prefixes,
+          is_nesting_allowed: false,
+          options: self.options,
+        };
+        let cond = SupportsCondition::parse_with_selector_parser(input, &selector_parser)?;
         Ok(AtRulePrelude::Supports(cond))
       },
+      "container" => {
+        let name = input.try_parse(ContainerName::parse).ok();
+        let condition = ContainerCondition::parse_with_options(input, &self.options)?;
+        Ok(AtRulePrelude::Container(name, condition))
+      },
+      "layer" => {
+        let name = input.try_parse(LayerName::parse).ok();
+        input.expect_exhausted()?;
+        Ok(AtRulePrelude::Layer(name.into_iter().collect()))
+      },
       "nest" => {
         let selector_parser = SelectorParser {
           default_namespace: self.default_namespace,
@@ -820,6 +1019,35 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for StyleRuleParser<'a, 'o, 'i> {
         }));
         Ok(())
       }
+      AtRulePrelude::Container(name, condition) => {
+        self.rules.0.push(CssRule::Container(ContainerRule {
+          name,
+          condition,
+          rules: parse_nested_at_rule(
+            input,
+            self.options.source_index,
+            self.default_namespace,
+            self.namespace_prefixes,
+            self.options,
+          )?,
+          loc,
+        }));
+        Ok(())
+      }
+      AtRulePrelude::Layer(names) => {
+        self.rules.0.push(CssRule::LayerBlock(LayerBlockRule {
+          name: names.into_iter().next(),
+          rules: parse_nested_at_rule(
+            input,
+            self.options.source_index,
+            self.default_namespace,
+            self.namespace_prefixes,
+            self.options,
+          )?,
+          loc,
+        }));
+        Ok(())
+      }
       AtRulePrelude::Nest(selectors) => {
         let (declarations, rules) = parse_declarations_and_nested_rules(
           input,
@@ -861,7 +1089,7 @@ fn parse_nested_at_rule<'a, 'o, 'i, 't>(
     column: loc.column,
   };
 
-  // Declarations can be immediately within @media and @supports blocks that are nested within a parent style rule.
+  // Declarations can be immediately within conditional group rules that are nested within a parent style rule.
   // These act the same way as if they were nested within a `& { ... }` block.
   let (declarations, mut rules) =
     parse_declarations_and_nested_rules(input, default_namespace, namespace_prefixes, options)?;
@@ -899,7 +1127,7 @@ impl<'a, 'o, 'b, 'i> QualifiedRuleParser<'i> for StyleRuleParser<'a, 'o, 'i> {
       is_nesting_allowed: true,
       options: &self.options,
     };
-    SelectorList::parse(&selector_parser, input, NestingRequirement::Prefixed)
+    SelectorList::parse(&selector_parser, input, NestingRequirement::Implicit)
   }
 
   fn parse_block<'t>(
@@ -10,7 +10,6 @@ use cssparser::{serialize_identifier, serialize_name};
 use parcel_sourcemap::{OriginalLocation, SourceMap};
 
 /// Options that control how CSS is serialized to a string.
-#[derive(Default)]
 pub struct PrinterOptions<'a> {
   /// Whether to minify the CSS, i.e. remove white space.
   pub minify: bool,
@@ -29,6 +28,32 @@ pub struct PrinterOptions<'a> {
   /// A mapping of pseudo classes to replace with class names that can be applied
   /// from JavaScript. Useful for polyfills, for example.
   pub pseudo_classes: Option<PseudoClasses<'a>>,
+  /// The maximum number of decimal places to print for lengths, angles, percentages and
+  /// color channels. Other numbers are not rounded. By default, up to six significant
+  /// digits are printed.
+  pub precision: Option<u8>,
+  /// Whether to convert dimensions to an equivalent unit when it is shorter, e.g.
+  /// `96px` to `1in`, or `.25turn` to `90deg`. Only applies when minifying.
+  pub convert_units: bool,
+  /// Whether to serialize colors in their shortest form, e.g. `#f00` or `red` rather
+  /// than `#ff0000`. When disabled, sRGB colors are always printed as `#rrggbb` or `#rrggbbaa`.
+  /// This option is only available from the Rust API.
+  pub shorten_colors: bool,
+}
+
+impl<'a> Default for PrinterOptions<'a> {
+  fn default() -> Self {
+    PrinterOptions {
+      minify: false,
+      source_map: None,
+      targets: None,
+      analyze_dependencies: false,
+      pseudo_classes: None,
+      precision: None,
+      convert_units: false,
+      shorten_colors: true,
+    }
+  }
 }
 
 /// A mapping of user action pseudo classes to replace with class names.
@@ -74,6 +99,9 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) css_module: Option<CssModule<'a, 'b, 'c>>,
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  pub(crate) precision: Option<u8>,
+  pub(crate) convert_units: bool,
+  pub(crate) shorten_colors: bool,
 }
 
 impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
@@ -102,6 +130,9 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
         None
       },
       pseudo_classes: options.pseudo_classes,
+      precision: options.precision,
+      convert_units: options.convert_units,
+      shorten_colors: options.shorten_colors,
     }
   }
 
@@ -202,6 +233,23 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     self.indent > 2
   }
 
+  /// Rounds a number to the maximum number of decimal places given by the `precision` option, if any.
+  pub(crate) fn round(&self, value: f32) -> f32 {
+    match self.precision {
+      Some(precision) if value.is_finite() => {
+        let factor = 10f64.powi(precision as i32);
+        let rounded = ((value as f64 * factor).round() / factor) as f32;
+        // Avoid printing negative zero.
+        if rounded == 0.0 {
+          0.0
+        } else {
+          rounded
+        }
+      }
+      _ => value,
+    }
+  }
+
   /// Adds a mapping to the source map, if any.
   pub fn add_mapping(&mut self, loc: Location) {
     self.loc = loc;
@@ -273,6 +321,35 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     Ok(())
   }
 
+  /// Writes a dashed ident that is always scoped to the current CSS module, like keyframe
+  /// names are, regardless of the `dashed_idents` option. Used for timeline names.
+  pub(crate) fn write_local_dashed_ident(&mut self, ident: &str, is_reference: bool) -> Result<(), PrinterError> {
+    self.write_str("--")?;
+
+    match &mut self.css_module {
+      Some(css_module) => {
+        let dest = &mut self.dest;
+        css_module
+          .config
+          .pattern
+          .write(&css_module.hash, &css_module.path, &ident[2..], |s| {
+            self.col += s.len() as u32;
+            serialize_name(s, dest)
+          })?;
+
+        css_module.add_dashed(ident);
+        if is_reference {
+          css_module.reference_dashed(ident, &None);
+        }
+      }
+      None => {
+        serialize_name(&ident[2..], self)?;
+      }
+    }
+
+    Ok(())
+  }
+
   /// Returns an error of the given kind at the provided location in the current source file.
   pub fn error(&self, kind: PrinterErrorKind, loc: crate::dependencies::Location) -> Error<PrinterErrorKind> {
     Error {
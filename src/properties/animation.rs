@@ -9,8 +9,15 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::Browsers;
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::values::length::{LengthPercentage, LengthPercentageOrAuto};
 use crate::values::number::CSSNumber;
-use crate::values::{easing::EasingFunction, ident::CustomIdent, time::Time};
+use crate::values::percentage::Percentage;
+use crate::values::size::Size2D;
+use crate::values::{
+  easing::EasingFunction,
+  ident::{CustomIdent, DashedIdent},
+  time::Time,
+};
 use cssparser::*;
 use itertools::izip;
 use smallvec::SmallVec;
@@ -267,6 +274,608 @@ impl<'i> ToCss for Animation<'i> {
 /// A list of animations.
 pub type AnimationList<'i> = SmallVec<[Animation<'i>; 1]>;
 
+enum_property! {
+  /// A scroll container, as used in the [scroll()](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function.
+  pub enum Scroller {
+    /// The nearest ancestor scroll container.
+    "nearest": Nearest,
+    /// The document viewport.
+    "root": Root,
+    /// The element's own principal box.
+    "self": SelfElement,
+  }
+}
+
+impl Default for Scroller {
+  fn default() -> Scroller {
+    Scroller::Nearest
+  }
+}
+
+enum_property! {
+  /// A scroll axis, as used in the [scroll()](https://drafts.csswg.org/scroll-animations-1/#scroll-notation)
+  /// and [view()](https://drafts.csswg.org/scroll-animations-1/#view-notation) functions and the
+  /// `scroll-timeline-axis` and `view-timeline-axis` properties.
+  pub enum ScrollAxis {
+    /// The block axis of the scroll container.
+    Block,
+    /// The inline axis of the scroll container.
+    Inline,
+    /// The horizontal axis of the scroll container.
+    X,
+    /// The vertical axis of the scroll container.
+    Y,
+  }
+}
+
+impl Default for ScrollAxis {
+  fn default() -> ScrollAxis {
+    ScrollAxis::Block
+  }
+}
+
+/// An anonymous scroll progress timeline, created with the
+/// [scroll()](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollFunction {
+  /// The scroll container whose scroll position drives the timeline.
+  pub scroller: Scroller,
+  /// The axis of the scroll container that drives the timeline.
+  pub axis: ScrollAxis,
+}
+
+impl<'i> Parse<'i> for ScrollFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("scroll")?;
+    input.parse_nested_block(|input| {
+      let mut scroller = None;
+      let mut axis = None;
+      loop {
+        if scroller.is_none() {
+          scroller = input.try_parse(Scroller::parse).ok();
+          if scroller.is_some() {
+            continue;
+          }
+        }
+
+        if axis.is_none() {
+          axis = input.try_parse(ScrollAxis::parse).ok();
+          if axis.is_some() {
+            continue;
+          }
+        }
+
+        break;
+      }
+
+      Ok(ScrollFunction {
+        scroller: scroller.unwrap_or_default(),
+        axis: axis.unwrap_or_default(),
+      })
+    })
+  }
+}
+
+impl ToCss for ScrollFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("scroll(")?;
+
+    let mut needs_space = false;
+    if self.scroller != Scroller::default() {
+      self.scroller.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.axis != ScrollAxis::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.axis.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// An anonymous view progress timeline, created with the
+/// [view()](https://drafts.csswg.org/scroll-animations-1/#view-notation) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewFunction {
+  /// The axis of the nearest scroll container that drives the timeline.
+  pub axis: ScrollAxis,
+  /// The inset applied to the scrollport when determining whether the element is in view.
+  pub inset: Size2D<LengthPercentageOrAuto>,
+}
+
+impl<'i> Parse<'i> for ViewFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("view")?;
+    input.parse_nested_block(|input| {
+      let mut axis = None;
+      let mut inset = None;
+      loop {
+        if axis.is_none() {
+          axis = input.try_parse(ScrollAxis::parse).ok();
+          if axis.is_some() {
+            continue;
+          }
+        }
+
+        if inset.is_none() {
+          inset = input.try_parse(Size2D::parse).ok();
+          if inset.is_some() {
+            continue;
+          }
+        }
+
+        break;
+      }
+
+      Ok(ViewFunction {
+        axis: axis.unwrap_or_default(),
+        inset: inset.unwrap_or(Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto)),
+      })
+    })
+  }
+}
+
+impl ToCss for ViewFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("view(")?;
+
+    let mut needs_space = false;
+    if self.axis != ScrollAxis::default() {
+      self.axis.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.inset != Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto) {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.inset.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// A value for the [animation-timeline](https://drafts.csswg.org/css-animations-2/#animation-timeline) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum AnimationTimeline<'i> {
+  /// The animation is driven by the document's default timeline.
+  Auto,
+  /// The animation is not associated with a timeline.
+  None,
+  /// The name of a scroll or view progress timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+  /// An anonymous scroll progress timeline.
+  Scroll(ScrollFunction),
+  /// An anonymous view progress timeline.
+  View(ViewFunction),
+}
+
+impl<'i> Parse<'i> for AnimationTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(AnimationTimeline::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnimationTimeline::None);
+    }
+
+    if let Ok(name) = input.try_parse(DashedIdent::parse) {
+      return Ok(AnimationTimeline::DashedIdent(name));
+    }
+
+    if let Ok(scroll) = input.try_parse(ScrollFunction::parse) {
+      return Ok(AnimationTimeline::Scroll(scroll));
+    }
+
+    Ok(AnimationTimeline::View(ViewFunction::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for AnimationTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationTimeline::Auto => dest.write_str("auto"),
+      AnimationTimeline::None => dest.write_str("none"),
+      AnimationTimeline::DashedIdent(name) => dest.write_local_dashed_ident(&name.0, true),
+      AnimationTimeline::Scroll(scroll) => scroll.to_css(dest),
+      AnimationTimeline::View(view) => view.to_css(dest),
+    }
+  }
+}
+
+/// A list of animation timelines.
+pub type AnimationTimelineList<'i> = SmallVec<[AnimationTimeline<'i>; 1]>;
+
+enum_property! {
+  /// A [named timeline range](https://drafts.csswg.org/scroll-animations-1/#named-ranges),
+  /// as used in the `animation-range` properties.
+  pub enum TimelineRangeName {
+    /// The full range of the view progress timeline.
+    "cover": Cover,
+    /// The range during which the subject is fully contained within the scrollport.
+    "contain": Contain,
+    /// The range during which the subject enters the scrollport.
+    "entry": Entry,
+    /// The range during which the subject exits the scrollport.
+    "exit": Exit,
+    /// The range during which the subject crosses the end edge of the scrollport.
+    "entry-crossing": EntryCrossing,
+    /// The range during which the subject crosses the start edge of the scrollport.
+    "exit-crossing": ExitCrossing,
+  }
+}
+
+/// The start or end of an animation's attachment range, as used in the
+/// [animation-range](https://drafts.csswg.org/scroll-animations-1/#animation-range) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum AnimationAttachmentRange {
+  /// The start or end of the timeline.
+  Normal,
+  /// An offset from the start of the timeline.
+  LengthPercentage {
+    /// The offset.
+    value: LengthPercentage,
+  },
+  /// An offset within a named timeline range.
+  TimelineRange {
+    /// The name of the timeline range.
+    name: TimelineRangeName,
+    /// The offset within the timeline range.
+    offset: LengthPercentage,
+  },
+}
+
+impl AnimationAttachmentRange {
+  fn parse_with_default_offset<'i, 't>(
+    input: &mut Parser<'i, 't>,
+    default_offset: f32,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(AnimationAttachmentRange::Normal);
+    }
+
+    if let Ok(value) = input.try_parse(LengthPercentage::parse) {
+      return Ok(AnimationAttachmentRange::LengthPercentage { value });
+    }
+
+    let name = TimelineRangeName::parse(input)?;
+    let offset = input
+      .try_parse(LengthPercentage::parse)
+      .unwrap_or(LengthPercentage::Percentage(Percentage(default_offset)));
+    Ok(AnimationAttachmentRange::TimelineRange { name, offset })
+  }
+
+  fn to_css_with_default_offset<W>(&self, dest: &mut Printer<W>, default_offset: f32) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationAttachmentRange::Normal => dest.write_str("normal"),
+      AnimationAttachmentRange::LengthPercentage { value } => value.to_css(dest),
+      AnimationAttachmentRange::TimelineRange { name, offset } => {
+        name.to_css(dest)?;
+        if *offset != LengthPercentage::Percentage(Percentage(default_offset)) {
+          dest.write_char(' ')?;
+          offset.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [animation-range-start](https://drafts.csswg.org/scroll-animations-1/#animation-range-start) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationRangeStart(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeStart {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Ok(AnimationRangeStart(
+      AnimationAttachmentRange::parse_with_default_offset(input, 0.0)?,
+    ))
+  }
+}
+
+impl ToCss for AnimationRangeStart {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css_with_default_offset(dest, 0.0)
+  }
+}
+
+/// A value for the [animation-range-end](https://drafts.csswg.org/scroll-animations-1/#animation-range-end) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationRangeEnd(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeEnd {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Ok(AnimationRangeEnd(AnimationAttachmentRange::parse_with_default_offset(
+      input, 1.0,
+    )?))
+  }
+}
+
+impl ToCss for AnimationRangeEnd {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css_with_default_offset(dest, 1.0)
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [animation-range](https://drafts.csswg.org/scroll-animations-1/#animation-range) shorthand property.
+  pub struct AnimationRange {
+    /// The start of the animation's attachment range.
+    start: AnimationRangeStart(AnimationRangeStart),
+    /// The end of the animation's attachment range.
+    end: AnimationRangeEnd(AnimationRangeEnd),
+  }
+}
+
+impl AnimationRange {
+  /// Returns the end value that is implied when only the start is specified.
+  fn implied_end(start: &AnimationRangeStart) -> AnimationRangeEnd {
+    match &start.0 {
+      AnimationAttachmentRange::TimelineRange { name, .. } => {
+        AnimationRangeEnd(AnimationAttachmentRange::TimelineRange {
+          name: *name,
+          offset: LengthPercentage::Percentage(Percentage(1.0)),
+        })
+      }
+      _ => AnimationRangeEnd(AnimationAttachmentRange::Normal),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for AnimationRange {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let start = AnimationRangeStart::parse(input)?;
+    let end = input
+      .try_parse(AnimationRangeEnd::parse)
+      .unwrap_or_else(|_| AnimationRange::implied_end(&start));
+    Ok(AnimationRange { start, end })
+  }
+}
+
+impl ToCss for AnimationRange {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.start.to_css(dest)?;
+    if self.end != AnimationRange::implied_end(&self.start) {
+      dest.write_char(' ')?;
+      self.end.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A timeline name, as used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name)
+/// and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TimelineName<'i> {
+  /// The `none` keyword.
+  None,
+  /// The name of the timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for TimelineName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineName::None);
+    }
+
+    Ok(TimelineName::DashedIdent(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TimelineName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineName::None => dest.write_str("none"),
+      TimelineName::DashedIdent(name) => dest.write_local_dashed_ident(&name.0, false),
+    }
+  }
+}
+
+/// A list of timeline names.
+pub type TimelineNameList<'i> = SmallVec<[TimelineName<'i>; 1]>;
+
+define_list_shorthand! {
+  /// A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+  pub struct ScrollTimeline<'i> {
+    /// The name of the scroll progress timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ScrollTimelineName(TimelineName<'i>),
+    /// The axis of the scroll container that drives the timeline.
+    axis: ScrollTimelineAxis(ScrollAxis),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let axis = input.try_parse(ScrollAxis::parse).unwrap_or_default();
+    Ok(ScrollTimeline { name, axis })
+  }
+}
+
+impl<'i> ToCss for ScrollTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+  pub struct ViewTimeline<'i> {
+    /// The name of the view progress timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ViewTimelineName(TimelineName<'i>),
+    /// The axis of the nearest scroll container that drives the timeline.
+    axis: ViewTimelineAxis(ScrollAxis),
+    /// The inset applied to the scrollport when determining whether the element is in view.
+    inset: ViewTimelineInset(Size2D<LengthPercentageOrAuto>),
+  }
+}
+
+impl<'i> Parse<'i> for ViewTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let mut axis = None;
+    let mut inset = None;
+    loop {
+      if axis.is_none() {
+        axis = input.try_parse(ScrollAxis::parse).ok();
+        if axis.is_some() {
+          continue;
+        }
+      }
+
+      if inset.is_none() {
+        inset = input.try_parse(Size2D::parse).ok();
+        if inset.is_some() {
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    Ok(ViewTimeline {
+      name,
+      axis: axis.unwrap_or_default(),
+      inset: inset.unwrap_or(Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto)),
+    })
+  }
+}
+
+impl<'i> ToCss for ViewTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    if self.inset != Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto) {
+      dest.write_char(' ')?;
+      self.inset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations-1/#timeline-scope) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TimelineScope<'i> {
+  /// No timeline names are in scope.
+  None,
+  /// All timeline names defined by descendants are in scope.
+  All,
+  /// The given timeline names are in scope.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdents(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for TimelineScope<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineScope::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("all")).is_ok() {
+      return Ok(TimelineScope::All);
+    }
+
+    Ok(TimelineScope::DashedIdents(
+      input.parse_comma_separated(DashedIdent::parse)?.into(),
+    ))
+  }
+}
+
+impl<'i> ToCss for TimelineScope<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineScope::None => dest.write_str("none"),
+      TimelineScope::All => dest.write_str("all"),
+      TimelineScope::DashedIdents(names) => {
+        let len = names.len();
+        for (idx, name) in names.iter().enumerate() {
+          dest.write_local_dashed_ident(&name.0, true)?;
+          if idx < len - 1 {
+            dest.delim(',', false)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct AnimationHandler<'i> {
   targets: Option<Browsers>,
@@ -278,6 +887,9 @@ pub(crate) struct AnimationHandler<'i> {
   play_states: Option<(SmallVec<[AnimationPlayState; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   fill_modes: Option<(SmallVec<[AnimationFillMode; 1]>, VendorPrefix)>,
+  timelines: Option<AnimationTimelineList<'i>>,
+  range_starts: Option<SmallVec<[AnimationRangeStart; 1]>>,
+  range_ends: Option<SmallVec<[AnimationRangeEnd; 1]>>,
   has_any: bool,
 }
 
@@ -368,6 +980,31 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
         property!(play_states, &play_states, vp);
         property!(delays, &delays, vp);
         property!(fill_modes, &fill_modes, vp);
+
+        // The unprefixed shorthand also resets animation-timeline and animation-range,
+        // which it cannot set itself, so any earlier values are overridden.
+        if vp.contains(VendorPrefix::None) {
+          self.timelines = None;
+          self.range_starts = None;
+          self.range_ends = None;
+        }
+      }
+      AnimationTimeline(val) => {
+        self.timelines = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRangeStart(val) => {
+        self.range_starts = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRangeEnd(val) => {
+        self.range_ends = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRange(val) => {
+        self.range_starts = Some(val.iter().map(|r| r.start.clone()).collect());
+        self.range_ends = Some(val.iter().map(|r| r.end.clone()).collect());
+        self.has_any = true;
       }
       Unparsed(val) if is_animation_property(&val.property_id) => {
         self.flush(dest);
@@ -506,6 +1143,32 @@ impl<'i> AnimationHandler<'i> {
     prop!(play_states, AnimationPlayState);
     prop!(delays, AnimationDelay);
     prop!(fill_modes, AnimationFillMode);
+
+    // These must come after the animation shorthand, which resets them.
+    if let Some(timelines) = std::mem::take(&mut self.timelines) {
+      dest.push(Property::AnimationTimeline(timelines));
+    }
+
+    match (
+      std::mem::take(&mut self.range_starts),
+      std::mem::take(&mut self.range_ends),
+    ) {
+      (Some(mut starts), Some(mut ends)) if starts.len() == ends.len() => {
+        let ranges = izip!(starts.drain(..), ends.drain(..))
+          .map(|(start, end)| AnimationRange { start, end })
+          .collect();
+        dest.push(Property::AnimationRange(ranges));
+      }
+      (starts, ends) => {
+        if let Some(starts) = starts {
+          dest.push(Property::AnimationRangeStart(starts));
+        }
+
+        if let Some(ends) = ends {
+          dest.push(Property::AnimationRangeEnd(ends));
+        }
+      }
+    }
   }
 }
 
@@ -520,7 +1183,172 @@ fn is_animation_property(property_id: &PropertyId) -> bool {
     | PropertyId::AnimationPlayState(_)
     | PropertyId::AnimationDelay(_)
     | PropertyId::AnimationFillMode(_)
-    | PropertyId::Animation(_) => true,
+    | PropertyId::Animation(_)
+    | PropertyId::AnimationTimeline
+    | PropertyId::AnimationRangeStart
+    | PropertyId::AnimationRangeEnd
+    | PropertyId::AnimationRange => true,
+    _ => false,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct TimelineHandler<'i> {
+  scroll_names: Option<TimelineNameList<'i>>,
+  scroll_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_names: Option<TimelineNameList<'i>>,
+  view_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_insets: Option<SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for TimelineHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      ScrollTimelineName(val) => self.scroll_names = Some(val.clone()),
+      ScrollTimelineAxis(val) => self.scroll_axes = Some(val.clone()),
+      ScrollTimeline(val) => {
+        self.scroll_names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.scroll_axes = Some(val.iter().map(|t| t.axis).collect());
+      }
+      ViewTimelineName(val) => self.view_names = Some(val.clone()),
+      ViewTimelineAxis(val) => self.view_axes = Some(val.clone()),
+      ViewTimelineInset(val) => self.view_insets = Some(val.clone()),
+      ViewTimeline(val) => {
+        self.view_names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.view_axes = Some(val.iter().map(|t| t.axis).collect());
+        self.view_insets = Some(val.iter().map(|t| t.inset.clone()).collect());
+      }
+      Unparsed(val) if is_timeline_property(&val.property_id) => {
+        self.flush(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl<'i> TimelineHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut scroll_names = std::mem::take(&mut self.scroll_names);
+    let mut scroll_axes = std::mem::take(&mut self.scroll_axes);
+    let mut view_names = std::mem::take(&mut self.view_names);
+    let mut view_axes = std::mem::take(&mut self.view_axes);
+    let mut view_insets = std::mem::take(&mut self.view_insets);
+
+    // Timelines whose names are all unused are removed entirely.
+    let unused_symbols = &context.options.unused_symbols;
+    if !unused_symbols.is_empty() {
+      if remove_unused_timeline_names(&mut scroll_names, unused_symbols) {
+        scroll_axes = None;
+      }
+
+      if remove_unused_timeline_names(&mut view_names, unused_symbols) {
+        view_axes = None;
+        view_insets = None;
+      }
+    }
+
+    match (scroll_names, scroll_axes) {
+      (Some(mut names), Some(mut axes)) if names.len() == axes.len() => {
+        let timelines = izip!(names.drain(..), axes.drain(..))
+          .map(|(name, axis)| ScrollTimeline { name, axis })
+          .collect();
+        dest.push(Property::ScrollTimeline(timelines));
+      }
+      (names, axes) => {
+        if let Some(names) = names {
+          dest.push(Property::ScrollTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ScrollTimelineAxis(axes));
+        }
+      }
+    }
+
+    match (view_names, view_axes, view_insets) {
+      (Some(mut names), Some(mut axes), Some(mut insets))
+        if names.len() == axes.len() && names.len() == insets.len() =>
+      {
+        let timelines = izip!(names.drain(..), axes.drain(..), insets.drain(..))
+          .map(|(name, axis, inset)| ViewTimeline { name, axis, inset })
+          .collect();
+        dest.push(Property::ViewTimeline(timelines));
+      }
+      (names, axes, insets) => {
+        if let Some(names) = names {
+          dest.push(Property::ViewTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ViewTimelineAxis(axes));
+        }
+
+        if let Some(insets) = insets {
+          dest.push(Property::ViewTimelineInset(insets));
+        }
+      }
+    }
+  }
+}
+
+/// Replaces timeline names that are in `unused_symbols` with `none`. If no names remain,
+/// the list is removed and true is returned.
+fn remove_unused_timeline_names<'i>(
+  names: &mut Option<TimelineNameList<'i>>,
+  unused_symbols: &std::collections::HashSet<String>,
+) -> bool {
+  let mut removed = false;
+  if let Some(list) = names {
+    for name in list.iter_mut() {
+      if matches!(name, TimelineName::DashedIdent(ident) if unused_symbols.contains(ident.0.as_ref())) {
+        *name = TimelineName::None;
+        removed = true;
+      }
+    }
+
+    removed = removed && list.iter().all(|name| *name == TimelineName::None);
+  }
+
+  if removed {
+    *names = None;
+  }
+
+  removed
+}
+
+#[inline]
+fn is_timeline_property(property_id: &PropertyId) -> bool {
+  match property_id {
+    PropertyId::ScrollTimelineName
+    | PropertyId::ScrollTimelineAxis
+    | PropertyId::ScrollTimeline
+    | PropertyId::ViewTimelineName
+    | PropertyId::ViewTimelineAxis
+    | PropertyId::ViewTimelineInset
+    | PropertyId::ViewTimeline => true,
     _ => false,
   }
 }
@@ -6,11 +6,11 @@ use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::logical::PropertyCategory;
+use crate::logical::{LogicalSide, PhysicalSide, PropertyCategory};
 use crate::macros::*;
 use crate::printer::Printer;
 use crate::properties::custom::UnparsedProperty;
-use crate::properties::{Property, PropertyId};
+use crate::properties::{writing_mode::Direction, Property, PropertyId};
 use crate::targets::Browsers;
 use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::color::{ColorFallbackKind, CssColor};
@@ -106,7 +106,7 @@ impl Default for LineStyle {
   }
 }
 
-/// A generic type that represents the `border` and `outline` shorthand properties.
+/// A generic type that represents the `border`, `outline`, and `column-rule` shorthand properties.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenericBorder<S, const P: u8> {
@@ -699,7 +699,7 @@ impl<'i> PropertyHandler<'i> for BorderHandler<'i> {
 }
 
 impl<'i> BorderHandler<'i> {
-  fn flush(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
     if !self.has_any {
       return;
     }
@@ -708,12 +708,34 @@ impl<'i> BorderHandler<'i> {
 
     let logical_supported = context.is_supported(Feature::LogicalBorders);
     let logical_shorthand_supported = context.is_supported(Feature::LogicalBorderShorthand);
-    macro_rules! logical_prop {
-      ($ltr: ident, $ltr_key: ident, $rtl: ident, $rtl_key: ident, $val: expr) => {{
-        context.add_logical_rule(Property::$ltr($val.clone()), Property::$rtl($val.clone()));
+    macro_rules! inline_prop {
+      ($side: ident, $top: ident, $right: ident, $bottom: ident, $left: ident, $val: expr) => {{
+        context.add_physical_property(dest, LogicalSide::$side, |side| match side {
+          PhysicalSide::Top => Property::$top($val.clone()),
+          PhysicalSide::Right => Property::$right($val.clone()),
+          PhysicalSide::Bottom => Property::$bottom($val.clone()),
+          PhysicalSide::Left => Property::$left($val.clone()),
+        });
       }};
     }
 
+    macro_rules! push {
+      ($prop: ident => $val: expr) => {
+        dest.push(Property::$prop($val))
+      };
+    }
+
+    macro_rules! block_prop {
+      ($push: ident, $side: ident, $top: ident, $right: ident, $bottom: ident, $left: ident, $val: expr) => {
+        match context.physical_side(LogicalSide::$side, Direction::Ltr) {
+          PhysicalSide::Top => $push!($top => $val),
+          PhysicalSide::Right => $push!($right => $val),
+          PhysicalSide::Bottom => $push!($bottom => $val),
+          PhysicalSide::Left => $push!($left => $val),
+        }
+      };
+    }
+
     macro_rules! fallbacks {
       ($prop: ident => $val: expr) => {{
         let mut val = $val;
@@ -732,112 +754,112 @@ impl<'i> BorderHandler<'i> {
         if logical_supported {
           fallbacks!(BorderInlineStart => $val);
         } else {
-          logical_prop!(BorderLeft, border_left, BorderRight, border_right, $val);
+          inline_prop!(InlineStart, BorderTop, BorderRight, BorderBottom, BorderLeft, $val);
         }
       };
       (BorderInlineStartWidth => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderInlineStartWidth($val));
         } else {
-          logical_prop!(BorderLeftWidth, border_left_width, BorderRightWidth, border_right_width, $val);
+          inline_prop!(InlineStart, BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth, $val);
         }
       };
       (BorderInlineStartColor => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderInlineStartColor => $val);
         } else {
-          logical_prop!(BorderLeftColor, border_left_color, BorderRightColor, border_right_color, $val);
+          inline_prop!(InlineStart, BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor, $val);
         }
       };
       (BorderInlineStartStyle => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderInlineStartStyle($val));
         } else {
-          logical_prop!(BorderLeftStyle, border_left_style, BorderRightStyle, border_right_style, $val);
+          inline_prop!(InlineStart, BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle, $val);
         }
       };
       (BorderInlineEnd => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderInlineEnd => $val);
         } else {
-          logical_prop!(BorderRight, border_right, BorderLeft, border_left, $val);
+          inline_prop!(InlineEnd, BorderTop, BorderRight, BorderBottom, BorderLeft, $val);
         }
       };
       (BorderInlineEndWidth => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderInlineEndWidth($val));
         } else {
-          logical_prop!(BorderRightWidth, border_right_width, BorderLeftWidth, border_left_width, $val);
+          inline_prop!(InlineEnd, BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth, $val);
         }
       };
       (BorderInlineEndColor => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderInlineEndColor => $val);
         } else {
-          logical_prop!(BorderRightColor, border_right_color, BorderLeftColor, border_left_color, $val);
+          inline_prop!(InlineEnd, BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor, $val);
         }
       };
       (BorderInlineEndStyle => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderInlineEndStyle($val));
         } else {
-          logical_prop!(BorderRightStyle, border_right_style, BorderLeftStyle, border_left_style, $val);
+          inline_prop!(InlineEnd, BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle, $val);
         }
       };
       (BorderBlockStart => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderBlockStart => $val);
         } else {
-          fallbacks!(BorderTop => $val);
+          block_prop!(fallbacks, BlockStart, BorderTop, BorderRight, BorderBottom, BorderLeft, $val);
         }
       };
       (BorderBlockStartWidth => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderBlockStartWidth($val));
         } else {
-          dest.push(Property::BorderTopWidth($val));
+          block_prop!(push, BlockStart, BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth, $val);
         }
       };
       (BorderBlockStartColor => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderBlockStartColor => $val);
         } else {
-          fallbacks!(BorderTopColor => $val);
+          block_prop!(fallbacks, BlockStart, BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor, $val);
         }
       };
       (BorderBlockStartStyle => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderBlockStartStyle($val));
         } else {
-          dest.push(Property::BorderTopStyle($val));
+          block_prop!(push, BlockStart, BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle, $val);
         }
       };
       (BorderBlockEnd => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderBlockEnd => $val);
         } else {
-          fallbacks!(BorderBottom => $val);
+          block_prop!(fallbacks, BlockEnd, BorderTop, BorderRight, BorderBottom, BorderLeft, $val);
         }
       };
       (BorderBlockEndWidth => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderBlockEndWidth($val));
         } else {
-          dest.push(Property::BorderBottomWidth($val));
+          block_prop!(push, BlockEnd, BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth, $val);
         }
       };
       (BorderBlockEndColor => $val: expr) => {
         if logical_supported {
           fallbacks!(BorderBlockEndColor => $val);
         } else {
-          fallbacks!(BorderBottomColor => $val);
+          block_prop!(fallbacks, BlockEnd, BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor, $val);
         }
       };
       (BorderBlockEndStyle => $val: expr) => {
         if logical_supported {
           dest.push(Property::BorderBlockEndStyle($val));
         } else {
-          dest.push(Property::BorderBottomStyle($val));
+          block_prop!(push, BlockEnd, BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle, $val);
         }
       };
       (BorderLeftColor => $val: expr) => {
@@ -1252,53 +1274,119 @@ impl<'i> BorderHandler<'i> {
       return;
     }
 
-    macro_rules! prop {
-      ($id: ident) => {{
-        let mut unparsed = unparsed.with_property_id(PropertyId::$id);
-        context.add_unparsed_fallbacks(&mut unparsed);
-        dest.push(Property::Unparsed(unparsed));
-      }};
-    }
-
     macro_rules! logical_prop {
-      ($ltr: ident, $ltr_key: ident, $rtl: ident, $rtl_key: ident) => {{
-        context.add_logical_rule(
-          Property::Unparsed(unparsed.with_property_id(PropertyId::$ltr)),
-          Property::Unparsed(unparsed.with_property_id(PropertyId::$rtl)),
-        );
+      ($side: ident, $top: ident, $right: ident, $bottom: ident, $left: ident) => {{
+        let id = |side: PhysicalSide| match side {
+          PhysicalSide::Top => PropertyId::$top,
+          PhysicalSide::Right => PropertyId::$right,
+          PhysicalSide::Bottom => PropertyId::$bottom,
+          PhysicalSide::Left => PropertyId::$left,
+        };
+
+        if LogicalSide::$side.is_inline() {
+          context.add_physical_property(dest, LogicalSide::$side, |side| {
+            Property::Unparsed(unparsed.with_property_id(id(side)))
+          });
+        } else {
+          let side = context.physical_side(LogicalSide::$side, Direction::Ltr);
+          let mut unparsed = unparsed.with_property_id(id(side));
+          context.add_unparsed_fallbacks(&mut unparsed);
+          dest.push(Property::Unparsed(unparsed));
+        }
       }};
     }
 
-    use PropertyId::*;
     match &unparsed.property_id {
-      BorderInlineStart => logical_prop!(BorderLeft, border_left, BorderRight, border_right),
-      BorderInlineStartWidth => {
-        logical_prop!(BorderLeftWidth, border_left_width, BorderRightWidth, border_right_width)
-      }
-      BorderInlineStartColor => {
-        logical_prop!(BorderLeftColor, border_left_color, BorderRightColor, border_right_color)
-      }
-      BorderInlineStartStyle => {
-        logical_prop!(BorderLeftStyle, border_left_style, BorderRightStyle, border_right_style)
-      }
-      BorderInlineEnd => logical_prop!(BorderRight, border_right, BorderLeft, border_left),
-      BorderInlineEndWidth => {
-        logical_prop!(BorderRightWidth, border_right_width, BorderLeftWidth, border_left_width)
-      }
-      BorderInlineEndColor => {
-        logical_prop!(BorderRightColor, border_right_color, BorderLeftColor, border_left_color)
-      }
-      BorderInlineEndStyle => {
-        logical_prop!(BorderRightStyle, border_right_style, BorderLeftStyle, border_left_style)
+      PropertyId::BorderInlineStart => {
+        logical_prop!(InlineStart, BorderTop, BorderRight, BorderBottom, BorderLeft)
       }
-      BorderBlockStart => prop!(BorderTop),
-      BorderBlockStartWidth => prop!(BorderTopWidth),
-      BorderBlockStartColor => prop!(BorderTopColor),
-      BorderBlockStartStyle => prop!(BorderTopStyle),
-      BorderBlockEnd => prop!(BorderBottom),
-      BorderBlockEndWidth => prop!(BorderBottomWidth),
-      BorderBlockEndColor => prop!(BorderBottomColor),
-      BorderBlockEndStyle => prop!(BorderBottomStyle),
+      PropertyId::BorderInlineStartWidth => logical_prop!(
+        InlineStart,
+        BorderTopWidth,
+        BorderRightWidth,
+        BorderBottomWidth,
+        BorderLeftWidth
+      ),
+      PropertyId::BorderInlineStartColor => logical_prop!(
+        InlineStart,
+        BorderTopColor,
+        BorderRightColor,
+        BorderBottomColor,
+        BorderLeftColor
+      ),
+      PropertyId::BorderInlineStartStyle => logical_prop!(
+        InlineStart,
+        BorderTopStyle,
+        BorderRightStyle,
+        BorderBottomStyle,
+        BorderLeftStyle
+      ),
+      PropertyId::BorderInlineEnd => logical_prop!(InlineEnd, BorderTop, BorderRight, BorderBottom, BorderLeft),
+      PropertyId::BorderInlineEndWidth => logical_prop!(
+        InlineEnd,
+        BorderTopWidth,
+        BorderRightWidth,
+        BorderBottomWidth,
+        BorderLeftWidth
+      ),
+      PropertyId::BorderInlineEndColor => logical_prop!(
+        InlineEnd,
+        BorderTopColor,
+        BorderRightColor,
+        BorderBottomColor,
+        BorderLeftColor
+      ),
+      PropertyId::BorderInlineEndStyle => logical_prop!(
+        InlineEnd,
+        BorderTopStyle,
+        BorderRightStyle,
+        BorderBottomStyle,
+        BorderLeftStyle
+      ),
+      PropertyId::BorderBlockStart => logical_prop!(BlockStart, BorderTop, BorderRight, BorderBottom, BorderLeft),
+      PropertyId::BorderBlockStartWidth => logical_prop!(
+        BlockStart,
+        BorderTopWidth,
+        BorderRightWidth,
+        BorderBottomWidth,
+        BorderLeftWidth
+      ),
+      PropertyId::BorderBlockStartColor => logical_prop!(
+        BlockStart,
+        BorderTopColor,
+        BorderRightColor,
+        BorderBottomColor,
+        BorderLeftColor
+      ),
+      PropertyId::BorderBlockStartStyle => logical_prop!(
+        BlockStart,
+        BorderTopStyle,
+        BorderRightStyle,
+        BorderBottomStyle,
+        BorderLeftStyle
+      ),
+      PropertyId::BorderBlockEnd => logical_prop!(BlockEnd, BorderTop, BorderRight, BorderBottom, BorderLeft),
+      PropertyId::BorderBlockEndWidth => logical_prop!(
+        BlockEnd,
+        BorderTopWidth,
+        BorderRightWidth,
+        BorderBottomWidth,
+        BorderLeftWidth
+      ),
+      PropertyId::BorderBlockEndColor => logical_prop!(
+        BlockEnd,
+        BorderTopColor,
+        BorderRightColor,
+        BorderBottomColor,
+        BorderLeftColor
+      ),
+      PropertyId::BorderBlockEndStyle => logical_prop!(
+        BlockEnd,
+        BorderTopStyle,
+        BorderRightStyle,
+        BorderBottomStyle,
+        BorderLeftStyle
+      ),
       _ => {
         let mut unparsed = unparsed.clone();
         context.add_unparsed_fallbacks(&mut unparsed);
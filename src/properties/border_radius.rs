@@ -4,7 +4,7 @@ use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::logical::PropertyCategory;
+use crate::logical::{LogicalSide, PhysicalSide, PropertyCategory};
 use crate::macros::define_shorthand;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
@@ -281,29 +281,68 @@ impl<'i> BorderRadiusHandler<'i> {
 
     let logical_supported = context.is_supported(compat::Feature::LogicalBorderRadius);
 
+    let targets = self.targets;
+
+    // Returns the physical corner property where the two given physical sides meet.
+    macro_rules! physical_corner {
+      ($a: expr, $b: expr, $property: ident) => {{
+        let is_top = $a == PhysicalSide::Top || $b == PhysicalSide::Top;
+        let is_left = $a == PhysicalSide::Left || $b == PhysicalSide::Left;
+        match (is_top, is_left) {
+          (true, true) => $property!(BorderTopLeftRadius),
+          (true, false) => $property!(BorderTopRightRadius),
+          (false, true) => $property!(BorderBottomLeftRadius),
+          (false, false) => $property!(BorderBottomRightRadius),
+        }
+      }};
+    }
+
     macro_rules! logical_property {
-      ($prop: ident, $key: ident, $ltr: ident, $rtl: ident) => {
+      ($key: ident, $block: ident, $inline: ident) => {
         if let Some(val) = $key {
           if logical_supported {
             dest.push(val);
           } else {
-            let vp = if let Some(targets) = self.targets {
-              Feature::$ltr.prefixes_for(targets)
-            } else {
-              VendorPrefix::None
-            };
+            macro_rules! prefixes {
+              ($prop: ident) => {
+                if let Some(targets) = targets {
+                  Feature::$prop.prefixes_for(targets)
+                } else {
+                  VendorPrefix::None
+                }
+              };
+            }
 
             match val {
               Property::BorderStartStartRadius(val)
               | Property::BorderStartEndRadius(val)
               | Property::BorderEndStartRadius(val)
               | Property::BorderEndEndRadius(val) => {
-                context.add_logical_rule(Property::$ltr(val.clone(), vp), Property::$rtl(val, vp));
+                macro_rules! typed {
+                  ($prop: ident) => {
+                    Property::$prop(val.clone(), prefixes!($prop))
+                  };
+                }
+
+                context.add_physical_corner_property(
+                  dest,
+                  LogicalSide::$block,
+                  LogicalSide::$inline,
+                  |block, inline| physical_corner!(block, inline, typed),
+                );
               }
               Property::Unparsed(val) => {
-                context.add_logical_rule(
-                  Property::Unparsed(val.with_property_id(PropertyId::$ltr(vp))),
-                  Property::Unparsed(val.with_property_id(PropertyId::$rtl(vp))),
+                macro_rules! unparsed {
+                  ($prop: ident) => {
+                    Property::Unparsed(val.with_property_id(PropertyId::$prop(prefixes!($prop))))
+                  };
+                }
+
+                context.add_physical_corner_property(
+                  dest,
+                  LogicalSide::$block,
+                  LogicalSide::$inline,
+                  |block, inline| physical_corner!(block, inline, unparsed),
                 );
               }
               _ => {}
@@ -317,30 +356,10 @@ impl<'i> BorderRadiusHandler<'i> {
     single_property!(BorderTopRightRadius, top_right);
     single_property!(BorderBottomLeftRadius, bottom_left);
     single_property!(BorderBottomRightRadius, bottom_right);
-    logical_property!(
-      BorderStartStartRadius,
-      start_start,
-      BorderTopLeftRadius,
-      BorderTopRightRadius
-    );
-    logical_property!(
-      BorderStartEndRadius,
-      start_end,
-      BorderTopRightRadius,
-      BorderTopLeftRadius
-    );
-    logical_property!(
-      BorderEndStartRadius,
-      end_start,
-      BorderBottomLeftRadius,
-      BorderBottomRightRadius
-    );
-    logical_property!(
-      BorderEndEndRadius,
-      end_end,
-      BorderBottomRightRadius,
-      BorderBottomLeftRadius
-    );
+    logical_property!(start_start, BlockStart, InlineStart);
+    logical_property!(start_end, BlockStart, InlineEnd);
+    logical_property!(end_start, BlockEnd, InlineStart);
+    logical_property!(end_end, BlockEnd, InlineEnd);
   }
 }
 
@@ -0,0 +1,431 @@
+//! CSS properties related to multi-column layout.
+
+use super::border::{BorderSideWidth, GenericBorder, LineStyle};
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, impl_shorthand};
+use crate::prefixes::Feature;
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
+use crate::values::color::CssColor;
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::vendor_prefix::VendorPrefix;
+use cssparser::*;
+
+/// A value for the [column-width](https://www.w3.org/TR/css-multicol-1/#cw) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ColumnWidth {
+  /// The column width is determined by other properties.
+  Auto,
+  /// An explicit column width.
+  Length(Length),
+}
+
+impl Default for ColumnWidth {
+  fn default() -> ColumnWidth {
+    ColumnWidth::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ColumnWidth {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnWidth::Auto);
+    }
+
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.is_sign_negative() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(ColumnWidth::Length(length))
+  }
+}
+
+impl ToCss for ColumnWidth {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnWidth::Auto => dest.write_str("auto"),
+      ColumnWidth::Length(length) => length.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [column-count](https://www.w3.org/TR/css-multicol-1/#cc) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ColumnCount {
+  /// The number of columns is determined by other properties.
+  Auto,
+  /// An explicit number of columns.
+  Integer(CSSInteger),
+}
+
+impl Default for ColumnCount {
+  fn default() -> ColumnCount {
+    ColumnCount::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ColumnCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnCount::Auto);
+    }
+
+    let location = input.current_source_location();
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(ColumnCount::Integer(count))
+  }
+}
+
+impl ToCss for ColumnCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnCount::Auto => dest.write_str("auto"),
+      ColumnCount::Integer(count) => count.to_css(dest),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [columns](https://www.w3.org/TR/css-multicol-1/#columns) shorthand property.
+  pub struct Columns(VendorPrefix) {
+    /// The width of the columns.
+    width: ColumnWidth(ColumnWidth, VendorPrefix),
+    /// The number of columns.
+    count: ColumnCount(ColumnCount, VendorPrefix),
+  }
+}
+
+impl<'i> Parse<'i> for Columns {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut count = None;
+    let mut autos = 0;
+
+    // `auto` is ambiguous, so it is applied to whichever value is not otherwise specified.
+    for _ in 0..2 {
+      if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+        autos += 1;
+        continue;
+      }
+
+      if count.is_none() {
+        if let Ok(ColumnCount::Integer(val)) = input.try_parse(ColumnCount::parse) {
+          count = Some(ColumnCount::Integer(val));
+          continue;
+        }
+      }
+
+      if width.is_none() {
+        if let Ok(ColumnWidth::Length(val)) = input.try_parse(ColumnWidth::parse) {
+          width = Some(ColumnWidth::Length(val));
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if autos == 0 && width.is_none() && count.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(Columns {
+      width: width.unwrap_or_default(),
+      count: count.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for Columns {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.width, &self.count) {
+      (ColumnWidth::Auto, ColumnCount::Auto) => dest.write_str("auto"),
+      (ColumnWidth::Auto, count) => count.to_css(dest),
+      (width, ColumnCount::Auto) => width.to_css(dest),
+      (width, count) => {
+        width.to_css(dest)?;
+        dest.write_char(' ')?;
+        count.to_css(dest)
+      }
+    }
+  }
+}
+
+/// A value for the [column-rule](https://www.w3.org/TR/css-multicol-1/#column-rule) shorthand property.
+pub type ColumnRule = GenericBorder<LineStyle, 12>;
+
+impl_shorthand! {
+  ColumnRule(ColumnRule, VendorPrefix) {
+    width: [ColumnRuleWidth(VendorPrefix)],
+    style: [ColumnRuleStyle(VendorPrefix)],
+    color: [ColumnRuleColor(VendorPrefix)],
+  }
+}
+
+enum_property! {
+  /// A value for the [column-span](https://www.w3.org/TR/css-multicol-1/#column-span) property.
+  pub enum ColumnSpan {
+    /// The element does not span multiple columns.
+    None,
+    /// The element spans across all columns.
+    All,
+  }
+}
+
+enum_property! {
+  /// A value for the [column-fill](https://www.w3.org/TR/css-multicol-1/#cf) property.
+  pub enum ColumnFill {
+    /// Columns are filled sequentially.
+    "auto": Auto,
+    /// Content is balanced equally between columns, except in the last fragment.
+    "balance": Balance,
+    /// Content is balanced equally between columns in all fragments.
+    "balance-all": BalanceAll,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ColumnsHandler {
+  targets: Option<Browsers>,
+  width: Option<(ColumnWidth, VendorPrefix)>,
+  count: Option<(ColumnCount, VendorPrefix)>,
+  rule_width: Option<(BorderSideWidth, VendorPrefix)>,
+  rule_style: Option<(LineStyle, VendorPrefix)>,
+  rule_color: Option<(CssColor, VendorPrefix)>,
+  has_any: bool,
+}
+
+impl ColumnsHandler {
+  pub fn new(targets: Option<Browsers>) -> ColumnsHandler {
+    ColumnsHandler {
+      targets,
+      ..ColumnsHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ColumnsHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    macro_rules! maybe_flush {
+      ($prop: ident, $val: expr, $vp: expr) => {{
+        // If two vendor prefixes for the same property have different
+        // values, we need to flush what we have immediately to preserve order.
+        if let Some((val, prefixes)) = &self.$prop {
+          if val != $val && !prefixes.contains(*$vp) {
+            self.finalize(dest, context);
+          }
+        }
+      }};
+    }
+
+    macro_rules! update {
+      ($prop: ident, $val: expr, $vp: expr) => {{
+        if let Some((val, prefixes)) = &mut self.$prop {
+          *val = $val.clone();
+          *prefixes |= *$vp;
+        } else {
+          self.$prop = Some(($val.clone(), *$vp));
+          self.has_any = true;
+        }
+      }};
+    }
+
+    macro_rules! property {
+      ($prop: ident, $val: expr, $vp: expr) => {{
+        maybe_flush!($prop, $val, $vp);
+
+        // Otherwise, update the value and add the prefix.
+        update!($prop, $val, $vp);
+      }};
+    }
+
+    match property {
+      ColumnWidth(val, vp) => property!(width, val, vp),
+      ColumnCount(val, vp) => property!(count, val, vp),
+      Columns(val, vp) => {
+        // Flush before updating either longhand so a prefixed shorthand is kept intact.
+        maybe_flush!(width, &val.width, vp);
+        maybe_flush!(count, &val.count, vp);
+        update!(width, &val.width, vp);
+        update!(count, &val.count, vp);
+      }
+      ColumnRuleWidth(val, vp) => property!(rule_width, val, vp),
+      ColumnRuleStyle(val, vp) => property!(rule_style, val, vp),
+      ColumnRuleColor(val, vp) => property!(rule_color, val, vp),
+      ColumnRule(val, vp) => {
+        maybe_flush!(rule_width, &val.width, vp);
+        maybe_flush!(rule_style, &val.style, vp);
+        maybe_flush!(rule_color, &val.color, vp);
+        update!(rule_width, &val.width, vp);
+        update!(rule_style, &val.style, vp);
+        update!(rule_color, &val.color, vp);
+      }
+      Unparsed(val) => {
+        let feature = match column_feature(&val.property_id) {
+          Some(feature) => feature,
+          None => return false,
+        };
+
+        self.finalize(dest, context);
+        let mut unparsed = val.get_prefixed(self.targets, feature);
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed))
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut width = std::mem::take(&mut self.width);
+    let mut count = std::mem::take(&mut self.count);
+    let mut rule_width = std::mem::take(&mut self.rule_width);
+    let mut rule_style = std::mem::take(&mut self.rule_style);
+    let mut rule_color = std::mem::take(&mut self.rule_color);
+
+    if let (Some((width, width_vp)), Some((count, count_vp))) = (&mut width, &mut count) {
+      let intersection = *width_vp & *count_vp;
+      if !intersection.is_empty() {
+        let mut prefix = intersection;
+        if prefix.contains(VendorPrefix::None) {
+          if let Some(targets) = self.targets {
+            prefix = Feature::Columns.prefixes_for(targets);
+          }
+        }
+
+        let columns = Columns {
+          width: width.clone(),
+          count: count.clone(),
+        };
+        dest.push(Property::Columns(columns, prefix));
+        width_vp.remove(intersection);
+        count_vp.remove(intersection);
+      }
+    }
+
+    if let (Some((width, width_vp)), Some((style, style_vp)), Some((color, color_vp))) =
+      (&mut rule_width, &mut rule_style, &mut rule_color)
+    {
+      let intersection = *width_vp & *style_vp & *color_vp;
+      if !intersection.is_empty() {
+        let mut prefix = intersection;
+        let mut rule = ColumnRule {
+          width: width.clone(),
+          style: *style,
+          color: color.clone(),
+        };
+
+        if prefix.contains(VendorPrefix::None) {
+          if let Some(targets) = self.targets {
+            prefix = Feature::ColumnRule.prefixes_for(targets);
+
+            let fallbacks = rule.get_fallbacks(targets);
+            for fallback in fallbacks {
+              dest.push(Property::ColumnRule(fallback, prefix))
+            }
+          }
+        }
+
+        dest.push(Property::ColumnRule(rule, prefix));
+        width_vp.remove(intersection);
+        style_vp.remove(intersection);
+        color_vp.remove(intersection);
+      }
+    }
+
+    macro_rules! single_property {
+      ($key: ident, $prop: ident) => {
+        if let Some((val, vp)) = $key {
+          if !vp.is_empty() {
+            let mut prefix = vp;
+            if prefix.contains(VendorPrefix::None) {
+              if let Some(targets) = self.targets {
+                prefix = Feature::$prop.prefixes_for(targets);
+              }
+            }
+            dest.push(Property::$prop(val, prefix))
+          }
+        }
+      };
+    }
+
+    single_property!(width, ColumnWidth);
+    single_property!(count, ColumnCount);
+    single_property!(rule_width, ColumnRuleWidth);
+    single_property!(rule_style, ColumnRuleStyle);
+
+    if let Some((mut val, vp)) = rule_color {
+      if !vp.is_empty() {
+        let mut prefix = vp;
+        if prefix.contains(VendorPrefix::None) {
+          if let Some(targets) = self.targets {
+            prefix = Feature::ColumnRuleColor.prefixes_for(targets);
+
+            let fallbacks = val.get_fallbacks(targets);
+            for fallback in fallbacks {
+              dest.push(Property::ColumnRuleColor(fallback, prefix))
+            }
+          }
+        }
+        dest.push(Property::ColumnRuleColor(val, prefix))
+      }
+    }
+  }
+}
+
+#[inline]
+fn column_feature(property_id: &PropertyId) -> Option<Feature> {
+  let feature = match property_id {
+    PropertyId::ColumnWidth(_) => Feature::ColumnWidth,
+    PropertyId::ColumnCount(_) => Feature::ColumnCount,
+    PropertyId::Columns(_) => Feature::Columns,
+    PropertyId::ColumnRuleWidth(_) => Feature::ColumnRuleWidth,
+    PropertyId::ColumnRuleStyle(_) => Feature::ColumnRuleStyle,
+    PropertyId::ColumnRuleColor(_) => Feature::ColumnRuleColor,
+    PropertyId::ColumnRule(_) => Feature::ColumnRule,
+    _ => return None,
+  };
+  Some(feature)
+}
@@ -0,0 +1,384 @@
+//! CSS properties related to generated content.
+
+use super::list::{write_counter_name, CounterStyle, PredefinedCounterStyle};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::string::CowArcStr;
+use crate::values::{ident::CustomIdent, image::Image};
+use cssparser::*;
+use smallvec::SmallVec;
+
+enum_property! {
+  /// A [`<quote>`](https://www.w3.org/TR/css-content-3/#typedef-quote) value,
+  /// as used in the `content` property.
+  #[allow(missing_docs)]
+  pub enum Quote {
+    "open-quote": OpenQuote,
+    "close-quote": CloseQuote,
+    "no-open-quote": NoOpenQuote,
+    "no-close-quote": NoCloseQuote,
+  }
+}
+
+/// An item within the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ContentItem<'i> {
+  /// A literal string.
+  String {
+    /// The string value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    value: CowArcStr<'i>,
+  },
+  /// An image, e.g. `url()` or a gradient.
+  Image {
+    /// The image value.
+    value: Image<'i>,
+  },
+  /// A [`counter()`](https://www.w3.org/TR/css-lists-3/#funcdef-counter) function.
+  Counter {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The counter style used to format the counter value.
+    style: CounterStyle<'i>,
+  },
+  /// A [`counters()`](https://www.w3.org/TR/css-lists-3/#funcdef-counters) function.
+  Counters {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The string used to join nested counter values.
+    separator: CowArcStr<'i>,
+    /// The counter style used to format the counter values.
+    style: CounterStyle<'i>,
+  },
+  /// An [`attr()`](https://www.w3.org/TR/css-values-5/#attr-notation) function.
+  Attr {
+    /// The name of the attribute.
+    name: CowArcStr<'i>,
+  },
+  /// A quote.
+  Quote {
+    /// The quote value.
+    value: Quote,
+  },
+}
+
+const DEFAULT_COUNTER_STYLE: CounterStyle<'static> = CounterStyle::Predefined(PredefinedCounterStyle::Decimal);
+
+impl<'i> Parse<'i> for ContentItem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(value) = input.try_parse(|input| input.expect_string_cloned()) {
+      return Ok(ContentItem::String { value: value.into() });
+    }
+
+    if let Ok(value) = input.try_parse(Quote::parse) {
+      return Ok(ContentItem::Quote { value });
+    }
+
+    if let Ok(item) = input.try_parse(parse_alt_function) {
+      return Ok(item);
+    }
+
+    let location = input.current_source_location();
+    match Image::parse(input)? {
+      Image::None => Err(location.new_custom_error(ParserError::InvalidValue)),
+      value => Ok(ContentItem::Image { value }),
+    }
+  }
+}
+
+/// Parses a `counter()`, `counters()`, or `attr()` function, which are the only
+/// functions allowed in alt text.
+fn parse_alt_function<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<ContentItem<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let f = input.expect_function()?.clone();
+  match_ignore_ascii_case! { &f,
+    "counter" => input.parse_nested_block(|input| {
+      let name = CustomIdent::parse(input)?;
+      let style = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        CounterStyle::parse(input)?
+      } else {
+        DEFAULT_COUNTER_STYLE
+      };
+      Ok(ContentItem::Counter { name, style })
+    }),
+    "counters" => input.parse_nested_block(|input| {
+      let name = CustomIdent::parse(input)?;
+      input.expect_comma()?;
+      let separator = input.expect_string_cloned()?.into();
+      let style = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        CounterStyle::parse(input)?
+      } else {
+        DEFAULT_COUNTER_STYLE
+      };
+      Ok(ContentItem::Counters { name, separator, style })
+    }),
+    "attr" => input.parse_nested_block(|input| {
+      let name = input.expect_ident_cloned()?.into();
+      Ok(ContentItem::Attr { name })
+    }),
+    _ => Err(location.new_unexpected_token_error(Token::Function(f)))
+  }
+}
+
+impl<'i> ContentItem<'i> {
+  fn is_valid_alt(&self) -> bool {
+    matches!(
+      self,
+      ContentItem::String { .. }
+        | ContentItem::Counter { .. }
+        | ContentItem::Counters { .. }
+        | ContentItem::Attr { .. }
+    )
+  }
+}
+
+impl<'i> ToCss for ContentItem<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContentItem::String { value } => {
+        serialize_string(value, dest)?;
+        Ok(())
+      }
+      ContentItem::Image { value } => value.to_css(dest),
+      ContentItem::Counter { name, style } => {
+        dest.write_str("counter(")?;
+        write_counter_name(name, dest)?;
+        if *style != DEFAULT_COUNTER_STYLE {
+          dest.delim(',', false)?;
+          style.to_css(dest)?;
+        }
+        dest.write_char(')')
+      }
+      ContentItem::Counters { name, separator, style } => {
+        dest.write_str("counters(")?;
+        write_counter_name(name, dest)?;
+        dest.delim(',', false)?;
+        serialize_string(separator, dest)?;
+        if *style != DEFAULT_COUNTER_STYLE {
+          dest.delim(',', false)?;
+          style.to_css(dest)?;
+        }
+        dest.write_char(')')
+      }
+      ContentItem::Attr { name } => {
+        dest.write_str("attr(")?;
+        serialize_identifier(name, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Quote { value } => value.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum Content<'i> {
+  /// For `::before` and `::after`, computes to `none`. Otherwise, the element is rendered normally.
+  Normal,
+  /// No content is generated.
+  None,
+  /// A list of content items, with optional alternative text.
+  Items {
+    /// The items to render.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    items: Vec<ContentItem<'i>>,
+    /// Alternative text for accessibility, specified after a `/`. Empty if none.
+    alt: Vec<ContentItem<'i>>,
+  },
+}
+
+impl<'i> Parse<'i> for Content<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Content::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Content::None);
+    }
+
+    let mut items = vec![ContentItem::parse(input)?];
+    while let Ok(item) = input.try_parse(ContentItem::parse) {
+      items.push(item);
+    }
+
+    let mut alt = Vec::new();
+    if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      loop {
+        let location = input.current_source_location();
+        let item = if alt.is_empty() {
+          ContentItem::parse(input)?
+        } else if let Ok(item) = input.try_parse(ContentItem::parse) {
+          item
+        } else {
+          break;
+        };
+
+        if !item.is_valid_alt() {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+
+        alt.push(item);
+      }
+    }
+
+    Ok(Content::Items { items, alt })
+  }
+}
+
+impl<'i> ToCss for Content<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Content::Normal => dest.write_str("normal"),
+      Content::None => dest.write_str("none"),
+      Content::Items { items, alt } => {
+        write_content_list(items, dest)?;
+        if !alt.is_empty() {
+          dest.delim('/', true)?;
+          write_content_list(alt, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+fn write_content_list<W>(items: &[ContentItem], dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  let mut iter = items.iter().peekable();
+  while let Some(item) = iter.next() {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+
+    // Adjacent strings are equivalent to a single concatenated string.
+    if let (true, ContentItem::String { value }) = (dest.minify, item) {
+      if matches!(iter.peek(), Some(ContentItem::String { .. })) {
+        let mut s = value.to_string();
+        while let Some(ContentItem::String { value }) = iter.peek() {
+          s.push_str(value);
+          iter.next();
+        }
+        serialize_string(&s, dest)?;
+        continue;
+      }
+    }
+
+    item.to_css(dest)?;
+  }
+  Ok(())
+}
+
+/// A pair of opening and closing quotes, as used in the [quotes](https://www.w3.org/TR/css-content-3/#quotes-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuotePair<'i> {
+  /// The opening quote.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub open: CowArcStr<'i>,
+  /// The closing quote.
+  pub close: CowArcStr<'i>,
+}
+
+/// A value for the [quotes](https://www.w3.org/TR/css-content-3/#quotes-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum Quotes<'i> {
+  /// Appropriate quote marks are chosen automatically based on the content language.
+  Auto,
+  /// Quotes produce no marks.
+  None,
+  /// Uses the same quotes as the parent element.
+  MatchParent,
+  /// A list of quote pairs, used for successive levels of nesting.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Pairs(SmallVec<[QuotePair<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for Quotes<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let location = input.current_source_location();
+      return match_ignore_ascii_case! { &ident,
+        "auto" => Ok(Quotes::Auto),
+        "none" => Ok(Quotes::None),
+        "match-parent" => Ok(Quotes::MatchParent),
+        _ => Err(location.new_unexpected_token_error(Token::Ident(ident)))
+      };
+    }
+
+    let mut pairs = SmallVec::new();
+    loop {
+      let open = if pairs.is_empty() {
+        input.expect_string_cloned()?
+      } else if let Ok(open) = input.try_parse(|input| input.expect_string_cloned()) {
+        open
+      } else {
+        break;
+      };
+      let close = input.expect_string_cloned()?;
+      pairs.push(QuotePair {
+        open: open.into(),
+        close: close.into(),
+      });
+    }
+
+    Ok(Quotes::Pairs(pairs))
+  }
+}
+
+impl<'i> ToCss for Quotes<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Quotes::Auto => dest.write_str("auto"),
+      Quotes::None => dest.write_str("none"),
+      Quotes::MatchParent => dest.write_str("match-parent"),
+      Quotes::Pairs(pairs) => {
+        let mut first = true;
+        for pair in pairs {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          serialize_string(&pair.open, dest)?;
+          dest.write_char(' ')?;
+          serialize_string(&pair.close, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
@@ -9,7 +9,7 @@ use crate::macros::enum_property;
 use crate::prefixes::{is_flex_2009, Feature};
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
@@ -366,6 +366,28 @@ enum_property! {
   }
 }
 
+impl IsCompatible for Display {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    let feature = match self {
+      Display::Pair(DisplayPair {
+        inside: DisplayInside::Flex(VendorPrefix::None),
+        ..
+      }) => Feature::DisplayFlex,
+      Display::Pair(DisplayPair {
+        inside: DisplayInside::Flex(..) | DisplayInside::Box(..),
+        ..
+      }) => return false,
+      Display::Pair(DisplayPair {
+        inside: DisplayInside::Grid,
+        ..
+      }) => Feature::DisplayGrid,
+      _ => return true,
+    };
+
+    targets.map_or(false, |targets| feature.prefixes_for(targets) == VendorPrefix::None)
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct DisplayHandler<'i> {
   targets: Option<Browsers>,
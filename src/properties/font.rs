@@ -9,8 +9,10 @@ use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
 use crate::printer::Printer;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
-use crate::values::number::CSSNumber;
+use crate::targets::Browsers;
+use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::CustomIdent;
+use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
 use crate::values::{angle::Angle, length::LengthPercentage, percentage::Percentage};
 use cssparser::*;
@@ -200,6 +202,15 @@ impl ToCss for FontSize {
   }
 }
 
+impl FallbackValues for FontSize {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      FontSize::Length(l) => l.get_fallbacks(targets).into_iter().map(FontSize::Length).collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
 enum_property! {
   /// A [font stretch keyword](https://www.w3.org/TR/css-fonts-4/#font-stretch-prop),
   /// as used in the `font-stretch` property.
@@ -528,6 +539,1057 @@ impl FontVariantCaps {
   }
 }
 
+/// Parses a value into `field` if it has not already been set.
+fn try_parse_field<'i, 't, T: Parse<'i>>(field: &mut Option<T>, input: &mut Parser<'i, 't>) -> bool {
+  if field.is_none() {
+    if let Ok(value) = input.try_parse(T::parse) {
+      *field = Some(value);
+      return true;
+    }
+  }
+  false
+}
+
+/// Parses a keyword into `flag` if it has not already been set.
+fn try_parse_flag<'i, 't>(flag: &mut bool, name: &str, input: &mut Parser<'i, 't>) -> bool {
+  if !*flag && input.try_parse(|input| input.expect_ident_matching(name)).is_ok() {
+    *flag = true;
+    return true;
+  }
+  false
+}
+
+/// Writes a list of space separated optional values, followed by keyword flags.
+macro_rules! write_values {
+  ($dest: ident, [$($val: expr),*], [$($flag: expr => $name: literal),*]) => {{
+    let mut needs_space = false;
+    $(
+      if let Some(val) = $val {
+        if needs_space {
+          $dest.write_char(' ')?;
+        }
+        val.to_css($dest)?;
+        needs_space = true;
+      }
+    )*
+    $(
+      if $flag {
+        if needs_space {
+          $dest.write_char(' ')?;
+        }
+        $dest.write_str($name)?;
+        needs_space = true;
+      }
+    )*
+    let _ = needs_space;
+  }};
+}
+
+enum_property! {
+  /// A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) keyword.
+  pub enum CommonLigValues {
+    /// Enables display of common ligatures.
+    "common-ligatures": CommonLigatures,
+    /// Disables display of common ligatures.
+    "no-common-ligatures": NoCommonLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) keyword.
+  pub enum DiscretionaryLigValues {
+    /// Enables display of discretionary ligatures.
+    "discretionary-ligatures": DiscretionaryLigatures,
+    /// Disables display of discretionary ligatures.
+    "no-discretionary-ligatures": NoDiscretionaryLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) keyword.
+  pub enum HistoricalLigValues {
+    /// Enables display of historical ligatures.
+    "historical-ligatures": HistoricalLigatures,
+    /// Disables display of historical ligatures.
+    "no-historical-ligatures": NoHistoricalLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) keyword.
+  pub enum ContextualAltValues {
+    /// Enables display of contextual alternates.
+    "contextual": Contextual,
+    /// Disables display of contextual alternates.
+    "no-contextual": NoContextual,
+  }
+}
+
+/// A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+///
+/// If no values are set, this represents `normal`. The `none` keyword is represented by
+/// disabling all types of ligatures and contextual forms.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantLigatures {
+  /// Common ligatures.
+  pub common: Option<CommonLigValues>,
+  /// Discretionary ligatures.
+  pub discretionary: Option<DiscretionaryLigValues>,
+  /// Historical ligatures.
+  pub historical: Option<HistoricalLigValues>,
+  /// Contextual alternates.
+  pub contextual: Option<ContextualAltValues>,
+}
+
+impl FontVariantLigatures {
+  /// Returns the value for the `none` keyword.
+  pub fn none() -> FontVariantLigatures {
+    FontVariantLigatures {
+      common: Some(CommonLigValues::NoCommonLigatures),
+      discretionary: Some(DiscretionaryLigValues::NoDiscretionaryLigatures),
+      historical: Some(HistoricalLigValues::NoHistoricalLigatures),
+      contextual: Some(ContextualAltValues::NoContextual),
+    }
+  }
+
+  fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    try_parse_field(&mut self.common, input)
+      || try_parse_field(&mut self.discretionary, input)
+      || try_parse_field(&mut self.historical, input)
+      || try_parse_field(&mut self.contextual, input)
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_values!(
+      dest,
+      [&self.common, &self.discretionary, &self.historical, &self.contextual],
+      []
+    );
+    Ok(())
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantLigatures {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantLigatures::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariantLigatures::none());
+    }
+
+    let mut value = FontVariantLigatures::default();
+    while value.parse_keyword(input) {}
+    if value == FontVariantLigatures::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantLigatures {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantLigatures::default() {
+      return dest.write_str("normal");
+    }
+
+    if *self == FontVariantLigatures::none() {
+      return dest.write_str("none");
+    }
+
+    self.write_keywords(dest)
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) keyword.
+  pub enum NumericFigureValues {
+    /// Enables display of lining numerals.
+    "lining-nums": LiningNums,
+    /// Enables display of old-style numerals.
+    "oldstyle-nums": OldstyleNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) keyword.
+  pub enum NumericSpacingValues {
+    /// Enables display of proportional numerals.
+    "proportional-nums": ProportionalNums,
+    /// Enables display of tabular numerals.
+    "tabular-nums": TabularNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) keyword.
+  pub enum NumericFractionValues {
+    /// Enables display of lining diagonal fractions.
+    "diagonal-fractions": DiagonalFractions,
+    /// Enables display of lining stacked fractions.
+    "stacked-fractions": StackedFractions,
+  }
+}
+
+/// A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+///
+/// If no values are set, this represents `normal`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantNumeric {
+  /// The numeral glyphs to use.
+  pub figure: Option<NumericFigureValues>,
+  /// The numeral spacing to use.
+  pub spacing: Option<NumericSpacingValues>,
+  /// The fraction glyphs to use.
+  pub fraction: Option<NumericFractionValues>,
+  /// Whether ordinal forms are enabled.
+  pub ordinal: bool,
+  /// Whether slashed zeros are enabled.
+  pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+  fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    try_parse_field(&mut self.figure, input)
+      || try_parse_field(&mut self.spacing, input)
+      || try_parse_field(&mut self.fraction, input)
+      || try_parse_flag(&mut self.ordinal, "ordinal", input)
+      || try_parse_flag(&mut self.slashed_zero, "slashed-zero", input)
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_values!(
+      dest,
+      [&self.figure, &self.spacing, &self.fraction],
+      [self.ordinal => "ordinal", self.slashed_zero => "slashed-zero"]
+    );
+    Ok(())
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantNumeric {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantNumeric::default());
+    }
+
+    let mut value = FontVariantNumeric::default();
+    while value.parse_keyword(input) {}
+    if value == FontVariantNumeric::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantNumeric {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantNumeric::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest)
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-variant-values) keyword.
+  pub enum EastAsianVariantValues {
+    /// JIS X 0208:1978 glyph forms.
+    Jis78,
+    /// JIS X 0208:1983 glyph forms.
+    Jis83,
+    /// JIS X 0208:1990 glyph forms.
+    Jis90,
+    /// JIS X 0213:2004 glyph forms.
+    Jis04,
+    /// Simplified Chinese glyph forms.
+    Simplified,
+    /// Traditional Chinese glyph forms.
+    Traditional,
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-width-values) keyword.
+  pub enum EastAsianWidthValues {
+    /// Enables display of full-width variants.
+    "full-width": FullWidth,
+    /// Enables display of proportionally-spaced variants.
+    "proportional-width": ProportionalWidth,
+  }
+}
+
+/// A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+///
+/// If no values are set, this represents `normal`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantEastAsian {
+  /// The glyph forms to use.
+  pub variant: Option<EastAsianVariantValues>,
+  /// The glyph widths to use.
+  pub width: Option<EastAsianWidthValues>,
+  /// Whether ruby variant glyphs are enabled.
+  pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+  fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    try_parse_field(&mut self.variant, input)
+      || try_parse_field(&mut self.width, input)
+      || try_parse_flag(&mut self.ruby, "ruby", input)
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_values!(dest, [&self.variant, &self.width], [self.ruby => "ruby"]);
+    Ok(())
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantEastAsian {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantEastAsian::default());
+    }
+
+    let mut value = FontVariantEastAsian::default();
+    while value.parse_keyword(input) {}
+    if value == FontVariantEastAsian::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantEastAsian {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantEastAsian::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest)
+  }
+}
+
+/// A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+///
+/// The functional values reference names defined by `@font-feature-values` rules.
+/// If no values are set, this represents `normal`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantAlternates<'i> {
+  /// The `stylistic()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub stylistic: Option<CustomIdent<'i>>,
+  /// Whether historical forms are enabled.
+  pub historical_forms: bool,
+  /// The `styleset()` function. Empty if not specified.
+  pub styleset: Vec<CustomIdent<'i>>,
+  /// The `character-variant()` function. Empty if not specified.
+  pub character_variant: Vec<CustomIdent<'i>>,
+  /// The `swash()` function.
+  pub swash: Option<CustomIdent<'i>>,
+  /// The `ornaments()` function.
+  pub ornaments: Option<CustomIdent<'i>>,
+  /// The `annotation()` function.
+  pub annotation: Option<CustomIdent<'i>>,
+}
+
+impl<'i> FontVariantAlternates<'i> {
+  fn parse_keyword<'t>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    if try_parse_flag(&mut self.historical_forms, "historical-forms", input) {
+      return true;
+    }
+
+    input
+      .try_parse(|input| {
+        let location = input.current_source_location();
+        let f = input.expect_function()?.clone();
+        macro_rules! single {
+          ($field: ident) => {{
+            if self.$field.is_some() {
+              return Err(location.new_unexpected_token_error(Token::Function(f.clone())));
+            }
+            self.$field = Some(input.parse_nested_block(CustomIdent::parse)?);
+          }};
+        }
+
+        macro_rules! list {
+          ($field: ident) => {{
+            if !self.$field.is_empty() {
+              return Err(location.new_unexpected_token_error(Token::Function(f.clone())));
+            }
+            self.$field = input.parse_nested_block(|input| input.parse_comma_separated(CustomIdent::parse))?;
+          }};
+        }
+
+        match_ignore_ascii_case! { &f,
+          "stylistic" => single!(stylistic),
+          "styleset" => list!(styleset),
+          "character-variant" => list!(character_variant),
+          "swash" => single!(swash),
+          "ornaments" => single!(ornaments),
+          "annotation" => single!(annotation),
+          _ => return Err(location.new_unexpected_token_error(Token::Function(f.clone())))
+        }
+
+        Ok::<_, ParseError<'i, ParserError<'i>>>(())
+      })
+      .is_ok()
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! function {
+      ($name: literal, $values: expr) => {{
+        let mut first = true;
+        for value in $values {
+          if first {
+            if needs_space {
+              dest.write_char(' ')?;
+            }
+            dest.write_str($name)?;
+            dest.write_char('(')?;
+            first = false;
+            needs_space = true;
+          } else {
+            dest.delim(',', false)?;
+          }
+          // These names reference @font-feature-values, so they are not renamed by CSS modules.
+          serialize_identifier(&value.0, dest)?;
+        }
+        if !first {
+          dest.write_char(')')?;
+        }
+      }};
+    }
+
+    function!("stylistic", option_slice(&self.stylistic));
+    if self.historical_forms {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      dest.write_str("historical-forms")?;
+      needs_space = true;
+    }
+    function!("styleset", &self.styleset);
+    function!("character-variant", &self.character_variant);
+    function!("swash", option_slice(&self.swash));
+    function!("ornaments", option_slice(&self.ornaments));
+    function!("annotation", option_slice(&self.annotation));
+    let _ = needs_space;
+    Ok(())
+  }
+}
+
+fn option_slice<T>(value: &Option<T>) -> &[T] {
+  match value {
+    Some(value) => std::slice::from_ref(value),
+    None => &[],
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantAlternates<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantAlternates::default());
+    }
+
+    let mut value = FontVariantAlternates::default();
+    while value.parse_keyword(input) {}
+    if value == FontVariantAlternates::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariantAlternates<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantAlternates::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest)
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+  pub enum FontVariantPosition {
+    /// No subscript or superscript glyphs are used.
+    Normal,
+    /// Enables display of subscript variants.
+    Sub,
+    /// Enables display of superscript variants.
+    Super,
+  }
+}
+
+impl Default for FontVariantPosition {
+  fn default() -> FontVariantPosition {
+    FontVariantPosition::Normal
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+  pub struct FontVariant<'i> {
+    /// The ligatures and contextual forms to use.
+    ligatures: FontVariantLigatures(FontVariantLigatures),
+    /// How the text should be capitalized.
+    caps: FontVariantCaps(FontVariantCaps),
+    /// The alternate glyphs to use.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    alternates: FontVariantAlternates(FontVariantAlternates<'i>),
+    /// The numeric glyphs to use.
+    numeric: FontVariantNumeric(FontVariantNumeric),
+    /// The East Asian glyphs to use.
+    east_asian: FontVariantEastAsian(FontVariantEastAsian),
+    /// The subscript or superscript glyphs to use.
+    position: FontVariantPosition(FontVariantPosition),
+  }
+}
+
+impl<'i> Default for FontVariant<'i> {
+  fn default() -> FontVariant<'i> {
+    FontVariant {
+      ligatures: FontVariantLigatures::default(),
+      caps: FontVariantCaps::default(),
+      alternates: FontVariantAlternates::default(),
+      numeric: FontVariantNumeric::default(),
+      east_asian: FontVariantEastAsian::default(),
+      position: FontVariantPosition::default(),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariant<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariant::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariant {
+        ligatures: FontVariantLigatures::none(),
+        ..FontVariant::default()
+      });
+    }
+
+    let mut ligatures = FontVariantLigatures::default();
+    let mut caps = None;
+    let mut alternates = FontVariantAlternates::default();
+    let mut numeric = FontVariantNumeric::default();
+    let mut east_asian = FontVariantEastAsian::default();
+    let mut position = None;
+    let mut count = 0;
+    while ligatures.parse_keyword(input)
+      || try_parse_field(&mut caps, input)
+      || alternates.parse_keyword(input)
+      || numeric.parse_keyword(input)
+      || east_asian.parse_keyword(input)
+      || try_parse_field(&mut position, input)
+    {
+      count += 1;
+    }
+
+    // Explicit "normal" keywords for the individual properties are not allowed in the shorthand.
+    if count == 0 || caps == Some(FontVariantCaps::Normal) || position == Some(FontVariantPosition::Normal) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(FontVariant {
+      ligatures,
+      caps: caps.unwrap_or_default(),
+      alternates,
+      numeric,
+      east_asian,
+      position: position.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for FontVariant<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariant::default() {
+      return dest.write_str("normal");
+    }
+
+    let none = FontVariant {
+      ligatures: FontVariantLigatures::none(),
+      ..FontVariant::default()
+    };
+    if *self == none {
+      return dest.write_str("none");
+    }
+
+    let mut needs_space = false;
+    macro_rules! write_keywords {
+      ($val: expr) => {{
+        if needs_space {
+          dest.write_char(' ')?;
+        }
+        $val?;
+        needs_space = true;
+      }};
+    }
+
+    if self.ligatures != FontVariantLigatures::default() {
+      write_keywords!(self.ligatures.write_keywords(dest));
+    }
+    if self.caps != FontVariantCaps::default() {
+      write_keywords!(self.caps.to_css(dest));
+    }
+    if self.alternates != FontVariantAlternates::default() {
+      write_keywords!(self.alternates.write_keywords(dest));
+    }
+    if self.numeric != FontVariantNumeric::default() {
+      write_keywords!(self.numeric.write_keywords(dest));
+    }
+    if self.east_asian != FontVariantEastAsian::default() {
+      write_keywords!(self.east_asian.write_keywords(dest));
+    }
+    if self.position != FontVariantPosition::default() {
+      write_keywords!(self.position.to_css(dest));
+    }
+    let _ = needs_space;
+    Ok(())
+  }
+}
+
+/// A single feature tag and value, as used in the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureTagValue<'i> {
+  /// The four letter OpenType feature tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The feature value. `on` is equivalent to 1, and `off` is equivalent to 0.
+  pub value: CSSInteger,
+}
+
+/// Parses a four letter OpenType tag.
+fn parse_opentype_tag<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CowArcStr<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let tag = input.expect_string_cloned()?;
+  if tag.len() != 4 || !tag.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(tag.into())
+}
+
+impl<'i> Parse<'i> for FeatureTagValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let value = if let Ok(value) = input.try_parse(CSSInteger::parse) {
+      if value < 0 {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      value
+    } else if input.try_parse(|input| input.expect_ident_matching("off")).is_ok() {
+      0
+    } else {
+      let _ = input.try_parse(|input| input.expect_ident_matching("on"));
+      1
+    };
+    Ok(FeatureTagValue { tag, value })
+  }
+}
+
+impl<'i> ToCss for FeatureTagValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    if self.value != 1 {
+      dest.write_char(' ')?;
+      self.value.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontFeatureSettings<'i> {
+  /// No features are changed.
+  Normal,
+  /// A list of features to enable or disable.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(Vec<FeatureTagValue<'i>>),
+}
+
+impl<'i> Default for FontFeatureSettings<'i> {
+  fn default() -> FontFeatureSettings<'i> {
+    FontFeatureSettings::Normal
+  }
+}
+
+impl<'i> Parse<'i> for FontFeatureSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontFeatureSettings::Normal);
+    }
+
+    let features = input.parse_comma_separated(FeatureTagValue::parse)?;
+    Ok(FontFeatureSettings::Features(features))
+  }
+}
+
+impl<'i> ToCss for FontFeatureSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontFeatureSettings::Normal => dest.write_str("normal"),
+      FontFeatureSettings::Features(features) => {
+        let mut first = true;
+        for feature in features {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A single axis and value, as used in the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariationAxisValue<'i> {
+  /// The four letter OpenType axis tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The axis value.
+  pub value: CSSNumber,
+}
+
+impl<'i> Parse<'i> for VariationAxisValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let value = CSSNumber::parse(input)?;
+    Ok(VariationAxisValue { tag, value })
+  }
+}
+
+impl<'i> ToCss for VariationAxisValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    dest.write_char(' ')?;
+    self.value.to_css(dest)
+  }
+}
+
+/// A value for the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontVariationSettings<'i> {
+  /// No variations are changed.
+  Normal,
+  /// A list of axis values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Axes(Vec<VariationAxisValue<'i>>),
+}
+
+impl<'i> Default for FontVariationSettings<'i> {
+  fn default() -> FontVariationSettings<'i> {
+    FontVariationSettings::Normal
+  }
+}
+
+impl<'i> Parse<'i> for FontVariationSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariationSettings::Normal);
+    }
+
+    let axes = input.parse_comma_separated(VariationAxisValue::parse)?;
+    Ok(FontVariationSettings::Axes(axes))
+  }
+}
+
+impl<'i> ToCss for FontVariationSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontVariationSettings::Normal => dest.write_str("normal"),
+      FontVariationSettings::Axes(axes) => {
+        let mut first = true;
+        for axis in axes {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          axis.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [font-kerning](https://www.w3.org/TR/css-fonts-4/#font-kerning-prop) property.
+  pub enum FontKerning {
+    /// The browser determines whether kerning is applied.
+    Auto,
+    /// Kerning is applied.
+    Normal,
+    /// Kerning is not applied.
+    None,
+  }
+}
+
+impl Default for FontKerning {
+  fn default() -> FontKerning {
+    FontKerning::Auto
+  }
+}
+
+enum_property! {
+  /// A value for the [font-optical-sizing](https://www.w3.org/TR/css-fonts-4/#font-optical-sizing-def) property.
+  pub enum FontOpticalSizing {
+    /// The browser may modify glyph shapes based on the font size.
+    Auto,
+    /// Optical sizing is disabled.
+    None,
+  }
+}
+
+impl Default for FontOpticalSizing {
+  fn default() -> FontOpticalSizing {
+    FontOpticalSizing::Auto
+  }
+}
+
+/// A value for the [font-synthesis](https://www.w3.org/TR/css-fonts-4/#font-synthesis) property.
+///
+/// If no values are set, this represents `none`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontSynthesis {
+  /// Whether bold faces may be synthesized.
+  pub weight: bool,
+  /// Whether oblique faces may be synthesized.
+  pub style: bool,
+  /// Whether small caps may be synthesized.
+  pub small_caps: bool,
+  /// Whether subscript and superscript glyphs may be synthesized.
+  pub position: bool,
+}
+
+impl Default for FontSynthesis {
+  fn default() -> FontSynthesis {
+    FontSynthesis {
+      weight: true,
+      style: true,
+      small_caps: true,
+      position: true,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontSynthesis {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontSynthesis {
+      weight: false,
+      style: false,
+      small_caps: false,
+      position: false,
+    };
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(value);
+    }
+
+    let mut count = 0;
+    while try_parse_flag(&mut value.weight, "weight", input)
+      || try_parse_flag(&mut value.style, "style", input)
+      || try_parse_flag(&mut value.small_caps, "small-caps", input)
+      || try_parse_flag(&mut value.position, "position", input)
+    {
+      count += 1;
+    }
+
+    if count == 0 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for FontSynthesis {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if !self.weight && !self.style && !self.small_caps && !self.position {
+      return dest.write_str("none");
+    }
+
+    write_values!(
+      dest,
+      [],
+      [
+        self.weight => "weight",
+        self.style => "style",
+        self.small_caps => "small-caps",
+        self.position => "position"
+      ]
+    );
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A font metric used to normalize the font size in the
+  /// [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+  pub enum FontSizeAdjustMetric {
+    /// The x-height of the font.
+    "ex-height": ExHeight,
+    /// The cap height of the font.
+    "cap-height": CapHeight,
+    /// The advance width of the "0" glyph.
+    "ch-width": ChWidth,
+    /// The advance width of the "水" glyph.
+    "ic-width": IcWidth,
+    /// The advance height of the "水" glyph.
+    "ic-height": IcHeight,
+  }
+}
+
+impl Default for FontSizeAdjustMetric {
+  fn default() -> FontSizeAdjustMetric {
+    FontSizeAdjustMetric::ExHeight
+  }
+}
+
+/// A value for the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum FontSizeAdjust {
+  /// The font size is not adjusted.
+  None,
+  /// The font size is adjusted so that the given metric matches the value.
+  Value {
+    /// The font metric to normalize.
+    metric: FontSizeAdjustMetric,
+    /// The aspect value, or `None` to use the value from the primary font (`from-font`).
+    value: Option<CSSNumber>,
+  },
+}
+
+impl Default for FontSizeAdjust {
+  fn default() -> FontSizeAdjust {
+    FontSizeAdjust::None
+  }
+}
+
+impl<'i> Parse<'i> for FontSizeAdjust {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontSizeAdjust::None);
+    }
+
+    let metric = input.try_parse(FontSizeAdjustMetric::parse).unwrap_or_default();
+    let value = if input.try_parse(|input| input.expect_ident_matching("from-font")).is_ok() {
+      None
+    } else {
+      let location = input.current_source_location();
+      let value = CSSNumber::parse(input)?;
+      if value < 0.0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Some(value)
+    };
+
+    Ok(FontSizeAdjust::Value { metric, value })
+  }
+}
+
+impl ToCss for FontSizeAdjust {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontSizeAdjust::None => dest.write_str("none"),
+      FontSizeAdjust::Value { metric, value } => {
+        if *metric != FontSizeAdjustMetric::default() {
+          metric.to_css(dest)?;
+          dest.write_char(' ')?;
+        }
+
+        match value {
+          Some(value) => value.to_css(dest),
+          None => dest.write_str("from-font"),
+        }
+      }
+    }
+  }
+}
+
 /// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -789,6 +1851,17 @@ pub(crate) struct FontHandler<'i> {
   stretch: Option<FontStretch>,
   line_height: Option<LineHeight>,
   variant_caps: Option<FontVariantCaps>,
+  variant_ligatures: Option<FontVariantLigatures>,
+  variant_alternates: Option<FontVariantAlternates<'i>>,
+  variant_numeric: Option<FontVariantNumeric>,
+  variant_east_asian: Option<FontVariantEastAsian>,
+  variant_position: Option<FontVariantPosition>,
+  feature_settings: Option<FontFeatureSettings<'i>>,
+  variation_settings: Option<FontVariationSettings<'i>>,
+  kerning: Option<FontKerning>,
+  optical_sizing: Option<FontOpticalSizing>,
+  size_adjust: Option<FontSizeAdjust>,
+  synthesis: Option<FontSynthesis>,
   has_any: bool,
 }
 
@@ -815,7 +1888,27 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       FontWeight(val) => property!(weight, val),
       FontStretch(val) => property!(stretch, val),
       FontVariantCaps(val) => property!(variant_caps, val),
+      FontVariantLigatures(val) => property!(variant_ligatures, val),
+      FontVariantAlternates(val) => property!(variant_alternates, val),
+      FontVariantNumeric(val) => property!(variant_numeric, val),
+      FontVariantEastAsian(val) => property!(variant_east_asian, val),
+      FontVariantPosition(val) => property!(variant_position, val),
+      FontFeatureSettings(val) => property!(feature_settings, val),
+      FontVariationSettings(val) => property!(variation_settings, val),
+      FontKerning(val) => property!(kerning, val),
+      FontOpticalSizing(val) => property!(optical_sizing, val),
+      FontSizeAdjust(val) => property!(size_adjust, val),
+      FontSynthesis(val) => property!(synthesis, val),
       LineHeight(val) => property!(line_height, val),
+      FontVariant(val) => {
+        self.variant_ligatures = Some(val.ligatures.clone());
+        self.variant_caps = Some(val.caps);
+        self.variant_alternates = Some(val.alternates.clone());
+        self.variant_numeric = Some(val.numeric.clone());
+        self.variant_east_asian = Some(val.east_asian.clone());
+        self.variant_position = Some(val.position);
+        self.has_any = true;
+      }
       Font(val) => {
         self.family = Some(val.family.clone());
         self.size = Some(val.size.clone());
@@ -824,8 +1917,18 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.stretch = Some(val.stretch.clone());
         self.line_height = Some(val.line_height.clone());
         self.variant_caps = Some(val.variant_caps.clone());
+        // The font shorthand also resets these properties to their initial values.
+        self.variant_ligatures = Some(Default::default());
+        self.variant_alternates = Some(Default::default());
+        self.variant_numeric = Some(Default::default());
+        self.variant_east_asian = Some(Default::default());
+        self.variant_position = Some(Default::default());
+        self.feature_settings = Some(Default::default());
+        self.variation_settings = Some(Default::default());
+        self.kerning = Some(Default::default());
+        self.optical_sizing = Some(Default::default());
+        self.size_adjust = Some(Default::default());
         self.has_any = true;
-        // TODO: reset other properties
       }
       Unparsed(val) if is_font_property(&val.property_id) => {
         self.finalize(dest, context);
@@ -850,10 +1953,21 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
     );
     let size = std::mem::take(&mut self.size);
     let style = std::mem::take(&mut self.style);
-    let weight = std::mem::take(&mut self.weight);
-    let stretch = std::mem::take(&mut self.stretch);
-    let line_height = std::mem::take(&mut self.line_height);
-    let variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut weight = std::mem::take(&mut self.weight);
+    let mut stretch = std::mem::take(&mut self.stretch);
+    let mut line_height = std::mem::take(&mut self.line_height);
+    let mut variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut variant_ligatures = std::mem::take(&mut self.variant_ligatures);
+    let mut variant_alternates = std::mem::take(&mut self.variant_alternates);
+    let mut variant_numeric = std::mem::take(&mut self.variant_numeric);
+    let mut variant_east_asian = std::mem::take(&mut self.variant_east_asian);
+    let mut variant_position = std::mem::take(&mut self.variant_position);
+    let mut feature_settings = std::mem::take(&mut self.feature_settings);
+    let mut variation_settings = std::mem::take(&mut self.variation_settings);
+    let mut kerning = std::mem::take(&mut self.kerning);
+    let mut optical_sizing = std::mem::take(&mut self.optical_sizing);
+    let mut size_adjust = std::mem::take(&mut self.size_adjust);
+    let synthesis = std::mem::take(&mut self.synthesis);
 
     if let Some(family) = &mut family {
       if family.len() > 1 {
@@ -872,54 +1986,174 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       && variant_caps.is_some()
     {
       let caps = variant_caps.unwrap();
-      decls.push(Property::Font(Font {
+      let mut font = Font {
         family: family.unwrap(),
         size: size.unwrap(),
         style: style.unwrap(),
-        weight: weight.unwrap(),
-        stretch: stretch.unwrap(),
-        line_height: line_height.unwrap(),
+        weight: weight.take().unwrap(),
+        stretch: stretch.take().unwrap(),
+        line_height: line_height.take().unwrap(),
         variant_caps: if caps.is_css2() {
           caps
         } else {
           FontVariantCaps::default()
         },
-      }));
+      };
+
+      if let Some(targets) = context.targets {
+        for size in font.size.get_fallbacks(targets) {
+          decls.push(Property::Font(Font { size, ..font.clone() }));
+        }
+      }
+
+      decls.push(Property::Font(font));
+
+      // All other properties reset by the `font` shorthand only need to be
+      // output if they have a non-initial value.
+      macro_rules! remove_default {
+        ($($prop: ident),+) => {
+          $(
+            if $prop.as_ref().map_or(false, |v| *v == Default::default()) {
+              $prop = None;
+            }
+          )+
+        };
+      }
 
       // The `font` property only accepts CSS 2.1 values for font-variant caps.
       // If we have a CSS 3+ value, we need to add a separate property.
-      if !caps.is_css2() {
-        decls.push(Property::FontVariantCaps(variant_caps.unwrap()))
+      fn is_default<T: Default + PartialEq>(value: &Option<T>) -> bool {
+        value.as_ref().map_or(true, |v| *v == T::default())
       }
+
+      let non_default_variants = [
+        !is_default(&variant_ligatures),
+        !is_default(&variant_alternates),
+        !is_default(&variant_numeric),
+        !is_default(&variant_east_asian),
+        !is_default(&variant_position),
+        !caps.is_css2(),
+      ]
+      .iter()
+      .filter(|v| **v)
+      .count();
+
+      // If multiple variant properties remain, they can be combined into the `font-variant` shorthand.
+      if non_default_variants > 1 {
+        variant_caps = Some(caps);
+      } else {
+        variant_caps = if caps.is_css2() { None } else { Some(caps) };
+        remove_default!(
+          variant_ligatures,
+          variant_alternates,
+          variant_numeric,
+          variant_east_asian,
+          variant_position
+        );
+      }
+
+      remove_default!(
+        feature_settings,
+        variation_settings,
+        kerning,
+        optical_sizing,
+        size_adjust
+      );
     } else {
       if let Some(val) = family {
         decls.push(Property::FontFamily(val))
       }
 
-      if let Some(val) = size {
+      if let Some(mut val) = size {
+        if let Some(targets) = context.targets {
+          for fallback in val.get_fallbacks(targets) {
+            decls.push(Property::FontSize(fallback));
+          }
+        }
         decls.push(Property::FontSize(val))
       }
 
       if let Some(val) = style {
         decls.push(Property::FontStyle(val))
       }
+    }
+
+    if variant_ligatures.is_some()
+      && variant_caps.is_some()
+      && variant_alternates.is_some()
+      && variant_numeric.is_some()
+      && variant_east_asian.is_some()
+      && variant_position.is_some()
+    {
+      decls.push(Property::FontVariant(FontVariant {
+        ligatures: variant_ligatures.unwrap(),
+        caps: variant_caps.unwrap(),
+        alternates: variant_alternates.unwrap(),
+        numeric: variant_numeric.unwrap(),
+        east_asian: variant_east_asian.unwrap(),
+        position: variant_position.unwrap(),
+      }))
+    } else {
+      if let Some(val) = variant_ligatures {
+        decls.push(Property::FontVariantLigatures(val))
+      }
 
       if let Some(val) = variant_caps {
         decls.push(Property::FontVariantCaps(val))
       }
 
-      if let Some(val) = weight {
-        decls.push(Property::FontWeight(val))
+      if let Some(val) = variant_alternates {
+        decls.push(Property::FontVariantAlternates(val))
+      }
+
+      if let Some(val) = variant_numeric {
+        decls.push(Property::FontVariantNumeric(val))
       }
 
-      if let Some(val) = stretch {
-        decls.push(Property::FontStretch(val))
+      if let Some(val) = variant_east_asian {
+        decls.push(Property::FontVariantEastAsian(val))
       }
 
-      if let Some(val) = line_height {
-        decls.push(Property::LineHeight(val))
+      if let Some(val) = variant_position {
+        decls.push(Property::FontVariantPosition(val))
       }
     }
+
+    if let Some(val) = weight {
+      decls.push(Property::FontWeight(val))
+    }
+
+    if let Some(val) = stretch {
+      decls.push(Property::FontStretch(val))
+    }
+
+    if let Some(val) = line_height {
+      decls.push(Property::LineHeight(val))
+    }
+
+    if let Some(val) = feature_settings {
+      decls.push(Property::FontFeatureSettings(val))
+    }
+
+    if let Some(val) = variation_settings {
+      decls.push(Property::FontVariationSettings(val))
+    }
+
+    if let Some(val) = kerning {
+      decls.push(Property::FontKerning(val))
+    }
+
+    if let Some(val) = optical_sizing {
+      decls.push(Property::FontOpticalSizing(val))
+    }
+
+    if let Some(val) = size_adjust {
+      decls.push(Property::FontSizeAdjust(val))
+    }
+
+    if let Some(val) = synthesis {
+      decls.push(Property::FontSynthesis(val))
+    }
   }
 }
 
@@ -970,6 +2204,18 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     | PropertyId::FontWeight
     | PropertyId::FontStretch
     | PropertyId::FontVariantCaps
+    | PropertyId::FontVariantLigatures
+    | PropertyId::FontVariantAlternates
+    | PropertyId::FontVariantNumeric
+    | PropertyId::FontVariantEastAsian
+    | PropertyId::FontVariantPosition
+    | PropertyId::FontVariant
+    | PropertyId::FontFeatureSettings
+    | PropertyId::FontVariationSettings
+    | PropertyId::FontKerning
+    | PropertyId::FontOpticalSizing
+    | PropertyId::FontSizeAdjust
+    | PropertyId::FontSynthesis
     | PropertyId::LineHeight
     | PropertyId::Font => true,
     _ => false,
@@ -0,0 +1,175 @@
+//! CSS properties related to replaced elements and images.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss, Zero};
+use crate::values::angle::Angle;
+use crate::values::resolution::Resolution;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [object-fit](https://drafts.csswg.org/css-images-4/#the-object-fit) property.
+  pub enum ObjectFit {
+    /// The content is sized to fill the box, ignoring its aspect ratio.
+    "fill": Fill,
+    /// The content is scaled to fit within the box, preserving its aspect ratio.
+    "contain": Contain,
+    /// The content is scaled to cover the box, preserving its aspect ratio.
+    "cover": Cover,
+    /// The content is not resized.
+    "none": None,
+    /// The content is sized as if `none` or `contain` were specified, whichever is smaller.
+    "scale-down": ScaleDown,
+  }
+}
+
+/// A value for the [image-orientation](https://drafts.csswg.org/css-images-3/#the-image-orientation) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ImageOrientation {
+  /// The EXIF orientation of the image is applied.
+  FromImage,
+  /// No additional rotation is applied.
+  None,
+  /// The image is rotated by the given angle, and optionally flipped horizontally.
+  Angle {
+    /// The angle to rotate the image by.
+    angle: Angle,
+    /// Whether the image is flipped horizontally after rotation.
+    flip: bool,
+  },
+}
+
+impl<'i> Parse<'i> for ImageOrientation {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("from-image")).is_ok() {
+      return Ok(ImageOrientation::FromImage);
+    }
+
+    if input.try_parse(|i| i.expect_ident_matching("none")).is_ok() {
+      return Ok(ImageOrientation::None);
+    }
+
+    let mut angle = input.try_parse(Angle::parse).ok();
+    let flip = input.try_parse(|i| i.expect_ident_matching("flip")).is_ok();
+    if angle.is_none() {
+      if !flip {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      angle = input.try_parse(Angle::parse).ok();
+    }
+
+    Ok(ImageOrientation::Angle {
+      angle: angle.unwrap_or(Angle::Deg(0.0)),
+      flip,
+    })
+  }
+}
+
+impl ToCss for ImageOrientation {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ImageOrientation::FromImage => dest.write_str("from-image"),
+      ImageOrientation::None => dest.write_str("none"),
+      ImageOrientation::Angle { angle, flip } => {
+        // A missing angle defaults to 0deg.
+        if !*flip || !angle.is_zero() {
+          angle.to_css(dest)?;
+          if *flip {
+            dest.write_char(' ')?;
+          }
+        }
+
+        if *flip {
+          dest.write_str("flip")?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [image-resolution](https://drafts.csswg.org/css-images-4/#the-image-resolution) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageResolution {
+  /// Whether the intrinsic resolution of the image is used, if any.
+  pub from_image: bool,
+  /// An explicit resolution, used when `from_image` is false or the image has no intrinsic resolution.
+  pub resolution: Option<Resolution>,
+  /// Whether the resolution is rounded to the nearest value that maps one image pixel
+  /// to an integer number of device pixels.
+  pub snap: bool,
+}
+
+impl<'i> Parse<'i> for ImageResolution {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut from_image = false;
+    let mut resolution = None;
+    let mut snap = false;
+
+    loop {
+      if !from_image && input.try_parse(|i| i.expect_ident_matching("from-image")).is_ok() {
+        from_image = true;
+        continue;
+      }
+
+      if resolution.is_none() {
+        if let Ok(value) = input.try_parse(Resolution::parse) {
+          resolution = Some(value);
+          continue;
+        }
+      }
+
+      if !snap && input.try_parse(|i| i.expect_ident_matching("snap")).is_ok() {
+        snap = true;
+        continue;
+      }
+
+      break;
+    }
+
+    if !from_image && resolution.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ImageResolution {
+      from_image,
+      resolution,
+      snap,
+    })
+  }
+}
+
+impl ToCss for ImageResolution {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.from_image {
+      dest.write_str("from-image")?;
+    }
+
+    if let Some(resolution) = &self.resolution {
+      if self.from_image {
+        dest.write_char(' ')?;
+      }
+      resolution.to_css(dest)?;
+    }
+
+    if self.snap {
+      dest.write_str(" snap")?;
+    }
+
+    Ok(())
+  }
+}
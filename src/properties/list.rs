@@ -8,9 +8,11 @@ use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthan
 use crate::printer::Printer;
 use crate::targets::Browsers;
 use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
 use crate::values::string::CowArcStr;
 use crate::values::{ident::CustomIdent, image::Image};
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
 #[derive(Debug, Clone, PartialEq)]
@@ -297,6 +299,133 @@ enum_property! {
   }
 }
 
+/// A single counter as used in the [counter-reset](https://www.w3.org/TR/css-lists-3/#counter-reset),
+/// [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment), and
+/// [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Counter<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// Whether the counter is a reversed counter. Only valid in `counter-reset`.
+  pub reversed: bool,
+  /// The value to reset, increment, or set the counter by.
+  pub value: Option<CSSInteger>,
+}
+
+/// A generic value for the counter properties.
+///
+/// The `DEFAULT` parameter is the value that is used when a counter is specified
+/// without an integer, and `REVERSED` indicates whether `reversed()` counters are allowed.
+/// See [CounterReset](CounterReset), [CounterIncrement](CounterIncrement), and [CounterSet](CounterSet).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum CounterList<'i, const DEFAULT: CSSInteger, const REVERSED: bool> {
+  /// No counters are affected.
+  None,
+  /// A list of counters.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Counters(SmallVec<[Counter<'i>; 1]>),
+}
+
+/// A value for the [counter-reset](https://www.w3.org/TR/css-lists-3/#counter-reset) property.
+pub type CounterReset<'i> = CounterList<'i, 0, true>;
+/// A value for the [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment) property.
+pub type CounterIncrement<'i> = CounterList<'i, 1, false>;
+/// A value for the [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set) property.
+pub type CounterSet<'i> = CounterList<'i, 0, false>;
+
+impl<'i, const DEFAULT: CSSInteger, const REVERSED: bool> Parse<'i> for CounterList<'i, DEFAULT, REVERSED> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(CounterList::None);
+    }
+
+    let mut counters = SmallVec::new();
+    loop {
+      let reversed = REVERSED && input.try_parse(|input| input.expect_function_matching("reversed")).is_ok();
+      let name = if reversed {
+        input.parse_nested_block(CustomIdent::parse)?
+      } else if counters.is_empty() {
+        CustomIdent::parse(input)?
+      } else if let Ok(name) = input.try_parse(CustomIdent::parse) {
+        name
+      } else {
+        break;
+      };
+
+      if name.0.eq_ignore_ascii_case("none") {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+
+      let value = input.try_parse(CSSInteger::parse).ok();
+      counters.push(Counter { name, reversed, value });
+    }
+
+    Ok(CounterList::Counters(counters))
+  }
+}
+
+impl<'i, const DEFAULT: CSSInteger, const REVERSED: bool> ToCss for CounterList<'i, DEFAULT, REVERSED> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterList::None => dest.write_str("none"),
+      CounterList::Counters(counters) => {
+        let mut first = true;
+        for counter in counters {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+
+          if counter.reversed {
+            dest.write_str("reversed(")?;
+            write_counter_name(&counter.name, dest)?;
+            dest.write_char(')')?;
+          } else {
+            write_counter_name(&counter.name, dest)?;
+          }
+
+          // Reversed counters default to the number of list items rather than a fixed value.
+          if let Some(value) = counter.value {
+            if counter.reversed || value != DEFAULT {
+              dest.write_char(' ')?;
+              value.to_css(dest)?;
+            }
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// Writes a counter name, scoping it when CSS modules are enabled.
+/// The built-in `list-item` counter is always written as is.
+pub(crate) fn write_counter_name<W>(name: &CustomIdent, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if name.0.as_ref() == "list-item" {
+    serialize_identifier(&name.0, dest)?;
+    return Ok(());
+  }
+
+  if let Some(css_module) = &mut dest.css_module {
+    css_module.reference(&name.0)
+  }
+  name.to_css(dest)
+}
+
 shorthand_property! {
   /// A value for the [list-style](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-property) shorthand property.
   pub struct ListStyle<'i> {
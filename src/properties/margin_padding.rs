@@ -2,11 +2,11 @@ use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::logical::PropertyCategory;
+use crate::logical::{LogicalSide, PhysicalSide, PropertyCategory};
 use crate::macros::{define_shorthand, rect_shorthand, size_shorthand};
 use crate::printer::Printer;
-use crate::properties::{Property, PropertyId};
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::properties::{writing_mode::Direction, Property, PropertyId};
+use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::{length::LengthPercentageOrAuto, rect::Rect, size::Size2D};
 use cssparser::*;
 
@@ -271,29 +271,51 @@ macro_rules! side_handler {
         let right = std::mem::take(&mut self.right);
         let logical_supported = true $(&& context.is_supported(Feature::$feature))?;
 
+        // Static fallbacks for math functions that are unsupported by the targets.
+        macro_rules! fallback {
+          ($val: expr) => {
+            match context.targets {
+              Some(targets) => $val.clone().get_fallbacks(targets).pop(),
+              None => None
+            }
+          };
+        }
+
         if (!$logical_shorthand || logical_supported) && top.is_some() && bottom.is_some() && left.is_some() && right.is_some() {
+          let (top, right, bottom, left) = (top.unwrap(), right.unwrap(), bottom.unwrap(), left.unwrap());
+          let fallbacks = (fallback!(top), fallback!(right), fallback!(bottom), fallback!(left));
+          if let (None, None, None, None) = fallbacks {
+          } else {
+            dest.push(Property::$shorthand($shorthand {
+              top: fallbacks.0.unwrap_or_else(|| top.clone()),
+              right: fallbacks.1.unwrap_or_else(|| right.clone()),
+              bottom: fallbacks.2.unwrap_or_else(|| bottom.clone()),
+              left: fallbacks.3.unwrap_or_else(|| left.clone())
+            }));
+          }
+
           dest.push(Property::$shorthand($shorthand {
-            top: top.unwrap(),
-            right: right.unwrap(),
-            bottom: bottom.unwrap(),
-            left: left.unwrap()
+            top,
+            right,
+            bottom,
+            left
           }));
         } else {
-          if let Some(val) = top {
-            dest.push(Property::$top(val));
-          }
-
-          if let Some(val) = bottom {
-            dest.push(Property::$bottom(val));
-          }
-
-          if let Some(val) = left {
-            dest.push(Property::$left(val));
+          macro_rules! side {
+            ($val: ident, $prop: ident) => {
+              if let Some(val) = $val {
+                if let Some(fallback) = fallback!(val) {
+                  dest.push(Property::$prop(fallback));
+                }
+                dest.push(Property::$prop(val));
+              }
+            };
           }
 
-          if let Some(val) = right {
-            dest.push(Property::$right(val));
-          }
+          side!(top, $top);
+          side!(bottom, $bottom);
+          side!(left, $left);
+          side!(right, $right);
         }
 
         let block_start = std::mem::take(&mut self.block_start);
@@ -321,14 +343,38 @@ macro_rules! side_handler {
           };
         }
 
+        macro_rules! physical {
+          ($val: expr, $side: expr) => {
+            match $side {
+              PhysicalSide::Top => Property::$top($val.clone()),
+              PhysicalSide::Right => Property::$right($val.clone()),
+              PhysicalSide::Bottom => Property::$bottom($val.clone()),
+              PhysicalSide::Left => Property::$left($val.clone()),
+            }
+          }
+        }
+
+        macro_rules! physical_id {
+          ($side: expr) => {
+            match $side {
+              PhysicalSide::Top => PropertyId::$top,
+              PhysicalSide::Right => PropertyId::$right,
+              PhysicalSide::Bottom => PropertyId::$bottom,
+              PhysicalSide::Left => PropertyId::$left,
+            }
+          }
+        }
+
         macro_rules! prop {
-          ($val: ident, $logical: ident, $physical: ident) => {
-            match $val {
+          ($val: ident, $logical: ident, $side: ident) => {
+            match &$val {
               Some(Property::$logical(val)) => {
-                dest.push(Property::$physical(val));
+                context.add_physical_property(dest, LogicalSide::$side, |side| physical!(val, side));
               }
               Some(Property::Unparsed(val)) => {
-                dest.push(Property::Unparsed(val.with_property_id(PropertyId::$physical)));
+                context.add_physical_property(dest, LogicalSide::$side, |side| {
+                  Property::Unparsed(val.with_property_id(physical_id!(side)))
+                });
               }
               _ => {}
             }
@@ -338,39 +384,25 @@ macro_rules! side_handler {
         if logical_supported {
           logical_side!(block_start, block_end, $block_shorthand, $block_start, $block_end);
         } else {
-          prop!(block_start, $block_start, $top);
-          prop!(block_end, $block_end, $bottom);
+          prop!(block_start, $block_start, BlockStart);
+          prop!(block_end, $block_end, BlockEnd);
         }
 
         if logical_supported {
           logical_side!(inline_start, inline_end, $inline_shorthand, $inline_start, $inline_end);
-        } else if inline_start.is_some() || inline_end.is_some() {
-          if matches!((&inline_start, &inline_end), (Some(Property::$inline_start(start)), Some(Property::$inline_end(end))) if start == end) {
-            prop!(inline_start, $inline_start, $left);
-            prop!(inline_end, $inline_end, $right);
-          } else {
-            macro_rules! logical_prop {
-              ($val: ident, $logical: ident, $ltr: ident, $rtl: ident) => {
-                match $val {
-                  Some(Property::$logical(val)) => {
-                    context.add_logical_rule(
-                      Property::$ltr(val.clone()),
-                      Property::$rtl(val)
-                    );
-                  }
-                  Some(Property::Unparsed(val)) => {
-                    context.add_logical_rule(
-                      Property::Unparsed(val.with_property_id(PropertyId::$ltr)),
-                      Property::Unparsed(val.with_property_id(PropertyId::$rtl))
-                    );
-                  }
-                  _ => {}
-                }
-              }
+        } else {
+          match (&inline_start, &inline_end) {
+            // Both sides have the same value, so the direction doesn't matter.
+            (Some(Property::$inline_start(start)), Some(Property::$inline_end(end))) if start == end => {
+              let start_side = context.physical_side(LogicalSide::InlineStart, Direction::Ltr);
+              let end_side = context.physical_side(LogicalSide::InlineEnd, Direction::Ltr);
+              dest.push(physical!(start, start_side));
+              dest.push(physical!(end, end_side));
+            }
+            _ => {
+              prop!(inline_start, $inline_start, InlineStart);
+              prop!(inline_end, $inline_end, InlineEnd);
             }
-
-            logical_prop!(inline_start, $inline_start, $left, $right);
-            logical_prop!(inline_end, $inline_end, $right, $left);
           }
         }
       }
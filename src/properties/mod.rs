@@ -97,7 +97,9 @@ pub mod border;
 pub mod border_image;
 pub mod border_radius;
 pub mod box_shadow;
+pub mod columns;
 pub mod contain;
+pub mod content;
 pub mod css_modules;
 pub mod custom;
 pub mod display;
@@ -106,6 +108,7 @@ pub mod flex;
 pub mod font;
 #[cfg(feature = "grid")]
 pub mod grid;
+pub mod image;
 pub mod list;
 pub(crate) mod margin_padding;
 pub mod masking;
@@ -113,12 +116,15 @@ pub mod outline;
 pub mod overflow;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod scroll;
 pub mod size;
 pub mod svg;
 pub mod text;
 pub mod transform;
 pub mod transition;
 pub mod ui;
+pub mod view_transition;
+pub mod writing_mode;
 
 use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
@@ -143,7 +149,9 @@ use border::*;
 use border_image::*;
 use border_radius::*;
 use box_shadow::*;
+use columns::*;
 use contain::*;
+use content::*;
 use css_modules::*;
 use cssparser::*;
 use custom::*;
@@ -153,11 +161,13 @@ use flex::*;
 use font::*;
 #[cfg(feature = "grid")]
 use grid::*;
+use image::*;
 use list::*;
 use margin_padding::*;
 use masking::*;
 use outline::*;
 use overflow::*;
+use scroll::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 use svg::*;
@@ -165,6 +175,8 @@ use text::*;
 use transform::*;
 use transition::*;
 use ui::*;
+use view_transition::*;
+use writing_mode::*;
 
 macro_rules! define_properties {
   (
@@ -320,7 +332,7 @@ macro_rules! define_properties {
     }
 
     impl<'i> PropertyId<'i> {
-      fn prefix(&self) -> VendorPrefix {
+      pub(crate) fn prefix(&self) -> VendorPrefix {
         use PropertyId::*;
         match self {
           $(
@@ -546,6 +558,15 @@ macro_rules! define_properties {
       pub fn parse<'t>(property_id: PropertyId<'i>, input: &mut Parser<'i, 't>, options: &ParserOptions) -> Result<Property<'i>, ParseError<'i, ParserError<'i>>> {
         let state = input.state();
 
+        // The legacy strictness-only syntax of scroll-snap-type is only valid for the prefixed properties.
+        if property_id == PropertyId::ScrollSnapType(VendorPrefix::None) {
+          let is_legacy = matches!(ScrollSnapType::parse(input), Ok(ScrollSnapType::Legacy { .. }));
+          input.reset(&state);
+          if is_legacy {
+            return Ok(Property::Unparsed(UnparsedProperty::parse(property_id, input, options)?))
+          }
+        }
+
         match property_id {
           $(
             $(#[$meta])*
@@ -583,6 +604,31 @@ macro_rules! define_properties {
         }
       }
 
+      /// Returns a mutable reference to the vendor prefix of this property, if it can be prefixed.
+      pub(crate) fn vendor_prefix_mut(&mut self) -> Option<&mut VendorPrefix> {
+        use Property::*;
+
+        match self {
+          $(
+            $(#[$meta])*
+            $property(_, $(vp_name!($vp, p))?) => {
+              $(
+                macro_rules! return_prefix {
+                  ($v: ty) => {
+                    return Some(p);
+                  };
+                }
+
+                return_prefix!($vp);
+              )?
+              #[allow(unreachable_code)]
+              None
+            },
+          )+
+          _ => None
+        }
+      }
+
       /// Parses a CSS property from a string.
       pub fn parse_string(property_id: PropertyId<'i>, input: &'i str, options: ParserOptions) -> Result<Self, ParseError<'i, ParserError<'i>>> {
         let mut input = ParserInput::new(input);
@@ -785,10 +831,17 @@ define_properties! {
   "max-block-size": MaxBlockSize(MaxSize) [logical_group: MaxSize, category: Logical],
   "max-inline-size": MaxInlineSize(MaxSize) [logical_group: MaxSize, category: Logical],
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
+  "aspect-ratio": AspectRatio(AspectRatio),
 
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
+  "scroll-behavior": ScrollBehavior(ScrollBehavior),
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword),
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  "overscroll-behavior-inline": OverscrollBehaviorInline(OverscrollBehaviorKeyword),
+  "overscroll-behavior-block": OverscrollBehaviorBlock(OverscrollBehaviorKeyword),
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
   "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
@@ -902,6 +955,17 @@ define_properties! {
   "column-gap": ColumnGap(GapValue),
   "gap": Gap(Gap) shorthand: true,
 
+  // https://www.w3.org/TR/css-multicol-1/
+  "column-width": ColumnWidth(ColumnWidth, VendorPrefix) / WebKit / Moz,
+  "column-count": ColumnCount(ColumnCount, VendorPrefix) / WebKit / Moz,
+  "columns": Columns(Columns, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-rule-width": ColumnRuleWidth(BorderSideWidth, VendorPrefix) / WebKit / Moz,
+  "column-rule-style": ColumnRuleStyle(LineStyle, VendorPrefix) / WebKit / Moz,
+  "column-rule-color": ColumnRuleColor(CssColor, VendorPrefix) / WebKit / Moz,
+  "column-rule": ColumnRule(ColumnRule, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-span": ColumnSpan(ColumnSpan, VendorPrefix) / WebKit / Moz,
+  "column-fill": ColumnFill(ColumnFill, VendorPrefix) / WebKit / Moz,
+
   // Old flex (2009): https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/
   "box-orient": BoxOrient(BoxOrient, VendorPrefix) / WebKit / Moz unprefixed: false,
   "box-direction": BoxDirection(BoxDirection, VendorPrefix) / WebKit / Moz unprefixed: false,
@@ -1003,12 +1067,28 @@ define_properties! {
   "scroll-padding-inline": ScrollPaddingInline(ScrollPaddingInline) shorthand: true,
   "scroll-padding": ScrollPadding(ScrollPadding) shorthand: true,
 
+  "scroll-snap-type": ScrollSnapType(ScrollSnapType, VendorPrefix) / WebKit / Ms,
+  "scroll-snap-align": ScrollSnapAlign(ScrollSnapAlign),
+  "scroll-snap-stop": ScrollSnapStop(ScrollSnapStop),
+
   "font-weight": FontWeight(FontWeight),
   "font-size": FontSize(FontSize),
   "font-stretch": FontStretch(FontStretch),
   "font-family": FontFamily(Vec<FontFamily<'i>>),
   "font-style": FontStyle(FontStyle),
   "font-variant-caps": FontVariantCaps(FontVariantCaps),
+  "font-variant-ligatures": FontVariantLigatures(FontVariantLigatures),
+  "font-variant-alternates": FontVariantAlternates(FontVariantAlternates<'i>),
+  "font-variant-numeric": FontVariantNumeric(FontVariantNumeric),
+  "font-variant-east-asian": FontVariantEastAsian(FontVariantEastAsian),
+  "font-variant-position": FontVariantPosition(FontVariantPosition),
+  "font-variant": FontVariant(FontVariant<'i>) shorthand: true,
+  "font-feature-settings": FontFeatureSettings(FontFeatureSettings<'i>),
+  "font-variation-settings": FontVariationSettings(FontVariationSettings<'i>),
+  "font-kerning": FontKerning(FontKerning),
+  "font-optical-sizing": FontOpticalSizing(FontOpticalSizing),
+  "font-synthesis": FontSynthesis(FontSynthesis),
+  "font-size-adjust": FontSizeAdjust(FontSizeAdjust),
   "line-height": LineHeight(LineHeight),
   "font": Font(Font<'i>) shorthand: true,
   "vertical-align": VerticalAlign(VerticalAlign),
@@ -1029,6 +1109,20 @@ define_properties! {
   "animation-delay": AnimationDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation-fill-mode": AnimationFillMode(SmallVec<[AnimationFillMode; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation": Animation(AnimationList<'i>, VendorPrefix) / WebKit / Moz / O shorthand: true,
+  "animation-timeline": AnimationTimeline(AnimationTimelineList<'i>),
+
+  // https://drafts.csswg.org/scroll-animations-1/
+  "animation-range-start": AnimationRangeStart(SmallVec<[AnimationRangeStart; 1]>),
+  "animation-range-end": AnimationRangeEnd(SmallVec<[AnimationRangeEnd; 1]>),
+  "animation-range": AnimationRange(SmallVec<[AnimationRange; 1]>) shorthand: true,
+  "scroll-timeline-name": ScrollTimelineName(TimelineNameList<'i>),
+  "scroll-timeline-axis": ScrollTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "scroll-timeline": ScrollTimeline(SmallVec<[ScrollTimeline<'i>; 1]>) shorthand: true,
+  "view-timeline-name": ViewTimelineName(TimelineNameList<'i>),
+  "view-timeline-axis": ViewTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "view-timeline-inset": ViewTimelineInset(SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>),
+  "view-timeline": ViewTimeline(SmallVec<[ViewTimeline<'i>; 1]>) shorthand: true,
+  "timeline-scope": TimelineScope(TimelineScope<'i>),
 
   // https://drafts.csswg.org/css-transforms-2/
   "transform": Transform(TransformList, VendorPrefix) / WebKit / Moz / Ms / O,
@@ -1084,12 +1178,21 @@ define_properties! {
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
 
+  // https://drafts.csswg.org/css-color-adjust/
+  "color-scheme": ColorScheme(ColorScheme),
+
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
   "list-style-image": ListStyleImage(Image<'i>),
   "list-style-position": ListStylePosition(ListStylePosition),
   "list-style": ListStyle(ListStyle<'i>) shorthand: true,
   "marker-side": MarkerSide(MarkerSide),
+  "counter-reset": CounterReset(CounterReset<'i>),
+  "counter-increment": CounterIncrement(CounterIncrement<'i>),
+  "counter-set": CounterSet(CounterSet<'i>),
+
+  "content": Content(Content<'i>),
+  "quotes": Quotes(Quotes<'i>),
 
   // CSS modules
   "composes": Composes(Composes<'i>) if css_modules,
@@ -1154,6 +1257,18 @@ define_properties! {
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
 
+  // https://drafts.csswg.org/css-images-4/
+  "object-fit": ObjectFit(ObjectFit, VendorPrefix) / O,
+  "object-position": ObjectPosition(Position, VendorPrefix) / O,
+  "image-orientation": ImageOrientation(ImageOrientation),
+  "image-resolution": ImageResolution(ImageResolution),
+
+  // https://drafts.csswg.org/css-writing-modes-4/
+  "writing-mode": WritingMode(WritingMode),
+  "direction": Direction(Direction),
+  "unicode-bidi": UnicodeBidi(UnicodeBidi),
+  "text-orientation": TextOrientation(TextOrientation),
+
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
@@ -1161,6 +1276,10 @@ define_properties! {
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
+
+  // https://drafts.csswg.org/css-view-transitions-2/
+  "view-transition-name": ViewTransitionName(ViewTransitionName<'i>),
+  "view-transition-class": ViewTransitionClass(NoneOrCustomIdentList<'i>),
 }
 
 impl<'i, T: smallvec::Array<Item = V>, V: Parse<'i>> Parse<'i> for SmallVec<T> {
@@ -7,7 +7,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
 use crate::values::number::CSSInteger;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
@@ -82,6 +82,18 @@ pub enum ZIndex {
   Integer(CSSInteger),
 }
 
+impl IsCompatible for Position {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      Position::Sticky(VendorPrefix::None) => targets.map_or(false, |targets| {
+        Feature::Sticky.prefixes_for(targets) == VendorPrefix::None
+      }),
+      Position::Sticky(..) => false,
+      _ => true,
+    }
+  }
+}
+
 impl<'i> Parse<'i> for ZIndex {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(value) = input.expect_integer() {
@@ -97,6 +97,10 @@ define_prefixes! {
   Appearance,
   ClipPath,
   BoxDecorationBreak,
+  ColumnSpan,
+  ColumnFill,
+  ObjectFit,
+  ObjectPosition,
 }
 
 macro_rules! define_fallbacks {
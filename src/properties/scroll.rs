@@ -0,0 +1,342 @@
+//! CSS properties related to scrolling.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property};
+use crate::prefixes::Feature;
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::vendor_prefix::VendorPrefix;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [scroll-behavior](https://www.w3.org/TR/css-overflow-3/#smooth-scrolling) property.
+  pub enum ScrollBehavior {
+    /// Scrolling happens instantly.
+    Auto,
+    /// Scrolling happens in a smooth fashion.
+    Smooth,
+  }
+}
+
+enum_property! {
+  /// An [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) keyword
+  /// as used in the `overscroll-behavior` property and its longhands.
+  pub enum OverscrollBehaviorKeyword {
+    /// Scroll chaining and overscroll affordances are not affected.
+    Auto,
+    /// Scroll chaining to neighboring scroll containers is prevented.
+    Contain,
+    /// Scroll chaining and overscroll affordances are prevented.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior in the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior in the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// The axis of a [scroll-snap-type](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-type) value.
+  pub enum ScrollSnapAxis {
+    /// The horizontal axis.
+    X,
+    /// The vertical axis.
+    Y,
+    /// The block axis.
+    Block,
+    /// The inline axis.
+    Inline,
+    /// Both axes.
+    Both,
+  }
+}
+
+enum_property! {
+  /// The strictness of a [scroll-snap-type](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-type) value.
+  pub enum ScrollSnapStrictness {
+    /// The scroll container must rest at a snap position.
+    Mandatory,
+    /// The scroll container may rest at a snap position if it is close by.
+    Proximity,
+  }
+}
+
+impl Default for ScrollSnapStrictness {
+  fn default() -> ScrollSnapStrictness {
+    ScrollSnapStrictness::Proximity
+  }
+}
+
+/// A value for the [scroll-snap-type](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-type) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ScrollSnapType {
+  /// The scroll container does not snap.
+  None,
+  /// The scroll container snaps in the given axis.
+  Snap {
+    /// The axis to snap in.
+    axis: ScrollSnapAxis,
+    /// How strictly snap positions are enforced.
+    strictness: ScrollSnapStrictness,
+  },
+  /// The legacy syntax supported by the `-ms-` and `-webkit-` prefixed property,
+  /// which only specifies the strictness. It is not accepted by the unprefixed property.
+  Legacy {
+    /// How strictly snap positions are enforced.
+    strictness: ScrollSnapStrictness,
+  },
+}
+
+impl<'i> Parse<'i> for ScrollSnapType {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("none")).is_ok() {
+      return Ok(ScrollSnapType::None);
+    }
+
+    if let Ok(strictness) = input.try_parse(ScrollSnapStrictness::parse) {
+      return Ok(ScrollSnapType::Legacy { strictness });
+    }
+
+    let axis = ScrollSnapAxis::parse(input)?;
+    let strictness = input.try_parse(ScrollSnapStrictness::parse).unwrap_or_default();
+    Ok(ScrollSnapType::Snap { axis, strictness })
+  }
+}
+
+impl ToCss for ScrollSnapType {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollSnapType::None => dest.write_str("none"),
+      ScrollSnapType::Snap { axis, strictness } => {
+        axis.to_css(dest)?;
+        if *strictness != ScrollSnapStrictness::default() {
+          dest.write_char(' ')?;
+          strictness.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ScrollSnapType::Legacy { strictness } => strictness.to_css(dest),
+    }
+  }
+}
+
+impl ScrollSnapType {
+  /// Returns the equivalent value in the legacy prefixed syntax.
+  fn to_legacy(&self) -> ScrollSnapType {
+    match self {
+      ScrollSnapType::Snap { strictness, .. } => ScrollSnapType::Legacy {
+        strictness: *strictness,
+      },
+      _ => self.clone(),
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword for the [scroll-snap-align](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-align) property.
+  pub enum ScrollSnapAlignKeyword {
+    /// The box does not define a snap position in this axis.
+    None,
+    /// The start edge of the box is aligned with the start edge of the snapport.
+    Start,
+    /// The end edge of the box is aligned with the end edge of the snapport.
+    End,
+    /// The center of the box is aligned with the center of the snapport.
+    Center,
+  }
+}
+
+/// A value for the [scroll-snap-align](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-align) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollSnapAlign {
+  /// The snap alignment in the block axis.
+  pub block: ScrollSnapAlignKeyword,
+  /// The snap alignment in the inline axis.
+  pub inline: ScrollSnapAlignKeyword,
+}
+
+impl<'i> Parse<'i> for ScrollSnapAlign {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let block = ScrollSnapAlignKeyword::parse(input)?;
+    let inline = input.try_parse(ScrollSnapAlignKeyword::parse).unwrap_or(block);
+    Ok(ScrollSnapAlign { block, inline })
+  }
+}
+
+impl ToCss for ScrollSnapAlign {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.block.to_css(dest)?;
+    if self.inline != self.block {
+      dest.write_char(' ')?;
+      self.inline.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [scroll-snap-stop](https://www.w3.org/TR/css-scroll-snap-1/#scroll-snap-stop) property.
+  pub enum ScrollSnapStop {
+    /// The scroll container may pass over a snap position.
+    Normal,
+    /// The scroll container must not pass over a snap position.
+    Always,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ScrollHandler {
+  targets: Option<Browsers>,
+  overscroll_x: Option<OverscrollBehaviorKeyword>,
+  overscroll_y: Option<OverscrollBehaviorKeyword>,
+  snap_type: Option<ScrollSnapType>,
+  prefixed_snap_type: Option<(ScrollSnapType, VendorPrefix)>,
+}
+
+impl ScrollHandler {
+  pub fn new(targets: Option<Browsers>) -> ScrollHandler {
+    ScrollHandler {
+      targets,
+      ..ScrollHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ScrollHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      OverscrollBehaviorX(val) => self.overscroll_x = Some(*val),
+      OverscrollBehaviorY(val) => self.overscroll_y = Some(*val),
+      OverscrollBehavior(val) => {
+        self.overscroll_x = Some(val.x);
+        self.overscroll_y = Some(val.y);
+      }
+      OverscrollBehaviorInline(..) | OverscrollBehaviorBlock(..) => {
+        // Logical longhands may override the physical ones depending on the writing mode,
+        // so flush to preserve their order.
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      ScrollSnapType(val, vp) => {
+        if *vp == VendorPrefix::None {
+          self.snap_type = Some(val.clone());
+        } else if let Some((cur, prefixes)) = &mut self.prefixed_snap_type {
+          if cur == val {
+            *prefixes |= *vp;
+          } else {
+            self.prefixed_snap_type = Some((val.clone(), *vp));
+          }
+        } else {
+          self.prefixed_snap_type = Some((val.clone(), *vp));
+        }
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehaviorX
+            | PropertyId::OverscrollBehaviorY
+            | PropertyId::OverscrollBehavior
+            | PropertyId::OverscrollBehaviorInline
+            | PropertyId::OverscrollBehaviorBlock
+            | PropertyId::ScrollSnapType(_)
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    let x = std::mem::take(&mut self.overscroll_x);
+    let y = std::mem::take(&mut self.overscroll_y);
+
+    match (x, y) {
+      (Some(x), Some(y)) => dest.push(Property::OverscrollBehavior(OverscrollBehavior { x, y })),
+      _ => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x))
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y))
+        }
+      }
+    }
+
+    let snap_type = std::mem::take(&mut self.snap_type);
+    let mut prefixed_snap_type = std::mem::take(&mut self.prefixed_snap_type);
+    if let (Some(snap_type), Some(targets)) = (&snap_type, self.targets) {
+      // Prefixed versions only support the legacy syntax, which applies to both axes.
+      let mut prefixes = Feature::ScrollSnapType.prefixes_for(targets);
+      prefixes.remove(VendorPrefix::None);
+      prefixed_snap_type = if prefixes.is_empty() {
+        None
+      } else {
+        Some((snap_type.to_legacy(), prefixes))
+      };
+    }
+
+    if let Some((val, prefixes)) = prefixed_snap_type {
+      dest.push(Property::ScrollSnapType(val, prefixes));
+    }
+
+    if let Some(val) = snap_type {
+      dest.push(Property::ScrollSnapType(val, VendorPrefix::None));
+    }
+  }
+}
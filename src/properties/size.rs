@@ -7,8 +7,10 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
-use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::targets::Browsers;
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, ToCss};
 use crate::values::length::LengthPercentage;
+use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
@@ -115,6 +117,46 @@ impl ToCss for Size {
   }
 }
 
+impl FallbackValues for Size {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      Size::LengthPercentage(l) => l.get_fallbacks(targets).into_iter().map(Size::LengthPercentage).collect(),
+      Size::FitContentFunction(l) => l.get_fallbacks(targets).into_iter().map(Size::FitContentFunction).collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
+macro_rules! impl_is_compatible {
+  ($size: ident, $keyword: ident) => {
+    impl IsCompatible for $size {
+      fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+        macro_rules! is_unprefixed {
+          ($prefix: ident, $feature: ident) => {
+            *$prefix == VendorPrefix::None
+              && targets.map_or(false, |targets| {
+                crate::prefixes::Feature::$feature.prefixes_for(targets) == VendorPrefix::None
+              })
+          };
+        }
+
+        match self {
+          $size::$keyword => true,
+          $size::LengthPercentage(l) | $size::FitContentFunction(l) => l.is_compatible(targets),
+          $size::MinContent(prefix) => is_unprefixed!(prefix, MinContent),
+          $size::MaxContent(prefix) => is_unprefixed!(prefix, MaxContent),
+          $size::FitContent(prefix) => is_unprefixed!(prefix, FitContent),
+          $size::Stretch(prefix) => is_unprefixed!(prefix, Stretch),
+          // No compatibility data.
+          $size::Contain => false,
+        }
+      }
+    }
+  };
+}
+
+impl_is_compatible!(Size, Auto);
+
 /// A value for the [minimum](https://drafts.csswg.org/css-sizing-3/#min-size-properties)
 /// and [maximum](https://drafts.csswg.org/css-sizing-3/#max-size-properties) size properties,
 /// e.g. `min-width` and `max-height`.
@@ -216,6 +258,22 @@ impl ToCss for MaxSize {
   }
 }
 
+impl_is_compatible!(MaxSize, None);
+
+impl FallbackValues for MaxSize {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      MaxSize::LengthPercentage(l) => {
+        l.get_fallbacks(targets).into_iter().map(MaxSize::LengthPercentage).collect()
+      }
+      MaxSize::FitContentFunction(l) => {
+        l.get_fallbacks(targets).into_iter().map(MaxSize::FitContentFunction).collect()
+      }
+      _ => Vec::new(),
+    }
+  }
+}
+
 fn parse_fit_content<'i, 't>(
   input: &mut Parser<'i, 't>,
 ) -> Result<LengthPercentage, ParseError<'i, ParserError<'i>>> {
@@ -233,6 +291,57 @@ enum_property! {
   }
 }
 
+/// A value for the [aspect-ratio](https://drafts.csswg.org/css-sizing-4/#aspect-ratio) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspectRatio {
+  /// The `auto` keyword.
+  pub auto: bool,
+  /// A preferred aspect ratio for the box.
+  pub ratio: Option<Ratio>,
+}
+
+impl<'i> Parse<'i> for AspectRatio {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut auto = input.try_parse(|i| i.expect_ident_matching("auto")).is_ok();
+    let ratio = input.try_parse(Ratio::parse).ok();
+    if !auto {
+      auto = input.try_parse(|i| i.expect_ident_matching("auto")).is_ok();
+    }
+
+    match &ratio {
+      None if !auto => return Err(location.new_custom_error(ParserError::InvalidValue)),
+      Some(Ratio(w, h)) if *w < 0.0 || *h < 0.0 => {
+        return Err(location.new_custom_error(ParserError::InvalidValue))
+      }
+      _ => {}
+    }
+
+    Ok(AspectRatio { auto, ratio })
+  }
+}
+
+impl ToCss for AspectRatio {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto")?;
+    }
+
+    if let Some(ratio) = &self.ratio {
+      if self.auto {
+        dest.write_char(' ')?;
+      }
+      ratio.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct SizeHandler;
 
@@ -268,14 +377,21 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
           $size::FitContent(VendorPrefix::None) => prefix!($prop, $size, FitContent),
           _ => {}
         }
+        if let Some(targets) = context.targets {
+          for fallback in $val.clone().get_fallbacks(targets) {
+            dest.push(Property::$prop(fallback));
+          }
+        }
         dest.push(Property::$prop($val.clone()));
       }};
     }
 
     macro_rules! logical {
-      ($prop: ident, $val: ident, $physical: ident, $size: ident) => {
+      ($prop: ident, $val: ident, $physical: ident, $vertical: ident, $size: ident) => {
         if logical_supported {
           property!($prop, $val, $size);
+        } else if context.is_vertical() {
+          property!($vertical, $val, $size);
         } else {
           property!($physical, $val, $size);
         }
@@ -289,17 +405,29 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
       Property::MinHeight(v) => property!(MinHeight, v, Size),
       Property::MaxWidth(v) => property!(MaxWidth, v, MaxSize),
       Property::MaxHeight(v) => property!(MaxHeight, v, MaxSize),
-      Property::BlockSize(size) => logical!(BlockSize, size, Height, Size),
-      Property::MinBlockSize(size) => logical!(MinBlockSize, size, MinHeight, Size),
-      Property::MaxBlockSize(size) => logical!(MaxBlockSize, size, MaxHeight, MaxSize),
-      Property::InlineSize(size) => logical!(InlineSize, size, Width, Size),
-      Property::MinInlineSize(size) => logical!(MinInlineSize, size, MinWidth, Size),
-      Property::MaxInlineSize(size) => logical!(MaxInlineSize, size, MaxWidth, MaxSize),
+      Property::BlockSize(size) => logical!(BlockSize, size, Height, Width, Size),
+      Property::MinBlockSize(size) => logical!(MinBlockSize, size, MinHeight, MinWidth, Size),
+      Property::MaxBlockSize(size) => logical!(MaxBlockSize, size, MaxHeight, MaxWidth, MaxSize),
+      Property::InlineSize(size) => logical!(InlineSize, size, Width, Height, Size),
+      Property::MinInlineSize(size) => logical!(MinInlineSize, size, MinWidth, MinHeight, Size),
+      Property::MaxInlineSize(size) => logical!(MaxInlineSize, size, MaxWidth, MaxHeight, MaxSize),
+      Property::AspectRatio(val) => {
+        if let Some(ratio) = &val.ratio {
+          if !context.is_supported(Feature::AspectRatio) {
+            context.add_aspect_ratio_fallback(ratio);
+          }
+        }
+        dest.push(property.clone());
+      }
       Property::Unparsed(unparsed) => {
         macro_rules! logical_unparsed {
-          ($physical: ident) => {
+          ($physical: ident, $vertical: ident) => {
             if logical_supported {
               dest.push(property.clone());
+            } else if context.is_vertical() {
+              dest.push(Property::Unparsed(
+                unparsed.with_property_id(PropertyId::$vertical),
+              ));
             } else {
               dest.push(Property::Unparsed(
                 unparsed.with_property_id(PropertyId::$physical),
@@ -317,12 +445,12 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
           | PropertyId::MaxHeight => {
             dest.push(property.clone());
           }
-          PropertyId::BlockSize => logical_unparsed!(Height),
-          PropertyId::MinBlockSize => logical_unparsed!(MinHeight),
-          PropertyId::MaxBlockSize => logical_unparsed!(MaxHeight),
-          PropertyId::InlineSize => logical_unparsed!(Width),
-          PropertyId::MinInlineSize => logical_unparsed!(MinWidth),
-          PropertyId::MaxInlineSize => logical_unparsed!(MaxWidth),
+          PropertyId::BlockSize => logical_unparsed!(Height, Width),
+          PropertyId::MinBlockSize => logical_unparsed!(MinHeight, MinWidth),
+          PropertyId::MaxBlockSize => logical_unparsed!(MaxHeight, MaxWidth),
+          PropertyId::InlineSize => logical_unparsed!(Width, Height),
+          PropertyId::MinInlineSize => logical_unparsed!(MinWidth, MinHeight),
+          PropertyId::MaxInlineSize => logical_unparsed!(MaxWidth, MaxHeight),
           _ => return false,
         }
       }
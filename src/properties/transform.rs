@@ -62,6 +62,8 @@ impl ToCss for TransformList {
           &mut base,
           PrinterOptions {
             minify: true,
+            precision: dest.precision,
+            convert_units: dest.convert_units,
             ..PrinterOptions::default()
           },
         ))?;
@@ -74,6 +76,8 @@ impl ToCss for TransformList {
             &mut decomposed,
             PrinterOptions {
               minify: true,
+              precision: dest.precision,
+              convert_units: dest.convert_units,
               ..PrinterOptions::default()
             },
           ))?;
@@ -89,6 +93,8 @@ impl ToCss for TransformList {
             &mut mat,
             PrinterOptions {
               minify: true,
+              precision: dest.precision,
+              convert_units: dest.convert_units,
               ..PrinterOptions::default()
             },
           ))?
@@ -97,6 +103,8 @@ impl ToCss for TransformList {
             &mut mat,
             PrinterOptions {
               minify: true,
+              precision: dest.precision,
+              convert_units: dest.convert_units,
               ..PrinterOptions::default()
             },
           ))?
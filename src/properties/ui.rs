@@ -1,16 +1,20 @@
 //! CSS properties related to user interface.
 
-use crate::declaration::DeclarationBlock;
+use super::custom::{CustomProperty, Token, TokenList, TokenOrValue};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property, shorthand_property};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, Shorthand, ToCss};
+use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::color::CssColor;
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
+use bitflags::bitflags;
 use cssparser::*;
 use smallvec::SmallVec;
 
@@ -359,3 +363,137 @@ impl<'i> ToCss for Appearance<'i> {
     }
   }
 }
+
+bitflags! {
+  /// A value for the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+  ///
+  /// An empty value represents the `normal` keyword. Unknown color scheme
+  /// names are ignored, as they have no effect in browsers.
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+  pub struct ColorScheme: u8 {
+    /// Indicates that the element supports a light color scheme.
+    const Light = 0b01;
+    /// Indicates that the element supports a dark color scheme.
+    const Dark  = 0b10;
+    /// Forbids the user agent from overriding the color scheme for the element.
+    const Only  = 0b100;
+  }
+}
+
+impl Default for ColorScheme {
+  fn default() -> ColorScheme {
+    ColorScheme::empty()
+  }
+}
+
+impl<'i> Parse<'i> for ColorScheme {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(ColorScheme::empty());
+    }
+
+    let mut value = ColorScheme::empty();
+    let mut any = false;
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      match_ignore_ascii_case! { &ident,
+        "light" => value |= ColorScheme::Light,
+        "dark" => value |= ColorScheme::Dark,
+        "only" => {
+          if value.contains(ColorScheme::Only) {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          value |= ColorScheme::Only;
+          continue;
+        },
+        // <custom-ident> excludes the CSS-wide keywords, and `none`.
+        "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
+          return Err(input.new_custom_error(ParserError::InvalidValue));
+        },
+        _ => {}
+      }
+      any = true;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for ColorScheme {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("normal");
+    }
+
+    if self.contains(ColorScheme::Light) {
+      dest.write_str("light")?;
+      if self.contains(ColorScheme::Dark) {
+        dest.write_char(' ')?;
+      }
+    }
+
+    if self.contains(ColorScheme::Dark) {
+      dest.write_str("dark")?;
+    }
+
+    if self.contains(ColorScheme::Only) {
+      dest.write_str(" only")?;
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ColorSchemeHandler;
+
+impl<'i> PropertyHandler<'i> for ColorSchemeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ColorScheme(color_scheme) => {
+        // When light-dark() is not supported, colors are compiled to a pair of var() references,
+        // where exactly one of these custom properties is set to the guaranteed-invalid value
+        // (`initial`) so that its fallback is used, and the other is empty.
+        if !context.is_supported(Feature::LightDark) {
+          if color_scheme.contains(ColorScheme::Light) {
+            dest.push(define_var("--parcel-light", Token::Ident("initial".into())));
+            dest.push(define_var("--parcel-dark", Token::WhiteSpace(" ")));
+
+            if color_scheme.contains(ColorScheme::Dark) {
+              context.add_dark_rule(define_var("--parcel-light", Token::WhiteSpace(" ")));
+              context.add_dark_rule(define_var("--parcel-dark", Token::Ident("initial".into())));
+            }
+          } else if color_scheme.contains(ColorScheme::Dark) {
+            dest.push(define_var("--parcel-light", Token::WhiteSpace(" ")));
+            dest.push(define_var("--parcel-dark", Token::Ident("initial".into())));
+          }
+        }
+
+        dest.push(property.clone());
+        true
+      }
+      _ => false,
+    }
+  }
+
+  fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
+}
+
+#[inline]
+fn define_var<'i>(name: &'static str, value: Token<'static>) -> Property<'i> {
+  Property::Custom(CustomProperty {
+    name: name.into(),
+    value: TokenList(vec![TokenOrValue::Token(value)]),
+  })
+}
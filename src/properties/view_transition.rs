@@ -0,0 +1,123 @@
+//! CSS properties related to view transitions.
+
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::{CustomIdent, CustomIdentList};
+use cssparser::*;
+use smallvec::SmallVec;
+
+/// A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ViewTransitionName<'i> {
+  /// The element does not participate in a view transition.
+  None,
+  /// The element participates in a view transition with a name generated by the browser.
+  Auto,
+  /// The element participates in a view transition with a name generated from its identity.
+  MatchElement,
+  /// The element participates in a view transition with the given name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Custom(CustomIdent<'i>),
+}
+
+impl<'i> Parse<'i> for ViewTransitionName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ViewTransitionName::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ViewTransitionName::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("match-element")).is_ok() {
+      return Ok(ViewTransitionName::MatchElement);
+    }
+
+    Ok(ViewTransitionName::Custom(CustomIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for ViewTransitionName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ViewTransitionName::None => dest.write_str("none"),
+      ViewTransitionName::Auto => dest.write_str("auto"),
+      ViewTransitionName::MatchElement => dest.write_str("match-element"),
+      // The root element's view transition name is defined by the browser, so it is not scoped.
+      ViewTransitionName::Custom(name) if name.0.as_ref() == "root" => dest.write_str("root"),
+      ViewTransitionName::Custom(name) => name.to_css(dest),
+    }
+  }
+}
+
+/// A list of identifiers or the `none` keyword, as used in the
+/// [view-transition-class](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class-prop)
+/// property and the `types` descriptor of the `@view-transition` rule.
+///
+/// Unlike view transition names, these identifiers are not scoped in CSS modules,
+/// because view transition types are also referenced from JavaScript.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum NoneOrCustomIdentList<'i> {
+  /// The `none` keyword.
+  None,
+  /// A list of identifiers.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Idents(CustomIdentList<'i>),
+}
+
+impl<'i> Parse<'i> for NoneOrCustomIdentList<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(NoneOrCustomIdentList::None);
+    }
+
+    let mut idents = SmallVec::new();
+    while let Ok(ident) = input.try_parse(CustomIdent::parse) {
+      idents.push(ident);
+    }
+
+    if idents.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(NoneOrCustomIdentList::Idents(idents))
+  }
+}
+
+impl<'i> ToCss for NoneOrCustomIdentList<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      NoneOrCustomIdentList::None => dest.write_str("none"),
+      NoneOrCustomIdentList::Idents(idents) => {
+        let mut first = true;
+        for ident in idents {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          serialize_identifier(&ident.0, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
@@ -0,0 +1,77 @@
+//! CSS properties related to writing modes and bidirectional text.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [writing-mode](https://drafts.csswg.org/css-writing-modes-4/#block-flow) property.
+  pub enum WritingMode {
+    /// Top-to-bottom block flow, with horizontal inline flow.
+    "horizontal-tb": HorizontalTb,
+    /// Right-to-left block flow, with vertical inline flow.
+    "vertical-rl": VerticalRl,
+    /// Left-to-right block flow, with vertical inline flow.
+    "vertical-lr": VerticalLr,
+    /// Right-to-left block flow, with vertical inline flow and all glyphs set sideways.
+    "sideways-rl": SidewaysRl,
+    /// Left-to-right block flow, with vertical inline flow and all glyphs set sideways,
+    /// so that lines run from bottom to top.
+    "sideways-lr": SidewaysLr,
+  }
+}
+
+impl Default for WritingMode {
+  fn default() -> WritingMode {
+    WritingMode::HorizontalTb
+  }
+}
+
+impl WritingMode {
+  /// Returns whether the inline axis is vertical in this writing mode.
+  pub fn is_vertical(&self) -> bool {
+    *self != WritingMode::HorizontalTb
+  }
+}
+
+enum_property! {
+  /// A value for the [direction](https://drafts.csswg.org/css-writing-modes-4/#direction) property.
+  pub enum Direction {
+    /// Left-to-right inline base direction.
+    Ltr,
+    /// Right-to-left inline base direction.
+    Rtl,
+  }
+}
+
+enum_property! {
+  /// A value for the [unicode-bidi](https://drafts.csswg.org/css-writing-modes-4/#unicode-bidi) property.
+  pub enum UnicodeBidi {
+    /// The box does not open an additional level of embedding.
+    "normal": Normal,
+    /// The box opens an additional level of embedding.
+    "embed": Embed,
+    /// The box's contents are treated as an isolated, independent paragraph.
+    "isolate": Isolate,
+    /// The box's contents are reordered strictly in the given direction, ignoring implicit bidi rules.
+    "bidi-override": BidiOverride,
+    /// Combines the behavior of `isolate` and `bidi-override`.
+    "isolate-override": IsolateOverride,
+    /// The box's contents are isolated, with the base direction determined from the content.
+    "plaintext": Plaintext,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-orientation](https://drafts.csswg.org/css-writing-modes-4/#text-orientation) property.
+  pub enum TextOrientation {
+    /// Horizontal-only scripts are set sideways, and vertical scripts are set upright.
+    Mixed,
+    /// All characters are set upright.
+    Upright,
+    /// All characters are set sideways.
+    Sideways,
+  }
+}
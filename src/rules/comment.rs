@@ -0,0 +1,60 @@
+//! Preserved comments.
+
+use super::Location;
+use crate::error::PrinterError;
+use crate::printer::Printer;
+use crate::traits::ToCss;
+use crate::values::string::CowArcStr;
+
+/// A comment between rules that is preserved in the output, even when minifying.
+///
+/// Only `/*! ... */` comments, which are commonly used for license headers, and
+/// `/* #__KEEP__ */` annotations are preserved. An annotation prevents the rule
+/// that follows it from being merged with other rules or removed during minification.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommentRule<'i> {
+  /// The text of the comment, without the `/*` and `*/` delimiters.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub text: CowArcStr<'i>,
+  /// The location of the rule in the source file.
+  pub loc: Location,
+}
+
+impl<'i> CommentRule<'i> {
+  /// Creates a comment rule if the given comment should be preserved.
+  pub(crate) fn new(text: &'i str, loc: Location) -> Option<CommentRule<'i>> {
+    let rule = CommentRule { text: text.into(), loc };
+    if text.starts_with('!') || rule.is_keep_annotation() {
+      Some(rule)
+    } else {
+      None
+    }
+  }
+
+  /// Returns whether the comment is a `#__KEEP__` (or `@__KEEP__`) annotation.
+  pub fn is_keep_annotation(&self) -> bool {
+    matches!(self.text.trim(), "#__KEEP__" | "@__KEEP__")
+  }
+}
+
+impl<'i> ToCss for CommentRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.add_mapping(self.loc);
+    dest.write_str("/*")?;
+    // Write line by line so that the printer keeps track of line numbers for source maps.
+    let mut first = true;
+    for line in self.text.split('\n') {
+      if first {
+        first = false;
+      } else {
+        dest.write_char('\n')?;
+      }
+      dest.write_str(line)?;
+    }
+    dest.write_str("*/")
+  }
+}
@@ -5,11 +5,15 @@ use cssparser::*;
 use super::Location;
 use super::{CssRuleList, MinifyContext};
 use crate::error::{MinifyError, ParserError, PrinterError};
-use crate::media_query::MediaCondition;
+use crate::macros::enum_property;
+use crate::media_query::{MediaFeature, Operator};
+use crate::parser::ParserOptions;
 use crate::printer::Printer;
+use crate::properties::{Property, PropertyId};
 use crate::rules::{StyleContext, ToCssWithContext};
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Parse, ParseWithOptions, ToCss};
 use crate::values::ident::CustomIdent;
+use crate::values::string::CowArcStr;
 
 /// A [@container](https://drafts.csswg.org/css-contain-3/#container-rule) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -19,7 +23,7 @@ pub struct ContainerRule<'i> {
   #[cfg_attr(feature = "serde", serde(borrow))]
   pub name: Option<ContainerName<'i>>,
   /// The container condition.
-  pub condition: MediaCondition<'i>,
+  pub condition: ContainerCondition<'i>,
   /// The rules within the `@container` rule.
   pub rules: CssRuleList<'i>,
   /// The location of the rule in the source file.
@@ -50,6 +54,496 @@ impl<'i> ToCss for ContainerName<'i> {
   }
 }
 
+/// A [container condition](https://drafts.csswg.org/css-contain-3/#container-condition) in a `@container` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ContainerCondition<'i> {
+  /// A size container feature, implicitly parenthesized.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Feature(ContainerSizeFeature<'i>),
+  /// A negation of a condition.
+  Not(Box<ContainerCondition<'i>>),
+  /// A set of joint operations.
+  Operation(Vec<ContainerCondition<'i>>, Operator),
+  /// A condition wrapped in parenthesis.
+  InParens(Box<ContainerCondition<'i>>),
+  /// A style query.
+  Style(StyleQuery<'i>),
+  /// A scroll state query.
+  ScrollState(ScrollStateQuery),
+}
+
+/// A [size container feature](https://drafts.csswg.org/css-contain-3/#size-container).
+///
+/// Size features share their syntax with media features, but only `width`, `height`,
+/// `inline-size`, `block-size`, `aspect-ratio`, and `orientation` are allowed.
+pub type ContainerSizeFeature<'i> = MediaFeature<'i>;
+
+/// A [style query](https://drafts.csswg.org/css-contain-3/#style-container) within a `style()` function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum StyleQuery<'i> {
+  /// A property declaration, e.g. `--theme: dark`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Declaration(Property<'i>),
+  /// A property name without a value, e.g. `--theme`.
+  Property(PropertyId<'i>),
+  /// A negation of a condition.
+  Not(Box<StyleQuery<'i>>),
+  /// A set of joint operations.
+  Operation(Vec<StyleQuery<'i>>, Operator),
+}
+
+/// A [scroll state query](https://drafts.csswg.org/css-conditional-5/#scroll-state-container)
+/// within a `scroll-state()` function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ScrollStateQuery {
+  /// A scroll state feature.
+  Feature(ScrollStateFeature),
+  /// A negation of a condition.
+  Not(Box<ScrollStateQuery>),
+  /// A set of joint operations.
+  Operation(Vec<ScrollStateQuery>, Operator),
+}
+
+/// A scroll state feature, e.g. `stuck: top`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollStateFeature {
+  /// The name of the feature.
+  pub name: ScrollStateFeatureName,
+  /// The feature value, or `None` when the feature is evaluated in a boolean context.
+  pub value: Option<ScrollStateValue>,
+}
+
+enum_property! {
+  /// The name of a scroll state feature.
+  pub enum ScrollStateFeatureName {
+    /// Whether a sticky positioned container is stuck to an edge of its scroll container.
+    Stuck,
+    /// Whether a container is snapped to its scroll snap container.
+    Snapped,
+    /// Whether a scroll container can be scrolled in a direction.
+    Scrollable,
+    /// The direction a scroll container was most recently scrolled in.
+    Scrolled,
+  }
+}
+
+enum_property! {
+  /// A value of a scroll state feature.
+  pub enum ScrollStateValue {
+    /// The `none` keyword.
+    "none": None,
+    /// The top edge.
+    "top": Top,
+    /// The right edge.
+    "right": Right,
+    /// The bottom edge.
+    "bottom": Bottom,
+    /// The left edge.
+    "left": Left,
+    /// The block start edge.
+    "block-start": BlockStart,
+    /// The inline start edge.
+    "inline-start": InlineStart,
+    /// The block end edge.
+    "block-end": BlockEnd,
+    /// The inline end edge.
+    "inline-end": InlineEnd,
+    /// The horizontal axis.
+    "x": X,
+    /// The vertical axis.
+    "y": Y,
+    /// The block axis.
+    "block": Block,
+    /// The inline axis.
+    "inline": Inline,
+    /// Both axes.
+    "both": Both,
+  }
+}
+
+impl ScrollStateFeatureName {
+  fn is_valid_value(&self, value: ScrollStateValue) -> bool {
+    use ScrollStateValue::*;
+    match self {
+      ScrollStateFeatureName::Stuck => matches!(
+        value,
+        None | Top | Right | Bottom | Left | BlockStart | InlineStart | BlockEnd | InlineEnd
+      ),
+      ScrollStateFeatureName::Snapped => matches!(value, None | X | Y | Block | Inline | Both),
+      ScrollStateFeatureName::Scrollable | ScrollStateFeatureName::Scrolled => !matches!(value, Both),
+    }
+  }
+}
+
+/// A condition made up of features combined with `not`, `and`, and `or`.
+trait QueryCondition<'i>: Sized {
+  /// Parses a feature within parentheses.
+  fn parse_feature<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>>;
+
+  /// Parses the arguments of a function, e.g. `style()`.
+  fn parse_function<'t>(
+    _name: &CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+    _options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Err(input.new_custom_error(ParserError::InvalidContainerQuery))
+  }
+
+  fn create_negation(condition: Box<Self>) -> Self;
+  fn create_operation(conditions: Vec<Self>, operator: Operator) -> Self;
+
+  /// Wraps a nested condition that was explicitly parenthesized in the source.
+  fn create_in_parens(condition: Self) -> Self {
+    condition
+  }
+
+  /// Returns whether the condition must be wrapped in parentheses when it is an operand.
+  fn needs_parens(&self) -> bool;
+}
+
+fn parse_query_condition<'i, 't, Q: QueryCondition<'i>>(
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions,
+) -> Result<Q, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|i| i.expect_ident_matching("not")).is_ok() {
+    let condition = parse_query_in_parens(input, options)?;
+    return Ok(Q::create_negation(Box::new(condition)));
+  }
+
+  let first_condition = parse_query_in_parens(input, options)?;
+  let operator = match input.try_parse(Operator::parse) {
+    Ok(op) => op,
+    Err(..) => return Ok(first_condition),
+  };
+
+  let mut conditions = vec![first_condition];
+  conditions.push(parse_query_in_parens(input, options)?);
+
+  let delim = match operator {
+    Operator::And => "and",
+    Operator::Or => "or",
+  };
+
+  loop {
+    if input.try_parse(|i| i.expect_ident_matching(delim)).is_err() {
+      return Ok(Q::create_operation(conditions, operator));
+    }
+
+    conditions.push(parse_query_in_parens(input, options)?);
+  }
+}
+
+fn parse_query_in_parens<'i, 't, Q: QueryCondition<'i>>(
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions,
+) -> Result<Q, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  match *input.next()? {
+    Token::ParenthesisBlock => input.parse_nested_block(|input| {
+      if let Ok(condition) = input.try_parse(|i| parse_query_condition(i, options)) {
+        return Ok(Q::create_in_parens(condition));
+      }
+
+      Q::parse_feature(input, options)
+    }),
+    Token::Function(ref name) => {
+      let name = name.clone();
+      input.parse_nested_block(|input| Q::parse_function(&name, input, options))
+    }
+    ref t => Err(location.new_unexpected_token_error(t.clone())),
+  }
+}
+
+/// Serializes an operand of `not`, `and`, or `or`.
+fn operand_to_css<'i, Q, W>(condition: &Q, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  Q: QueryCondition<'i> + ToCss,
+  W: std::fmt::Write,
+{
+  if condition.needs_parens() {
+    dest.write_char('(')?;
+    condition.to_css(dest)?;
+    dest.write_char(')')
+  } else {
+    condition.to_css(dest)
+  }
+}
+
+fn operation_to_css<'i, Q, W>(
+  conditions: &[Q],
+  operator: Operator,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  Q: QueryCondition<'i> + ToCss,
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  for condition in conditions {
+    if !first {
+      dest.write_char(' ')?;
+      operator.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+    first = false;
+    operand_to_css(condition, dest)?;
+  }
+  Ok(())
+}
+
+impl<'i> ParseWithOptions<'i> for ContainerCondition<'i> {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    parse_query_condition(input, options)
+  }
+}
+
+impl<'i> QueryCondition<'i> for ContainerCondition<'i> {
+  fn parse_feature<'t>(
+    input: &mut Parser<'i, 't>,
+    _options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let feature = MediaFeature::parse(input)?;
+    let name = match &feature {
+      MediaFeature::Plain { name, .. } => {
+        let name = name.as_ref();
+        if name.len() > 4 && (name[..4].eq_ignore_ascii_case("min-") || name[..4].eq_ignore_ascii_case("max-")) {
+          &name[4..]
+        } else {
+          name
+        }
+      }
+      MediaFeature::Boolean(name) | MediaFeature::Range { name, .. } | MediaFeature::Interval { name, .. } => {
+        name.as_ref()
+      }
+    };
+
+    match_ignore_ascii_case! { name,
+      "width" | "height" | "inline-size" | "block-size" | "aspect-ratio" | "orientation" => {
+        Ok(ContainerCondition::Feature(feature))
+      },
+      _ => Err(location.new_custom_error(ParserError::InvalidContainerQuery))
+    }
+  }
+
+  fn parse_function<'t>(
+    name: &CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    match_ignore_ascii_case! { name.as_ref(),
+      "style" => Ok(ContainerCondition::Style(StyleQuery::parse_with_options(input, options)?)),
+      "scroll-state" => Ok(ContainerCondition::ScrollState(ScrollStateQuery::parse_with_options(input, options)?)),
+      _ => Err(input.new_custom_error(ParserError::InvalidContainerQuery))
+    }
+  }
+
+  fn create_negation(condition: Box<Self>) -> Self {
+    ContainerCondition::Not(condition)
+  }
+
+  fn create_operation(conditions: Vec<Self>, operator: Operator) -> Self {
+    ContainerCondition::Operation(conditions, operator)
+  }
+
+  fn create_in_parens(condition: Self) -> Self {
+    ContainerCondition::InParens(Box::new(condition))
+  }
+
+  fn needs_parens(&self) -> bool {
+    matches!(self, ContainerCondition::Not(..) | ContainerCondition::Operation(..))
+  }
+}
+
+impl<'i> ToCss for ContainerCondition<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContainerCondition::Feature(feature) => feature.to_css(dest),
+      ContainerCondition::Not(condition) => {
+        dest.write_str("not ")?;
+        operand_to_css(&**condition, dest)
+      }
+      ContainerCondition::Operation(conditions, operator) => operation_to_css(conditions, *operator, dest),
+      ContainerCondition::InParens(condition) => {
+        dest.write_char('(')?;
+        condition.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ContainerCondition::Style(query) => {
+        dest.write_str("style(")?;
+        query.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ContainerCondition::ScrollState(query) => {
+        dest.write_str("scroll-state(")?;
+        query.to_css(dest)?;
+        dest.write_char(')')
+      }
+    }
+  }
+}
+
+impl<'i> ParseWithOptions<'i> for StyleQuery<'i> {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(query) = input.try_parse(|input| parse_query_condition(input, options)) {
+      return Ok(query);
+    }
+
+    Self::parse_feature(input, options)
+  }
+}
+
+impl<'i> QueryCondition<'i> for StyleQuery<'i> {
+  fn parse_feature<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let property_id = PropertyId::from(CowArcStr::from(input.expect_ident()?));
+    if input.try_parse(|input| input.expect_colon()).is_err() {
+      input.expect_exhausted()?;
+      return Ok(StyleQuery::Property(property_id));
+    }
+
+    let property = Property::parse(property_id, input, options)?;
+    input.expect_exhausted()?;
+    Ok(StyleQuery::Declaration(property))
+  }
+
+  fn create_negation(condition: Box<Self>) -> Self {
+    StyleQuery::Not(condition)
+  }
+
+  fn create_operation(conditions: Vec<Self>, operator: Operator) -> Self {
+    StyleQuery::Operation(conditions, operator)
+  }
+
+  fn needs_parens(&self) -> bool {
+    true
+  }
+}
+
+impl<'i> ToCss for StyleQuery<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      StyleQuery::Declaration(property) => property.to_css(dest, false),
+      StyleQuery::Property(property_id) => property_id.to_css(dest),
+      StyleQuery::Not(query) => {
+        dest.write_str("not ")?;
+        operand_to_css(&**query, dest)
+      }
+      StyleQuery::Operation(queries, operator) => operation_to_css(queries, *operator, dest),
+    }
+  }
+}
+
+impl<'i> ParseWithOptions<'i> for ScrollStateQuery {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(query) = input.try_parse(|input| parse_query_condition(input, options)) {
+      return Ok(query);
+    }
+
+    Self::parse_feature(input, options)
+  }
+}
+
+impl<'i> QueryCondition<'i> for ScrollStateQuery {
+  fn parse_feature<'t>(
+    input: &mut Parser<'i, 't>,
+    _options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = ScrollStateFeatureName::parse(input)?;
+    let value = if input.try_parse(|input| input.expect_colon()).is_ok() {
+      let location = input.current_source_location();
+      let value = ScrollStateValue::parse(input)?;
+      if !name.is_valid_value(value) {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Some(value)
+    } else {
+      None
+    };
+
+    input.expect_exhausted()?;
+    Ok(ScrollStateQuery::Feature(ScrollStateFeature { name, value }))
+  }
+
+  fn create_negation(condition: Box<Self>) -> Self {
+    ScrollStateQuery::Not(condition)
+  }
+
+  fn create_operation(conditions: Vec<Self>, operator: Operator) -> Self {
+    ScrollStateQuery::Operation(conditions, operator)
+  }
+
+  fn needs_parens(&self) -> bool {
+    true
+  }
+}
+
+impl ToCss for ScrollStateQuery {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollStateQuery::Feature(feature) => feature.to_css(dest),
+      ScrollStateQuery::Not(query) => {
+        dest.write_str("not ")?;
+        operand_to_css(&**query, dest)
+      }
+      ScrollStateQuery::Operation(queries, operator) => operation_to_css(queries, *operator, dest),
+    }
+  }
+}
+
+impl ToCss for ScrollStateFeature {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if let Some(value) = &self.value {
+      dest.delim(':', false)?;
+      value.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 impl<'i> ContainerRule<'i> {
   pub(crate) fn minify(
     &mut self,
@@ -57,7 +551,7 @@ impl<'i> ContainerRule<'i> {
     parent_is_unused: bool,
   ) -> Result<bool, MinifyError> {
     self.rules.minify(context, parent_is_unused)?;
-    Ok(self.rules.0.is_empty())
+    Ok(context.options.remove_empty_rules && self.rules.0.is_empty())
   }
 }
 
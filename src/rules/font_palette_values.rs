@@ -186,7 +186,7 @@ impl<'i> Parse<'i> for OverrideColors {
     }
 
     let color = CssColor::parse(input)?;
-    if matches!(color, CssColor::CurrentColor) {
+    if !color.is_static() {
       return Err(input.new_custom_error(ParserError::InvalidValue));
     }
 
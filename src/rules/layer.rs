@@ -1,8 +1,10 @@
 //! The `@layer` rule.
 
-use super::{CssRuleList, Location, MinifyContext};
+use super::style::StyleRule;
+use super::{CssRule, CssRuleList, Location, MinifyContext, StyleContext, ToCssWithContext};
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::selector::{add_id_specificity, max_id_specificity};
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
 use cssparser::*;
@@ -121,12 +123,16 @@ impl<'i> LayerBlockRule<'i> {
   ) -> Result<bool, MinifyError> {
     self.rules.minify(context, parent_is_unused)?;
 
-    Ok(self.rules.0.is_empty())
+    Ok(context.options.remove_empty_rules && self.rules.0.is_empty())
   }
 }
 
-impl<'i> ToCss for LayerBlockRule<'i> {
-  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+impl<'a, 'i> ToCssWithContext<'a, 'i> for LayerBlockRule<'i> {
+  fn to_css_with_context<W>(
+    &self,
+    dest: &mut Printer<W>,
+    context: Option<&StyleContext<'a, 'i>>,
+  ) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
@@ -141,9 +147,182 @@ impl<'i> ToCss for LayerBlockRule<'i> {
     dest.write_char('{')?;
     dest.indent();
     dest.newline()?;
-    self.rules.to_css(dest)?;
+    self.rules.to_css_with_context(dest, context)?;
     dest.dedent();
     dest.newline()?;
     dest.write_char('}')
   }
 }
+
+/// The tree of cascade layers declared in a style sheet. The root node represents unlayered styles.
+struct LayerTree<'i> {
+  nodes: Vec<LayerNode<'i>>,
+}
+
+struct LayerNode<'i> {
+  /// The name of the layer, or `None` for anonymous layers (and the root).
+  name: Option<CowArcStr<'i>>,
+  children: Vec<usize>,
+}
+
+impl<'i> LayerTree<'i> {
+  fn new() -> Self {
+    LayerTree {
+      nodes: vec![LayerNode {
+        name: None,
+        children: Vec::new(),
+      }],
+    }
+  }
+
+  /// Returns the node for the given layer name within the parent layer, declaring it if needed.
+  fn declare(&mut self, parent: usize, name: &LayerName<'i>) -> usize {
+    let mut id = parent;
+    for part in &name.0 {
+      let existing = self.nodes[id]
+        .children
+        .iter()
+        .find(|child| self.nodes[**child].name.as_ref() == Some(part))
+        .cloned();
+      id = match existing {
+        Some(child) => child,
+        None => self.add(id, Some(part.clone())),
+      };
+    }
+    id
+  }
+
+  fn add(&mut self, parent: usize, name: Option<CowArcStr<'i>>) -> usize {
+    let id = self.nodes.len();
+    self.nodes.push(LayerNode {
+      name,
+      children: Vec::new(),
+    });
+    self.nodes[parent].children.push(id);
+    id
+  }
+
+  /// Collects the layers declared by the given rules, and returns the maximum number of
+  /// id selectors in any style rule.
+  fn collect(&mut self, rules: &CssRuleList<'i>, parent: usize, anonymous: &mut Vec<usize>) -> u32 {
+    let mut max_ids = 0;
+    for rule in &rules.0 {
+      let ids = match rule {
+        CssRule::LayerStatement(statement) => {
+          for name in &statement.names {
+            self.declare(parent, name);
+          }
+          0
+        }
+        CssRule::LayerBlock(block) => {
+          let id = match &block.name {
+            Some(name) => self.declare(parent, name),
+            None => {
+              let id = self.add(parent, None);
+              anonymous.push(id);
+              id
+            }
+          };
+          self.collect(&block.rules, id, anonymous)
+        }
+        CssRule::Import(import) => {
+          if let Some(Some(name)) = &import.layer {
+            self.declare(parent, name);
+          }
+          0
+        }
+        CssRule::Media(media) => self.collect(&media.rules, parent, anonymous),
+        CssRule::Supports(supports) => self.collect(&supports.rules, parent, anonymous),
+        CssRule::Container(container) => self.collect(&container.rules, parent, anonymous),
+        CssRule::MozDocument(document) => self.collect(&document.rules, parent, anonymous),
+        CssRule::Style(style) => max_style_ids(style),
+        _ => 0,
+      };
+      max_ids = max_ids.max(ids);
+    }
+    max_ids
+  }
+
+  /// Assigns a rank to each layer in precedence order. Nested layers have lower precedence
+  /// than the styles directly within their parent layer, and the root (unlayered styles)
+  /// has the highest precedence of all.
+  fn rank(&self, id: usize, ranks: &mut [u32], next: &mut u32) {
+    for child in &self.nodes[id].children {
+      self.rank(*child, ranks, next);
+    }
+    ranks[id] = *next;
+    *next += 1;
+  }
+}
+
+fn max_style_ids(style: &StyleRule) -> u32 {
+  let mut max_ids = max_id_specificity(&style.selectors);
+  for rule in &style.rules.0 {
+    if let CssRule::Style(nested) = rule {
+      max_ids = max_ids.max(max_style_ids(nested));
+    }
+  }
+  max_ids
+}
+
+struct LayerFlattener<'i> {
+  tree: LayerTree<'i>,
+  ranks: Vec<u32>,
+  step: u32,
+  anonymous: std::vec::IntoIter<usize>,
+}
+
+impl<'i> LayerFlattener<'i> {
+  fn flatten(&mut self, rules: &mut CssRuleList<'i>, parent: usize) {
+    let mut result = Vec::with_capacity(rules.0.len());
+    for mut rule in std::mem::take(&mut rules.0) {
+      match &mut rule {
+        CssRule::LayerStatement(_) => continue,
+        CssRule::LayerBlock(block) => {
+          let id = match &block.name {
+            Some(name) => self.tree.declare(parent, name),
+            None => self.anonymous.next().unwrap(),
+          };
+          self.flatten(&mut block.rules, id);
+          result.append(&mut block.rules.0);
+          continue;
+        }
+        CssRule::Media(media) => self.flatten(&mut media.rules, parent),
+        CssRule::Supports(supports) => self.flatten(&mut supports.rules, parent),
+        CssRule::Container(container) => self.flatten(&mut container.rules, parent),
+        CssRule::MozDocument(document) => self.flatten(&mut document.rules, parent),
+        CssRule::Style(style) => add_id_specificity(&mut style.selectors, self.ranks[parent] * self.step),
+        _ => {}
+      }
+      result.push(rule);
+    }
+    rules.0 = result;
+  }
+}
+
+/// Removes `@layer` rules for browsers that do not support cascade layers, while preserving
+/// the precedence of the layers. The final layer order is computed from all `@layer` statements
+/// and blocks, and the specificity of the selectors within each layer is raised by repeating
+/// `:not(#\#)` so that later layers win over earlier ones, and unlayered styles win over all layers.
+///
+/// Note that the reversed precedence of `!important` declarations within layers is not preserved.
+pub(crate) fn flatten_layers<'i>(rules: &mut CssRuleList<'i>) {
+  let mut tree = LayerTree::new();
+  let mut anonymous = Vec::new();
+  let max_ids = tree.collect(rules, 0, &mut anonymous);
+  if tree.nodes.len() == 1 {
+    return;
+  }
+
+  let mut ranks = vec![0; tree.nodes.len()];
+  tree.rank(0, &mut ranks, &mut 0);
+
+  // Each layer must add more id selectors than any selector in a lower layer could have.
+  let mut flattener = LayerFlattener {
+    tree,
+    ranks,
+    step: max_ids + 1,
+    anonymous: anonymous.into_iter(),
+  };
+  flattener.flatten(rules, 0);
+}
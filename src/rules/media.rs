@@ -2,7 +2,8 @@
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
-use crate::error::{MinifyError, PrinterError};
+use crate::compat::Feature;
+use crate::error::{MinifyError, MinifyErrorKind, PrinterError};
 use crate::media_query::MediaList;
 use crate::printer::Printer;
 use crate::rules::{StyleContext, ToCssWithContext};
@@ -33,7 +34,16 @@ impl<'i> MediaRule<'i> {
       self.query.transform_custom_media(self.loc, custom_media)?;
     }
 
-    Ok(self.rules.0.is_empty() || self.query.never_matches())
+    if let Some(targets) = context.targets {
+      if !Feature::MediaBooleanLogic.is_compatible(*targets) && self.query.downlevel_conditions().is_err() {
+        context.warnings.push(MinifyError {
+          kind: MinifyErrorKind::UnsupportedMediaBooleanLogic,
+          loc: self.loc,
+        });
+      }
+    }
+
+    Ok((context.options.remove_empty_rules && self.rules.0.is_empty()) || self.query.never_matches())
   }
 }
 
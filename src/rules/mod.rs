@@ -36,6 +36,7 @@
 
 #![deny(missing_docs)]
 
+pub mod comment;
 pub mod container;
 pub mod counter_style;
 pub mod custom_media;
@@ -50,27 +51,35 @@ pub mod namespace;
 pub mod nesting;
 pub mod page;
 pub mod property;
+pub mod scroll_timeline;
 pub mod style;
 pub mod supports;
 pub mod unknown;
+pub mod view_transition;
 pub mod viewport;
 
 use self::font_palette_values::FontPaletteValuesRule;
 use self::layer::{LayerBlockRule, LayerStatementRule};
 use self::property::PropertyRule;
+use self::scroll_timeline::ScrollTimelineRule;
+use self::view_transition::ViewTransitionRule;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationHandler;
 use crate::dependencies::{Dependency, ImportDependency};
-use crate::error::{MinifyError, ParserError, PrinterError};
+use crate::error::{MinifyError, MinifyErrorKind, ParserError, PrinterError};
 use crate::parser::TopLevelRuleParser;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
-use crate::selector::{downlevel_selectors, get_prefix, is_equivalent};
-use crate::stylesheet::ParserOptions;
+use crate::selector::{
+  downlevel_selectors, expand_is_selectors, get_prefix, has_has_selector, has_negation_list,
+  has_view_transition_pseudo_element, is_equivalent, Selectors,
+};
+use crate::stylesheet::{MinifyOptions, ParserOptions};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
+use comment::CommentRule;
 use container::ContainerRule;
 use counter_style::CounterStyleRule;
 use cssparser::{parse_one_rule, ParseError, Parser, ParserInput};
@@ -83,8 +92,9 @@ use media::MediaRule;
 use namespace::NamespaceRule;
 use nesting::NestingRule;
 use page::PageRule;
+use parcel_selectors::SelectorList;
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use style::StyleRule;
 use supports::SupportsRule;
 use unknown::UnknownAtRule;
@@ -101,7 +111,7 @@ pub(crate) trait ToCssWithContext<'a, 'i> {
 }
 
 pub(crate) struct StyleContext<'a, 'i> {
-  pub rule: &'a StyleRule<'i>,
+  pub selectors: &'a SelectorList<'i, Selectors>,
   pub parent: Option<&'a StyleContext<'a, 'i>>,
 }
 
@@ -163,10 +173,16 @@ pub enum CssRule<'i> {
   Property(PropertyRule<'i>),
   /// A `@container` rule.
   Container(ContainerRule<'i>),
+  /// A `@view-transition` rule.
+  ViewTransition(ViewTransitionRule<'i>),
+  /// A `@scroll-timeline` rule.
+  ScrollTimeline(ScrollTimelineRule<'i>),
   /// A placeholder for a rule that was removed.
   Ignored,
   /// An unknown at-rule.
   Unknown(UnknownAtRule<'i>),
+  /// A preserved comment.
+  Comment(CommentRule<'i>),
 }
 
 impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRule<'i> {
@@ -194,10 +210,13 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRule<'i> {
       CssRule::Viewport(viewport) => viewport.to_css(dest),
       CssRule::CustomMedia(custom_media) => custom_media.to_css(dest),
       CssRule::LayerStatement(layer) => layer.to_css(dest),
-      CssRule::LayerBlock(layer) => layer.to_css(dest),
+      CssRule::LayerBlock(layer) => layer.to_css_with_context(dest, context),
       CssRule::Property(property) => property.to_css(dest),
       CssRule::Container(container) => container.to_css_with_context(dest, context),
+      CssRule::ViewTransition(view_transition) => view_transition.to_css(dest),
+      CssRule::ScrollTimeline(scroll_timeline) => scroll_timeline.to_css(dest),
       CssRule::Unknown(unknown) => unknown.to_css(dest),
+      CssRule::Comment(comment) => comment.to_css(dest),
       CssRule::Ignored => Ok(()),
     }
   }
@@ -243,8 +262,9 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub handler: &'a mut DeclarationHandler<'i>,
   pub important_handler: &'a mut DeclarationHandler<'i>,
   pub handler_context: &'a mut PropertyHandlerContext<'i, 'a>,
-  pub unused_symbols: &'a HashSet<String>,
+  pub options: &'a MinifyOptions,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
+  pub warnings: Vec<MinifyError>,
 }
 
 impl<'i> CssRuleList<'i> {
@@ -255,10 +275,14 @@ impl<'i> CssRuleList<'i> {
   ) -> Result<(), MinifyError> {
     let mut keyframe_rules = HashMap::new();
     let mut rules = Vec::new();
+    let mut keep_next = false;
     for mut rule in self.0.drain(..) {
+      // Rules following a `#__KEEP__` annotation are not merged with other rules or removed.
+      let kept = std::mem::take(&mut keep_next);
+      let merge = context.options.merge_rules && (rules.is_empty() || !is_kept(&rules, rules.len() - 1));
       match &mut rule {
         CssRule::Keyframes(keyframes) => {
-          if context.unused_symbols.contains(keyframes.name.0.as_ref()) {
+          if context.options.unused_symbols.contains(keyframes.name.0.as_ref()) {
             continue;
           }
           keyframes.minify(context);
@@ -302,20 +326,20 @@ impl<'i> CssRuleList<'i> {
         }
         CssRule::Media(media) => {
           if let Some(CssRule::Media(last_rule)) = rules.last_mut() {
-            if last_rule.query == media.query {
+            if merge && last_rule.query == media.query {
               last_rule.rules.0.extend(media.rules.0.drain(..));
               last_rule.minify(context, parent_is_unused)?;
               continue;
             }
           }
 
-          if media.minify(context, parent_is_unused)? {
+          if media.minify(context, parent_is_unused)? && !kept {
             continue;
           }
         }
         CssRule::Supports(supports) => {
           if let Some(CssRule::Supports(last_rule)) = rules.last_mut() {
-            if last_rule.condition == supports.condition {
+            if merge && last_rule.condition == supports.condition {
               last_rule.rules.0.extend(supports.rules.0.drain(..));
               last_rule.minify(context, parent_is_unused)?;
               continue;
@@ -323,56 +347,89 @@ impl<'i> CssRuleList<'i> {
           }
 
           supports.minify(context, parent_is_unused)?;
-          if supports.rules.0.is_empty() {
+          if context.options.remove_empty_rules && supports.rules.0.is_empty() && !kept {
             continue;
           }
         }
         CssRule::Container(container) => {
           if let Some(CssRule::Container(last_rule)) = rules.last_mut() {
-            if last_rule.name == container.name && last_rule.condition == container.condition {
+            if merge && last_rule.name == container.name && last_rule.condition == container.condition {
               last_rule.rules.0.extend(container.rules.0.drain(..));
               last_rule.minify(context, parent_is_unused)?;
               continue;
             }
           }
 
-          if container.minify(context, parent_is_unused)? {
+          if container.minify(context, parent_is_unused)? && !kept {
             continue;
           }
         }
         CssRule::LayerBlock(layer) => {
           if let Some(CssRule::LayerBlock(last_rule)) = rules.last_mut() {
-            if last_rule.name == layer.name {
+            if merge && last_rule.name == layer.name {
               last_rule.rules.0.extend(layer.rules.0.drain(..));
               last_rule.minify(context, parent_is_unused)?;
               continue;
             }
           }
-          if layer.minify(context, parent_is_unused)? {
+          if layer.minify(context, parent_is_unused)? && !kept {
             continue;
           }
         }
         CssRule::MozDocument(document) => document.minify(context)?,
         CssRule::Style(style) => {
-          if parent_is_unused || style.minify(context, parent_is_unused)? {
+          if (parent_is_unused || style.minify(context, parent_is_unused)?) && !kept {
             continue;
           }
 
           if let Some(targets) = context.targets {
             style.vendor_prefix = get_prefix(&style.selectors);
             if style.vendor_prefix.contains(VendorPrefix::None) {
+              expand_is_selectors(
+                &mut style.selectors,
+                *targets,
+                context.options.max_selector_expansion,
+                context.options.allow_specificity_changes,
+              );
               style.vendor_prefix = downlevel_selectors(&mut style.selectors, *targets);
+              if !crate::compat::Feature::CssNotSelList.is_compatible(*targets)
+                && has_negation_list(&style.selectors)
+              {
+                context.warnings.push(MinifyError {
+                  kind: MinifyErrorKind::UnsupportedNotSelectorList,
+                  loc: style.loc,
+                });
+              }
+            }
+
+            if !context.options.wrap_unsupported_has
+              && !crate::compat::Feature::CssHas.is_compatible(*targets)
+              && has_has_selector(&style.selectors)
+            {
+              context.warnings.push(MinifyError {
+                kind: MinifyErrorKind::UnsupportedHasSelector,
+                loc: style.loc,
+              });
+            }
+
+            if !crate::compat::Feature::ViewTransitions.is_compatible(*targets)
+              && (has_view_transition_pseudo_element(&style.selectors) || style.has_view_transition_properties())
+            {
+              context.warnings.push(MinifyError {
+                kind: MinifyErrorKind::UnsupportedViewTransitions,
+                loc: style.loc,
+              });
             }
           }
 
           // Attempt to merge the new rule with the last rule we added.
           let mut merged = false;
           if let Some(CssRule::Style(last_style_rule)) = rules.last_mut() {
-            if merge_style_rules(style, last_style_rule, context) {
+            if merge && merge_style_rules(style, last_style_rule, context) {
               // If that was successful, then the last rule has been updated to include the
               // selectors/declarations of the new rule. This might mean that we can merge it
               // with the previous rule, so continue trying while we have style rules available.
-              while rules.len() >= 2 {
+              while rules.len() >= 2 && !is_kept(&rules, rules.len() - 2) {
                 let len = rules.len();
                 let (a, b) = rules.split_at_mut(len - 1);
                 if let (CssRule::Style(last), CssRule::Style(prev)) = (&mut b[0], &mut a[len - 2]) {
@@ -391,7 +448,9 @@ impl<'i> CssRuleList<'i> {
 
           let supports = context.handler_context.get_supports_rules(&style);
           let logical = context.handler_context.get_logical_rules(&style);
-          if !merged && !style.is_empty() {
+          let dark = context.handler_context.get_dark_rules(&style);
+          let aspect_ratio = context.handler_context.get_aspect_ratio_rules(&style);
+          if !merged && (!style.is_empty() || !context.options.remove_empty_rules || kept) {
             rules.push(rule);
           }
 
@@ -402,10 +461,18 @@ impl<'i> CssRuleList<'i> {
           }
 
           rules.extend(supports);
+          rules.extend(dark);
+
+          if !aspect_ratio.is_empty() {
+            let mut aspect_ratio = CssRuleList(aspect_ratio);
+            aspect_ratio.minify(context, parent_is_unused)?;
+            rules.extend(aspect_ratio.0)
+          }
+
           continue;
         }
         CssRule::CounterStyle(counter_style) => {
-          if context.unused_symbols.contains(counter_style.name.0.as_ref()) {
+          if context.options.unused_symbols.contains(counter_style.name.0.as_ref()) {
             continue;
           }
         }
@@ -415,7 +482,7 @@ impl<'i> CssRuleList<'i> {
           }
         }
         CssRule::FontPaletteValues(f) => {
-          if context.unused_symbols.contains(f.name.0.as_ref()) {
+          if context.options.unused_symbols.contains(f.name.0.as_ref()) {
             continue;
           }
 
@@ -429,10 +496,26 @@ impl<'i> CssRuleList<'i> {
           }
         }
         CssRule::Property(property) => {
-          if context.unused_symbols.contains(property.name.0.as_ref()) {
+          if context.options.unused_symbols.contains(property.name.0.as_ref()) {
             continue;
           }
         }
+        CssRule::ViewTransition(view_transition) => {
+          if let Some(targets) = context.targets {
+            if !crate::compat::Feature::ViewTransitions.is_compatible(*targets) {
+              context.warnings.push(MinifyError {
+                kind: MinifyErrorKind::UnsupportedViewTransitions,
+                loc: view_transition.loc,
+              });
+            }
+          }
+        }
+        CssRule::ScrollTimeline(scroll_timeline) => {
+          if context.options.unused_symbols.contains(scroll_timeline.name.0.as_ref()) {
+            continue;
+          }
+        }
+        CssRule::Comment(comment) => keep_next = comment.is_keep_annotation(),
         _ => {}
       }
 
@@ -444,6 +527,11 @@ impl<'i> CssRuleList<'i> {
   }
 }
 
+/// Returns whether the rule at the given index follows a `#__KEEP__` annotation.
+fn is_kept(rules: &[CssRule], index: usize) -> bool {
+  index > 0 && matches!(&rules[index - 1], CssRule::Comment(comment) if comment.is_keep_annotation())
+}
+
 fn merge_style_rules<'i>(
   style: &mut StyleRule<'i>,
   last_style_rule: &mut StyleRule<'i>,
@@ -474,7 +562,11 @@ fn merge_style_rules<'i>(
   {
     // Append the selectors to the last rule if the declarations are the same, and all selectors are compatible.
     if style.is_compatible(*context.targets) && last_style_rule.is_compatible(*context.targets) {
-      last_style_rule.selectors.0.extend(style.selectors.0.drain(..));
+      for selector in style.selectors.0.drain(..) {
+        if !context.options.remove_duplicate_selectors || !last_style_rule.selectors.0.contains(&selector) {
+          last_style_rule.selectors.0.push(selector);
+        }
+      }
       return true;
     }
 
@@ -518,6 +610,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
   {
     let mut first = true;
     let mut last_without_block = false;
+    let mut last_comment = false;
 
     for rule in &self.0 {
       if let CssRule::Ignored = &rule {
@@ -542,6 +635,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
         first = false;
       } else {
         if !dest.minify
+          && !last_comment
           && !(last_without_block
             && matches!(
               rule,
@@ -557,6 +651,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
         rule,
         CssRule::Import(..) | CssRule::Namespace(..) | CssRule::LayerStatement(..)
       );
+      last_comment = matches!(rule, CssRule::Comment(..));
     }
 
     Ok(())
@@ -0,0 +1,230 @@
+//! The `@scroll-timeline` rule.
+
+use super::Location;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::properties::custom::CustomProperty;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::DashedIdent;
+use crate::values::string::CowArcStr;
+use cssparser::*;
+
+/// A [@scroll-timeline](https://www.w3.org/TR/2021/WD-scroll-animations-1-20210610/#scroll-timeline-at-rule) rule.
+///
+/// The name is a `<dashed-ident>` so that it can be referenced by the `animation-timeline` property.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollTimelineRule<'i> {
+  /// The name of the scroll timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// Declarations in the `@scroll-timeline` rule.
+  pub properties: Vec<ScrollTimelineProperty<'i>>,
+  /// The location of the rule in the source file.
+  pub loc: Location,
+}
+
+/// A property within an `@scroll-timeline` rule.
+///
+///  See [ScrollTimelineRule](ScrollTimelineRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ScrollTimelineProperty<'i> {
+  /// The `source` property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Source(ScrollTimelineSource<'i>),
+  /// The `orientation` property.
+  Orientation(ScrollTimelineOrientation),
+  /// An unknown or unsupported property.
+  Custom(CustomProperty<'i>),
+}
+
+/// A value for the [source](https://www.w3.org/TR/2021/WD-scroll-animations-1-20210610/#descdef-scroll-timeline-source)
+/// property in an `@scroll-timeline` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ScrollTimelineSource<'i> {
+  /// The scrolling element of the document.
+  Auto,
+  /// The timeline is inactive.
+  None,
+  /// The element with the given id, i.e. `selector(#id)`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Selector(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for ScrollTimelineSource<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ScrollTimelineSource::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ScrollTimelineSource::None);
+    }
+
+    input.expect_function_matching("selector")?;
+    input.parse_nested_block(|input| {
+      let location = input.current_source_location();
+      match input.next()? {
+        Token::IDHash(id) => Ok(ScrollTimelineSource::Selector(id.into())),
+        t => Err(location.new_unexpected_token_error(t.clone())),
+      }
+    })
+  }
+}
+
+impl<'i> ToCss for ScrollTimelineSource<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollTimelineSource::Auto => dest.write_str("auto"),
+      ScrollTimelineSource::None => dest.write_str("none"),
+      ScrollTimelineSource::Selector(id) => {
+        // Ids are scoped in CSS modules, so this must match the id selector of the element.
+        dest.write_str("selector(#")?;
+        dest.write_ident(id)?;
+        dest.write_char(')')
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [orientation](https://www.w3.org/TR/2021/WD-scroll-animations-1-20210610/#descdef-scroll-timeline-orientation)
+  /// property in an `@scroll-timeline` rule.
+  pub enum ScrollTimelineOrientation {
+    /// The block axis, or the horizontal axis if the source is not scrollable in the block axis.
+    Auto,
+    /// The block axis.
+    Block,
+    /// The inline axis.
+    Inline,
+    /// The horizontal axis.
+    Horizontal,
+    /// The vertical axis.
+    Vertical,
+  }
+}
+
+pub(crate) struct ScrollTimelineDeclarationParser;
+
+impl<'i> cssparser::DeclarationParser<'i> for ScrollTimelineDeclarationParser {
+  type Declaration = ScrollTimelineProperty<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    let state = input.state();
+    match_ignore_ascii_case! { &name,
+      "source" => {
+        if let Ok(source) = ScrollTimelineSource::parse(input) {
+          return Ok(ScrollTimelineProperty::Source(source))
+        }
+      },
+      "orientation" => {
+        if let Ok(orientation) = ScrollTimelineOrientation::parse(input) {
+          return Ok(ScrollTimelineProperty::Orientation(orientation))
+        }
+      },
+      _ => {}
+    }
+
+    // Other descriptors, e.g. `scroll-offsets`, are kept as is.
+    input.reset(&state);
+    return Ok(ScrollTimelineProperty::Custom(CustomProperty::parse(
+      name.into(),
+      input,
+      &Default::default(),
+    )?));
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'i> AtRuleParser<'i> for ScrollTimelineDeclarationParser {
+  type Prelude = ();
+  type AtRule = ScrollTimelineProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> ScrollTimelineRule<'i> {
+  pub(crate) fn parse<'t>(
+    name: DashedIdent<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut parser = DeclarationListParser::new(input, ScrollTimelineDeclarationParser);
+    let mut properties = vec![];
+    while let Some(decl) = parser.next() {
+      if let Ok(decl) = decl {
+        properties.push(decl);
+      }
+    }
+
+    Ok(ScrollTimelineRule { name, properties, loc })
+  }
+}
+
+impl<'i> ToCss for ScrollTimelineRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.add_mapping(self.loc);
+    dest.write_str("@scroll-timeline ")?;
+    dest.write_local_dashed_ident(&self.name.0, false)?;
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+    let len = self.properties.len();
+    for (i, prop) in self.properties.iter().enumerate() {
+      dest.newline()?;
+      prop.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for ScrollTimelineProperty<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    macro_rules! property {
+      ($prop: literal, $value: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        $value.to_css(dest)
+      }};
+    }
+
+    match self {
+      ScrollTimelineProperty::Source(s) => property!("source", s),
+      ScrollTimelineProperty::Orientation(o) => property!("orientation", o),
+      ScrollTimelineProperty::Custom(custom) => {
+        dest.write_str(custom.name.as_ref())?;
+        dest.delim(':', false)?;
+        custom.value.to_css(dest, true)
+      }
+    }
+  }
+}
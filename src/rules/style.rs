@@ -8,10 +8,11 @@ use crate::compat::Feature;
 use crate::context::DeclarationContext;
 use crate::declaration::DeclarationBlock;
 use crate::error::ParserError;
-use crate::error::{MinifyError, PrinterError, PrinterErrorKind};
+use crate::error::{MinifyError, MinifyErrorKind, PrinterError, PrinterErrorKind};
 use crate::printer::Printer;
-use crate::rules::{CssRuleList, StyleContext, ToCssWithContext};
-use crate::selector::{is_compatible, is_unused, Selectors};
+use crate::properties::PropertyId;
+use crate::rules::{CssRule, CssRuleList, StyleContext, ToCssWithContext};
+use crate::selector::{has_nesting_suffix, is_compatible, is_unused, minify_selectors, Selectors};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
 use crate::vendor_prefix::VendorPrefix;
@@ -52,9 +53,21 @@ impl<'i> StyleRule<'i> {
     context: &mut MinifyContext<'_, 'i>,
     parent_is_unused: bool,
   ) -> Result<bool, MinifyError> {
+    if context.options.remove_duplicate_selectors {
+      minify_selectors(
+        &mut self.selectors,
+        *context.targets,
+        context.options.allow_specificity_changes,
+      );
+    }
+
     let mut unused = false;
-    if !context.unused_symbols.is_empty() {
-      if is_unused(&mut self.selectors.0.iter(), &context.unused_symbols, parent_is_unused) {
+    if !context.options.unused_symbols.is_empty() {
+      if is_unused(
+        &mut self.selectors.0.iter(),
+        &context.options.unused_symbols,
+        parent_is_unused,
+      ) {
         if self.rules.0.is_empty() {
           return Ok(true);
         }
@@ -66,10 +79,17 @@ impl<'i> StyleRule<'i> {
     }
 
     context.handler_context.context = DeclarationContext::StyleRule;
+    context.handler_context.set_writing_mode(&self.declarations);
     self
       .declarations
       .minify(context.handler, context.important_handler, context.handler_context);
     context.handler_context.context = DeclarationContext::None;
+    if context.handler_context.reset_writing_mode() {
+      context.warnings.push(MinifyError {
+        kind: MinifyErrorKind::AmbiguousWritingMode,
+        loc: self.loc,
+      });
+    }
 
     if !self.rules.0.is_empty() {
       self.rules.minify(context, unused)?;
@@ -81,6 +101,16 @@ impl<'i> StyleRule<'i> {
     Ok(false)
   }
 
+  /// Returns whether the rule declares the `view-transition-name` or `view-transition-class` properties.
+  pub(crate) fn has_view_transition_properties(&self) -> bool {
+    self.declarations.iter().any(|(property, _)| {
+      matches!(
+        property.property_id(),
+        PropertyId::ViewTransitionName | PropertyId::ViewTransitionClass
+      )
+    })
+  }
+
   /// Returns whether the rule is empty.
   pub fn is_empty(&self) -> bool {
     self.declarations.is_empty() && self.rules.0.is_empty()
@@ -207,9 +237,10 @@ impl<'a, 'i> StyleRule<'i> {
     W: std::fmt::Write,
   {
     // If supported, or there are no targets, preserve nesting. Otherwise, write nested rules after parent.
+    // Sass-style nesting suffixes are not valid CSS, so they are always compiled.
     let supports_nesting = self.rules.0.is_empty()
-      || dest.targets.is_none()
-      || Feature::CssNesting.is_compatible(dest.targets.unwrap());
+      || ((dest.targets.is_none() || Feature::CssNesting.is_compatible(dest.targets.unwrap()))
+        && !has_nested_suffix(&self.rules));
     let len = self.declarations.declarations.len() + self.declarations.important_declarations.len();
     let has_declarations = supports_nesting || len > 0 || self.rules.0.is_empty();
 
@@ -286,7 +317,7 @@ impl<'a, 'i> StyleRule<'i> {
       self.rules.to_css_with_context(
         dest,
         Some(&StyleContext {
-          rule: self,
+          selectors: &self.selectors,
           parent: context,
         }),
       )?;
@@ -295,3 +326,19 @@ impl<'a, 'i> StyleRule<'i> {
     Ok(())
   }
 }
+
+/// Returns whether any of the nested style rules use a Sass-style nesting suffix, e.g. `&__element`.
+/// Suffixes are resolved against all ancestors, so the entire tree must be compiled in that case.
+fn has_nested_suffix(rules: &CssRuleList) -> bool {
+  rules.0.iter().any(|rule| match rule {
+    CssRule::Style(style) => style.selectors.0.iter().any(has_nesting_suffix) || has_nested_suffix(&style.rules),
+    CssRule::Nesting(nesting) => {
+      nesting.style.selectors.0.iter().any(has_nesting_suffix) || has_nested_suffix(&nesting.style.rules)
+    }
+    CssRule::Media(media) => has_nested_suffix(&media.rules),
+    CssRule::Supports(supports) => has_nested_suffix(&supports.rules),
+    CssRule::Container(container) => has_nested_suffix(&container.rules),
+    CssRule::LayerBlock(layer) => has_nested_suffix(&layer.rules),
+    _ => false,
+  })
+}
@@ -1,13 +1,19 @@
 //! The `@supports` rule.
 
 use super::Location;
-use super::{CssRuleList, MinifyContext};
+use super::{CssRule, CssRuleList, MinifyContext};
 use crate::error::{MinifyError, ParserError, PrinterError};
+use crate::parser::ParserOptions;
 use crate::printer::Printer;
 use crate::rules::{StyleContext, ToCssWithContext};
+#[cfg(feature = "serde")]
+use crate::selector::{deserialize_selectors, serialize_selectors};
+use crate::selector::{has_has_selector, SelectorIdent, SelectorParser, Selectors};
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
 use cssparser::*;
+use parcel_selectors::parser::{Component, LocalName, NestingRequirement, Selector, SelectorList};
+use std::collections::HashMap;
 
 /// A [@supports](https://drafts.csswg.org/css-conditional-3/#at-supports) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -74,7 +80,11 @@ pub enum SupportsCondition<'i> {
   #[cfg_attr(feature = "serde", serde(borrow))]
   Declaration(CowArcStr<'i>),
   /// A selector to evaluate.
-  Selector(CowArcStr<'i>),
+  #[cfg_attr(
+    feature = "serde",
+    serde(serialize_with = "serialize_selectors", deserialize_with = "deserialize_selectors")
+  )]
+  Selector(SelectorList<'i, Selectors>),
   // FontTechnology()
   /// A parenthesized expression.
   Parens(Box<SupportsCondition<'i>>),
@@ -108,12 +118,30 @@ impl<'i> SupportsCondition<'i> {
 
 impl<'i> Parse<'i> for SupportsCondition<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let options = ParserOptions::default();
+    let selector_parser = SelectorParser {
+      default_namespace: &None,
+      namespace_prefixes: &HashMap::new(),
+      is_nesting_allowed: false,
+      options: &options,
+    };
+    Self::parse_with_selector_parser(input, &selector_parser)
+  }
+}
+
+impl<'i> SupportsCondition<'i> {
+  /// Parses a supports condition, using the given selector parser for `selector()` conditions
+  /// so that the parser options and declared namespaces apply to them.
+  pub(crate) fn parse_with_selector_parser<'t>(
+    input: &mut Parser<'i, 't>,
+    selector_parser: &SelectorParser<'_, '_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if input.try_parse(|input| input.expect_ident_matching("not")).is_ok() {
-      let in_parens = Self::parse_in_parens(input)?;
+      let in_parens = Self::parse_in_parens(input, selector_parser)?;
       return Ok(SupportsCondition::Not(Box::new(in_parens)));
     }
 
-    let in_parens = Self::parse_in_parens(input)?;
+    let in_parens = Self::parse_in_parens(input, selector_parser)?;
     let mut expected_type = None;
     let mut conditions = Vec::new();
 
@@ -137,7 +165,7 @@ impl<'i> Parse<'i> for SupportsCondition<'i> {
           expected_type = Some(found_type);
         }
 
-        Self::parse_in_parens(input)
+        Self::parse_in_parens(input, selector_parser)
       });
 
       if let Ok(condition) = condition {
@@ -156,10 +184,11 @@ impl<'i> Parse<'i> for SupportsCondition<'i> {
       _ => Ok(in_parens),
     }
   }
-}
 
-impl<'i> SupportsCondition<'i> {
-  fn parse_in_parens<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+  fn parse_in_parens<'t>(
+    input: &mut Parser<'i, 't>,
+    selector_parser: &SelectorParser<'_, '_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     input.skip_whitespace();
     let location = input.current_source_location();
     let pos = input.position();
@@ -167,11 +196,14 @@ impl<'i> SupportsCondition<'i> {
       Token::Function(ref f) => {
         match_ignore_ascii_case! { &*f,
           "selector" => {
+            // Unknown or invalid selectors are preserved as is below.
             let res = input.try_parse(|input| {
               input.parse_nested_block(|input| {
-                let pos = input.position();
-                input.expect_no_error_token()?;
-                Ok(SupportsCondition::Selector(input.slice_from(pos).into()))
+                let selectors = SelectorList::parse(selector_parser, input, NestingRequirement::None)?;
+                if selectors.0.len() != 1 {
+                  return Err(input.new_custom_error(ParserError::InvalidValue));
+                }
+                Ok(SupportsCondition::Selector(selectors))
               })
             });
             if res.is_ok() {
@@ -184,7 +216,9 @@ impl<'i> SupportsCondition<'i> {
       Token::ParenthesisBlock => {
         let res = input.try_parse(|input| {
           input.parse_nested_block(|input| {
-            if let Ok(condition) = input.try_parse(SupportsCondition::parse) {
+            if let Ok(condition) =
+              input.try_parse(|input| SupportsCondition::parse_with_selector_parser(input, selector_parser))
+            {
               return Ok(SupportsCondition::Parens(Box::new(condition)));
             }
 
@@ -257,12 +291,82 @@ impl<'i> ToCss for SupportsCondition<'i> {
         dest.write_str(&decl)?;
         dest.write_char(')')
       }
-      SupportsCondition::Selector(sel) => {
+      SupportsCondition::Selector(selectors) => {
         dest.write_str("selector(")?;
-        dest.write_str(sel)?;
+        selectors.to_css_with_context(dest, None)?;
         dest.write_char(')')
       }
       SupportsCondition::Unknown(unknown) => dest.write_str(&unknown),
     }
   }
 }
+
+impl<'i> SupportsCondition<'i> {
+  /// Returns the `selector(:has(a))` condition, which tests for `:has()` support.
+  fn has_selector() -> SupportsCondition<'i> {
+    let a = Component::LocalName(LocalName {
+      name: SelectorIdent("a".into()),
+      lower_name: SelectorIdent("a".into()),
+    });
+    let has = Component::Has(vec![Selector::from_vec2(vec![a])].into_boxed_slice());
+    SupportsCondition::Selector(SelectorList(vec![Selector::from_vec2(vec![has])].into()))
+  }
+
+  fn contains_has_selector(&self) -> bool {
+    match self {
+      SupportsCondition::Selector(selectors) => has_has_selector(selectors),
+      SupportsCondition::Not(_) => false,
+      SupportsCondition::Parens(condition) => condition.contains_has_selector(),
+      SupportsCondition::And(conditions) => conditions.iter().any(|c| c.contains_has_selector()),
+      _ => false,
+    }
+  }
+}
+
+/// Moves the selectors of style rules that use `:has()` into a copy of the rule wrapped in
+/// `@supports selector(:has(a))`, so that the rest of the selector list is not invalidated
+/// in browsers that do not support `:has()`. Rules that are already within such an `@supports`
+/// rule are left unchanged.
+pub(crate) fn wrap_has_rules<'i>(rules: &mut CssRuleList<'i>, in_has_supports: bool) {
+  let mut i = 0;
+  while i < rules.0.len() {
+    match &mut rules.0[i] {
+      CssRule::Style(style) if !in_has_supports && has_has_selector(&style.selectors) => {
+        let (has, rest): (Vec<_>, Vec<_>) = style
+          .selectors
+          .0
+          .iter()
+          .cloned()
+          .partition(|selector| has_has_selector(&SelectorList(vec![selector.clone()].into())));
+
+        let loc = style.loc;
+        let mut wrapped = style.clone();
+        wrapped.selectors = SelectorList(has.into());
+        let supports = CssRule::Supports(SupportsRule {
+          condition: SupportsCondition::has_selector(),
+          rules: CssRuleList(vec![CssRule::Style(wrapped)]),
+          loc,
+        });
+
+        if rest.is_empty() {
+          rules.0[i] = supports;
+        } else {
+          style.selectors = SelectorList(rest.into());
+          i += 1;
+          rules.0.insert(i, supports);
+        }
+      }
+      CssRule::Supports(supports) => {
+        let in_has_supports = in_has_supports || supports.condition.contains_has_selector();
+        wrap_has_rules(&mut supports.rules, in_has_supports)
+      }
+      CssRule::Media(media) => wrap_has_rules(&mut media.rules, in_has_supports),
+      CssRule::Container(container) => wrap_has_rules(&mut container.rules, in_has_supports),
+      CssRule::LayerBlock(layer) => wrap_has_rules(&mut layer.rules, in_has_supports),
+      CssRule::MozDocument(document) => wrap_has_rules(&mut document.rules, in_has_supports),
+      _ => {}
+    }
+
+    i += 1;
+  }
+}
@@ -1,11 +1,12 @@
 use crate::compat::Feature;
-use crate::error::{ParserError, PrinterError};
+use crate::error::{ParserError, PrinterError, PrinterErrorKind};
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
 use crate::rules::{StyleContext, ToCssWithContext};
 use crate::stylesheet::{ParserOptions, PrinterOptions};
 use crate::targets::Browsers;
 use crate::traits::{Parse, ToCss};
+use crate::values::ident::CustomIdent;
 use crate::vendor_prefix::VendorPrefix;
 use crate::{macros::enum_property, values::string::CowArcStr};
 use cssparser::*;
@@ -246,6 +247,7 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
       "first-letter" => FirstLetter,
       "cue" => Cue,
       "cue-region" => CueRegion,
+      "view-transition" => ViewTransition,
       "selection" => Selection(VendorPrefix::None),
       "-moz-selection" => Selection(VendorPrefix::Moz),
       "placeholder" => Placeholder(VendorPrefix::None),
@@ -285,6 +287,10 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
     let pseudo_element = match_ignore_ascii_case! { &name,
       "cue" => CueFunction(Box::new(Selector::parse(self, arguments)?)),
       "cue-region" => CueRegionFunction(Box::new(Selector::parse(self, arguments)?)),
+      "view-transition-group" => ViewTransitionGroup(ViewTransitionPartSelector::parse(arguments)?),
+      "view-transition-image-pair" => ViewTransitionImagePair(ViewTransitionPartSelector::parse(arguments)?),
+      "view-transition-old" => ViewTransitionOld(ViewTransitionPartSelector::parse(arguments)?),
+      "view-transition-new" => ViewTransitionNew(ViewTransitionPartSelector::parse(arguments)?),
       _ => {
         self.options.warn(arguments.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
         CustomFunction(name.into(), TokenList::parse(arguments, &self.options, 0)?)
@@ -326,6 +332,11 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
   fn is_nesting_allowed(&self) -> bool {
     self.is_nesting_allowed
   }
+
+  #[inline]
+  fn is_nesting_suffix_allowed(&self) -> bool {
+    self.is_nesting_allowed && self.options.sass_nesting
+  }
 }
 
 enum_property! {
@@ -702,6 +713,11 @@ pub enum PseudoElement<'i> {
   CueRegion,
   CueFunction(Box<Selector<'i, Selectors>>),
   CueRegionFunction(Box<Selector<'i, Selectors>>),
+  ViewTransition,
+  ViewTransitionGroup(ViewTransitionPartSelector<'i>),
+  ViewTransitionImagePair(ViewTransitionPartSelector<'i>),
+  ViewTransitionOld(ViewTransitionPartSelector<'i>),
+  ViewTransitionNew(ViewTransitionPartSelector<'i>),
   Custom(CowArcStr<'i>),
   CustomFunction(CowArcStr<'i>, TokenList<'i>),
 }
@@ -724,6 +740,86 @@ pub enum WebKitScrollbarPseudoElement {
   Resizer,
 }
 
+/// A part name in the argument of a [view transition pseudo-element](https://drafts.csswg.org/css-view-transitions-1/#pseudo),
+/// e.g. `::view-transition-group()`.
+#[derive(PartialEq, Clone, Debug)]
+pub enum ViewTransitionPartName<'i> {
+  /// The `*` wildcard, which matches any view transition name.
+  All,
+  /// A view transition name.
+  Name(CustomIdent<'i>),
+}
+
+/// The argument of a [view transition pseudo-element](https://drafts.csswg.org/css-view-transitions-2/#pseudo-element-class-additions),
+/// consisting of a part name and an optional list of view transition classes.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ViewTransitionPartSelector<'i> {
+  /// The view transition name to match.
+  pub name: ViewTransitionPartName<'i>,
+  /// The view transition classes that must also match.
+  pub classes: Vec<CustomIdent<'i>>,
+}
+
+impl<'i> Parse<'i> for ViewTransitionPartSelector<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = if input.try_parse(|input| input.expect_delim('*')).is_ok() {
+      Some(ViewTransitionPartName::All)
+    } else {
+      input.try_parse(CustomIdent::parse).ok().map(ViewTransitionPartName::Name)
+    };
+
+    let mut classes = Vec::new();
+    while let Ok(class) = input.try_parse(|input| -> Result<_, ParseError<'i, ParserError<'i>>> {
+      input.expect_delim('.')?;
+      let location = input.current_source_location();
+      match input.next_including_whitespace()? {
+        Token::Ident(class) => Ok(CustomIdent(class.into())),
+        t => Err(location.new_unexpected_token_error(t.clone())),
+      }
+    }) {
+      classes.push(class);
+    }
+
+    if name.is_none() && classes.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(ViewTransitionPartSelector {
+      name: name.unwrap_or(ViewTransitionPartName::All),
+      classes,
+    })
+  }
+}
+
+impl<'i> ToCss for ViewTransitionPartSelector<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: fmt::Write,
+  {
+    match &self.name {
+      // The wildcard is implied when there are classes.
+      ViewTransitionPartName::All if !self.classes.is_empty() => {}
+      ViewTransitionPartName::All => dest.write_char('*')?,
+      // The root element's view transition name is defined by the browser, so it is not scoped.
+      ViewTransitionPartName::Name(name) if name.0.as_ref() == "root" => dest.write_str("root")?,
+      ViewTransitionPartName::Name(name) => {
+        if let Some(css_module) = &mut dest.css_module {
+          css_module.reference(&name.0)
+        }
+        name.to_css(dest)?;
+      }
+    }
+
+    // View transition classes are not scoped, matching the view-transition-class property.
+    for class in &self.classes {
+      dest.write_char('.')?;
+      serialize_identifier(&class.0, dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 impl<'i> cssparser::ToCss for PseudoElement<'i> {
   fn to_css<W>(&self, _: &mut W) -> std::fmt::Result
   where
@@ -783,6 +879,27 @@ impl<'i> ToCss for PseudoElement<'i> {
         selector.to_css_with_context(dest, None)?;
         dest.write_char(')')
       }
+      ViewTransition => dest.write_str("::view-transition"),
+      ViewTransitionGroup(part) => {
+        dest.write_str("::view-transition-group(")?;
+        part.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ViewTransitionImagePair(part) => {
+        dest.write_str("::view-transition-image-pair(")?;
+        part.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ViewTransitionOld(part) => {
+        dest.write_str("::view-transition-old(")?;
+        part.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ViewTransitionNew(part) => {
+        dest.write_str("::view-transition-new(")?;
+        part.to_css(dest)?;
+        dest.write_char(')')
+      }
       Placeholder(prefix) => {
         let vp = write_prefix!(prefix);
         if vp == VendorPrefix::WebKit || vp == VendorPrefix::Ms {
@@ -1183,6 +1300,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for Component<'i, Selectors> {
       NonTSPseudoClass(pseudo) => pseudo.to_css_with_context(dest, context),
       PseudoElement(pseudo) => pseudo.to_css(dest),
       Nesting => serialize_nesting(dest, context, false),
+      NestingSuffix(suffix) => serialize_nesting_suffix(dest, context, suffix),
       Class(ref class) => {
         dest.write_char('.')?;
         dest.write_ident(&class.0)
@@ -1212,13 +1330,13 @@ where
     // Otherwise, use an :is() pseudo class.
     // Type selectors are only allowed at the start of a compound selector,
     // so use :is() if that is not the case.
-    if ctx.rule.selectors.0.len() == 1
-      && (first || (!has_type_selector(&ctx.rule.selectors.0[0]) && is_simple(&ctx.rule.selectors.0[0])))
+    if ctx.selectors.0.len() == 1
+      && (first || (!has_type_selector(&ctx.selectors.0[0]) && is_simple(&ctx.selectors.0[0])))
     {
-      ctx.rule.selectors.0.first().unwrap().to_css_with_context(dest, ctx.parent)
+      ctx.selectors.0.first().unwrap().to_css_with_context(dest, ctx.parent)
     } else {
       dest.write_str(":is(")?;
-      serialize_selector_list(ctx.rule.selectors.0.iter(), dest, ctx.parent, false)?;
+      serialize_selector_list(ctx.selectors.0.iter(), dest, ctx.parent, false)?;
       dest.write_char(')')
     }
   } else {
@@ -1226,6 +1344,60 @@ where
   }
 }
 
+fn serialize_nesting_suffix<'i, W>(
+  dest: &mut Printer<W>,
+  context: Option<&StyleContext<'_, 'i>>,
+  suffix: &SelectorIdent<'i>,
+) -> Result<(), PrinterError>
+where
+  W: fmt::Write,
+{
+  if let Some(ctx) = context {
+    // The suffix is concatenated with the last simple selector of the parent, e.g. `.block` + `__element`.
+    // This is only possible with a single parent selector ending in a class, id, type, or nesting selector.
+    if ctx.selectors.0.len() == 1 {
+      let mut components = to_parse_order(ctx.selectors.0[0].iter_raw_match_order().as_slice());
+      let concatenated = match components.last_mut() {
+        Some(Component::Class(name)) | Some(Component::ID(name)) => {
+          *name = SelectorIdent(format!("{}{}", name.0, suffix.0).into());
+          true
+        }
+        Some(Component::LocalName(local)) => {
+          local.name = SelectorIdent(format!("{}{}", local.name.0, suffix.0).into());
+          local.lower_name = SelectorIdent(format!("{}{}", local.lower_name.0, suffix.0.to_lowercase()).into());
+          true
+        }
+        // If the parent itself ends with a nesting selector, the suffix applies to the grandparent.
+        Some(Component::NestingSuffix(name)) => {
+          *name = SelectorIdent(format!("{}{}", name.0, suffix.0).into());
+          true
+        }
+        Some(component @ Component::Nesting) => {
+          *component = Component::NestingSuffix(suffix.clone());
+          true
+        }
+        _ => false,
+      };
+
+      if concatenated {
+        return Selector::from_vec2(components).to_css_with_context(dest, ctx.parent);
+      }
+    }
+
+    Err(dest.error(
+      PrinterErrorKind::InvalidNestingSuffix,
+      crate::dependencies::Location {
+        line: dest.loc.line + 1,
+        column: dest.loc.column,
+      },
+    ))
+  } else {
+    dest.write_char('&')?;
+    serialize_identifier(&suffix.0, dest)?;
+    Ok(())
+  }
+}
+
 #[inline]
 fn has_type_selector(selector: &parcel_selectors::parser::Selector<Selectors>) -> bool {
   let mut iter = selector.iter_raw_parse_order_from(0);
@@ -1273,6 +1445,47 @@ where
 {
   let mut first = true;
   for selector in iter {
+    // When compiling nesting, a selector containing a single nesting selector can be duplicated for
+    // each parent selector rather than wrapping the parent selectors in :is(), as long as they all
+    // have the same specificity. Since the duplication compounds with each level of nesting, :is()
+    // is used instead once the output would grow too large, unless it is unsupported by the targets.
+    if let Some(ctx) = context {
+      // Nesting suffixes must always be expanded, as they cannot be concatenated with an :is() selector.
+      if has_single_nesting(selector) && has_nesting_suffix(selector) {
+        for_each_suffix_context(ctx, &mut |ctx| {
+          if !first {
+            dest.delim(',', false)?;
+          }
+          first = false;
+          serialize_selector(selector, dest, Some(ctx), is_relative)
+        })?;
+        continue;
+      }
+
+      if ctx.selectors.0.len() > 1
+        && has_single_nesting(selector)
+        && has_equal_specificity(ctx.selectors)
+        && (expanded_len(ctx) <= MAX_NESTING_EXPANSION
+          || dest
+            .targets
+            .map_or(false, |targets| !Feature::CssMatchesPseudo.is_compatible(targets)))
+      {
+        for parent in ctx.selectors.0.iter() {
+          if !first {
+            dest.delim(',', false)?;
+          }
+          first = false;
+          let parent = SelectorList(smallvec::smallvec![parent.clone()]);
+          let ctx = StyleContext {
+            selectors: &parent,
+            parent: ctx.parent,
+          };
+          serialize_selector(selector, dest, Some(&ctx), is_relative)?;
+        }
+        continue;
+      }
+    }
+
     if !first {
       dest.delim(',', false)?;
     }
@@ -1282,6 +1495,94 @@ where
   Ok(())
 }
 
+/// Calls the given function with a context for each selector that a nesting suffix may be concatenated
+/// with. The suffix is concatenated with the parent selector, or with the grandparent selector when the
+/// parent itself ends with a nesting selector, e.g. `.a, .b { & { &__x {} } }` -> `.a__x, .b__x`. Each
+/// parent with multiple selectors along the way is expanded so that every context has a single selector.
+fn for_each_suffix_context<'i>(
+  ctx: &StyleContext<'_, 'i>,
+  f: &mut dyn FnMut(&StyleContext<'_, 'i>) -> Result<(), PrinterError>,
+) -> Result<(), PrinterError> {
+  for selector in ctx.selectors.0.iter() {
+    let selectors = SelectorList(smallvec::smallvec![selector.clone()]);
+    // Components within the last compound selector are stored first, in parse order.
+    let ends_with_nesting = matches!(
+      selector.iter_raw_match_order().take_while(|c| !c.is_combinator()).last(),
+      Some(Component::Nesting) | Some(Component::NestingSuffix(..))
+    );
+    match ctx.parent {
+      Some(parent) if ends_with_nesting => for_each_suffix_context(parent, &mut |parent| {
+        f(&StyleContext {
+          selectors: &selectors,
+          parent: Some(parent),
+        })
+      })?,
+      parent => f(&StyleContext {
+        selectors: &selectors,
+        parent,
+      })?,
+    }
+  }
+
+  Ok(())
+}
+
+/// The maximum number of selectors that a nested selector may be expanded into
+/// before the parent selectors are wrapped in :is() instead.
+const MAX_NESTING_EXPANSION: usize = 8;
+
+/// Returns the number of selectors produced by expanding a nested selector for each
+/// of its parent selectors, including those of the ancestor style rules.
+fn expanded_len(ctx: &StyleContext) -> usize {
+  ctx.selectors.0.len() * ctx.parent.map_or(1, expanded_len)
+}
+
+/// Returns whether the selector contains exactly one nesting selector, which is not within
+/// the arguments of a pseudo class.
+fn has_single_nesting(selector: &Selector<Selectors>) -> bool {
+  let mut count = 0;
+  for component in selector.iter_raw_match_order() {
+    if matches!(component, Component::Nesting | Component::NestingSuffix(..)) {
+      count += 1;
+    } else if component_has_nesting(component) {
+      return false;
+    }
+  }
+
+  count == 1
+}
+
+fn component_has_nesting(component: &Component<Selectors>) -> bool {
+  fn has_nesting(selector: &Selector<Selectors>) -> bool {
+    selector.iter_raw_match_order().any(component_has_nesting)
+  }
+
+  match component {
+    Component::Nesting | Component::NestingSuffix(..) => true,
+    Component::Is(selectors)
+    | Component::Where(selectors)
+    | Component::Negation(selectors)
+    | Component::Has(selectors)
+    | Component::Any(_, selectors) => selectors.iter().any(has_nesting),
+    Component::Slotted(selector) | Component::Host(Some(selector)) => has_nesting(selector),
+    Component::NonTSPseudoClass(PseudoClass::Local(selector))
+    | Component::NonTSPseudoClass(PseudoClass::Global(selector)) => has_nesting(selector),
+    _ => false,
+  }
+}
+
+/// Returns whether the selector contains a Sass-style nesting suffix, e.g. `&__element`.
+pub(crate) fn has_nesting_suffix(selector: &Selector<Selectors>) -> bool {
+  selector
+    .iter_raw_match_order()
+    .any(|component| matches!(component, Component::NestingSuffix(..)))
+}
+
+fn has_equal_specificity(selectors: &SelectorList<Selectors>) -> bool {
+  let specificity = selectors.0[0].specificity();
+  selectors.0.iter().all(|selector| selector.specificity() == specificity)
+}
+
 fn serialize_negation<'a, 'i: 'a, I, W>(
   iter: I,
   dest: &mut Printer<W>,
@@ -1384,6 +1685,7 @@ pub fn is_compatible(selectors: &SelectorList<Selectors>, targets: Option<Browse
         | Component::Root => Feature::CssSel3,
 
         Component::Is(_) | Component::Nesting => Feature::CssMatchesPseudo,
+        Component::NestingSuffix(..) => continue,
         Component::Any(..) => Feature::AnyPseudo,
         Component::Has(_) => Feature::CssHas,
 
@@ -1458,6 +1760,11 @@ pub fn is_compatible(selectors: &SelectorList<Selectors>, targets: Option<Browse
           PseudoElement::Backdrop(prefix) if *prefix == VendorPrefix::None => Feature::Dialog,
           PseudoElement::Cue => Feature::Cue,
           PseudoElement::CueFunction(_) => Feature::CueFunction,
+          PseudoElement::ViewTransition
+          | PseudoElement::ViewTransitionGroup(_)
+          | PseudoElement::ViewTransitionImagePair(_)
+          | PseudoElement::ViewTransitionOld(_)
+          | PseudoElement::ViewTransitionNew(_) => Feature::ViewTransitions,
           PseudoElement::Custom(_) | _ => return false,
         },
 
@@ -1511,15 +1818,19 @@ pub fn is_equivalent<'i>(selectors: &SelectorList<'i, Selectors>, other: &Select
 
 /// Returns the vendor prefix (if any) used in the given selector list.
 /// If multiple vendor prefixes are seen, this is invalid, and an empty result is returned.
+/// Every compound selector is checked, not only the rightmost one, so that pseudo classes
+/// such as `:is()` and `:lang()` are downleveled in ancestor compound selectors too.
 pub fn get_prefix(selectors: &SelectorList<Selectors>) -> VendorPrefix {
   let mut prefix = VendorPrefix::empty();
   for selector in &selectors.0 {
-    for component in selector.iter() {
+    for component in selector.iter_raw_match_order() {
       let p = match component {
         // Return none rather than empty for these so that we call downlevel_selectors.
         Component::NonTSPseudoClass(PseudoClass::Lang(..))
         | Component::NonTSPseudoClass(PseudoClass::Dir(..))
-        | Component::Is(..) => VendorPrefix::None,
+        | Component::Is(..)
+        | Component::Where(..)
+        | Component::Negation(..) => VendorPrefix::None,
         Component::NonTSPseudoClass(pc) => pc.get_prefix(),
         Component::PseudoElement(pe) => pe.get_prefix(),
         _ => VendorPrefix::empty(),
@@ -1543,11 +1854,376 @@ const RTL_LANGS: &[&str] = &[
   "ur", "yi",
 ];
 
+/// Minifies the given selectors. Duplicate selectors are removed, `:is()` with a single
+/// compound selector argument is unwrapped, and adjacent `:not()` pseudo classes are
+/// combined into a single selector list where supported. Specificity is preserved unless
+/// `allow_specificity_changes` is true.
+pub(crate) fn minify_selectors(
+  selectors: &mut SelectorList<Selectors>,
+  targets: Option<Browsers>,
+  allow_specificity_changes: bool,
+) {
+  for selector in &mut selectors.0 {
+    minify_selector(selector, targets, allow_specificity_changes);
+  }
+
+  let mut i = 0;
+  while i < selectors.0.len() {
+    if selectors.0[..i].contains(&selectors.0[i]) {
+      selectors.0.remove(i);
+    } else {
+      i += 1;
+    }
+  }
+}
+
+fn minify_selector_list<'i>(
+  selectors: &mut Box<[Selector<'i, Selectors>]>,
+  targets: Option<Browsers>,
+  allow_specificity_changes: bool,
+) {
+  let mut list = SelectorList(std::mem::take(selectors).into_vec().into());
+  minify_selectors(&mut list, targets, allow_specificity_changes);
+  *selectors = list.0.into_vec().into_boxed_slice();
+}
+
+fn minify_selector<'i>(
+  selector: &mut Selector<'i, Selectors>,
+  targets: Option<Browsers>,
+  allow_specificity_changes: bool,
+) {
+  let components = selector.raw_match_order_mut();
+  for component in components.iter_mut() {
+    match component {
+      Component::Is(selectors)
+      | Component::Where(selectors)
+      | Component::Negation(selectors)
+      | Component::Any(_, selectors)
+      | Component::Has(selectors) => minify_selector_list(selectors, targets, allow_specificity_changes),
+      _ => {}
+    }
+  }
+
+  // Compound selectors are stored right to left, but the components
+  // within each compound are in parse order.
+  let mut start = 0;
+  while start < components.len() {
+    let mut end = start;
+    while end < components.len() && !components[end].is_combinator() {
+      end += 1;
+    }
+
+    end = unwrap_is(components, start, end);
+    end = merge_negations(components, start, end, targets, allow_specificity_changes);
+    start = end + 1;
+  }
+}
+
+/// Unwraps :is() pseudo classes in the compound selector between `start` and `end`
+/// that contain a single compound selector, e.g. `.a:is(.b)` -> `.a.b`. This does
+/// not affect specificity. Returns the new end of the compound selector.
+fn unwrap_is<'i>(components: &mut Vec<Component<'i, Selectors>>, start: usize, mut end: usize) -> usize {
+  let mut i = start;
+  while i < end {
+    let inner = match &components[i] {
+      Component::Is(selectors) if selectors.len() == 1 => &selectors[0],
+      _ => {
+        i += 1;
+        continue;
+      }
+    };
+
+    let compound = &components[start..end];
+    let has_type = compound.iter().any(|c| is_type_selector(Some(c)) || is_namespace(Some(c)));
+    let is_unwrappable = inner
+      .iter_raw_match_order()
+      .all(|c| !c.is_combinator() && !is_namespace(Some(c)) && (!has_type || !is_type_selector(Some(c))));
+    if !is_unwrappable {
+      i += 1;
+      continue;
+    }
+
+    let mut inner: Vec<_> = inner.iter_raw_match_order().cloned().collect();
+    let ty = if is_type_selector(inner.first()) {
+      Some(inner.remove(0))
+    } else {
+      None
+    };
+
+    let len = inner.len();
+    components.splice(i..i + 1, inner);
+    end = end + len - 1;
+    i += len;
+
+    // Type selectors must come first in a compound selector (after a leading nesting selector).
+    if let Some(ty) = ty {
+      let index = if matches!(components[start], Component::Nesting) {
+        start + 1
+      } else {
+        start
+      };
+      components.insert(index, ty);
+      end += 1;
+      i += 1;
+    }
+  }
+
+  end
+}
+
+/// Combines adjacent :not() pseudo classes in the compound selector between `start` and `end`
+/// into a single selector list, e.g. `:not(.a):not(*)` -> `:not(.a, *)`, when supported by
+/// the targets. Since the specificity of :not() is that of its most specific argument, this is
+/// only done when it does not change the specificity of the selector, unless
+/// `allow_specificity_changes` is true. Returns the new end of the compound selector.
+fn merge_negations<'i>(
+  components: &mut Vec<Component<'i, Selectors>>,
+  start: usize,
+  mut end: usize,
+  targets: Option<Browsers>,
+  allow_specificity_changes: bool,
+) -> usize {
+  if let Some(targets) = targets {
+    if !Feature::CssNotSelList.is_compatible(targets) {
+      return end;
+    }
+  }
+
+  let specificity =
+    |selectors: &[Selector<Selectors>]| selectors.iter().map(|s| s.specificity()).max().unwrap_or(0);
+
+  let mut i = start;
+  while i + 1 < end {
+    if let (Component::Negation(a), Component::Negation(b)) = (&components[i], &components[i + 1]) {
+      let (a_specificity, b_specificity) = (specificity(a), specificity(b));
+      if allow_specificity_changes || a_specificity == 0 || b_specificity == 0 {
+        let mut selectors = a.to_vec();
+        for selector in b.iter() {
+          if !selectors.contains(selector) {
+            selectors.push(selector.clone());
+          }
+        }
+        components[i] = Component::Negation(selectors.into_boxed_slice());
+        components.remove(i + 1);
+        end -= 1;
+        continue;
+      }
+    }
+
+    i += 1;
+  }
+
+  end
+}
+
+/// Returns the number of id selectors that contribute to the specificity of the given selectors.
+pub(crate) fn max_id_specificity(selectors: &SelectorList<Selectors>) -> u32 {
+  selectors.0.iter().map(|s| s.specificity() >> 20).max().unwrap_or(0)
+}
+
+/// Increases the specificity of each selector in the list by the given number of id selectors,
+/// without affecting what it matches. This is done by appending `:not(#\#)` to the last compound
+/// selector (before any pseudo element), which can never fail to match since `#` is not a valid id.
+pub(crate) fn add_id_specificity(selectors: &mut SelectorList<Selectors>, count: u32) {
+  if count == 0 {
+    return;
+  }
+
+  for selector in &mut selectors.0 {
+    let mut components: Vec<_> = selector.iter_raw_match_order().cloned().collect();
+
+    // Components are stored in match order, so the last compound selector comes first.
+    let is_combinator = |c: &Component<Selectors>| c.is_combinator();
+    let mut index = components.iter().position(is_combinator).unwrap_or(components.len());
+    if let Some(Component::Combinator(Combinator::PseudoElement)) = components.get(index) {
+      index = components[index + 1..]
+        .iter()
+        .position(is_combinator)
+        .map_or(components.len(), |i| index + 1 + i);
+    }
+
+    let not = Component::Negation(
+      vec![Selector::from_vec2(vec![Component::ID(SelectorIdent("#".into()))])].into_boxed_slice(),
+    );
+    components.splice(index..index, std::iter::repeat(not).take(count as usize));
+    *selector = Selector::from_vec2(to_parse_order(&components));
+  }
+}
+
+/// Returns a copy of the selector that matches the given pseudo element of the originally
+/// matched element. The selector must not already contain a pseudo element.
+pub(crate) fn with_pseudo_element<'i>(
+  selector: &Selector<'i, Selectors>,
+  pseudo_element: PseudoElement<'i>,
+) -> Selector<'i, Selectors> {
+  let components: Vec<_> = selector.iter_raw_match_order().cloned().collect();
+  let mut components = to_parse_order(&components);
+  components.push(Component::Combinator(Combinator::PseudoElement));
+  components.push(Component::PseudoElement(pseudo_element));
+  Selector::from_vec2(components)
+}
+
+/// Converts components in match order to parse order. Compound selectors are stored
+/// right to left, but the components within each compound are in parse order.
+fn to_parse_order<'i>(components: &[Component<'i, Selectors>]) -> Vec<Component<'i, Selectors>> {
+  let mut parse_order = Vec::with_capacity(components.len());
+  let mut compounds = components.split(|c| c.is_combinator()).rev();
+  let mut combinators = components.iter().filter(|c| c.is_combinator()).rev();
+  if let Some(compound) = compounds.next() {
+    parse_order.extend(compound.iter().cloned());
+  }
+  for compound in compounds {
+    parse_order.extend(combinators.next().cloned());
+    parse_order.extend(compound.iter().cloned());
+  }
+  parse_order
+}
+
+/// Expands `:is()` pseudo classes into a list of selectors when they are not supported by
+/// the targets, e.g. `:is(.a, .b) :is(.c, .d)` -> `.a .c, .a .d, .b .c, .b .d`.
+///
+/// An `:is()` is only expanded when all of its arguments have the same specificity, and a
+/// `:where()` is never expanded, unless `allow_specificity_changes` is true. Simple arguments
+/// are left for `downlevel_selectors` to prefix as `:-webkit-any()` where that is supported.
+/// Selectors are left unchanged if the expansion would result in more than `max_expansion`
+/// selectors.
+pub(crate) fn expand_is_selectors(
+  selectors: &mut SelectorList<Selectors>,
+  targets: Browsers,
+  max_expansion: usize,
+  allow_specificity_changes: bool,
+) {
+  if Feature::CssMatchesPseudo.is_compatible(targets) {
+    return;
+  }
+
+  let can_prefix = Feature::AnyPseudo.is_compatible(targets);
+  let mut expanded = Vec::with_capacity(selectors.0.len());
+  for selector in selectors.0.iter() {
+    let components = to_parse_order(&selector.iter_raw_match_order().cloned().collect::<Vec<_>>());
+    let mut result = Vec::new();
+    if expand_is(
+      components,
+      can_prefix,
+      max_expansion,
+      allow_specificity_changes,
+      &mut result,
+    ) {
+      for selector in result {
+        if !expanded.contains(&selector) {
+          expanded.push(selector);
+        }
+      }
+    } else {
+      expanded.push(selector.clone());
+    }
+  }
+
+  if expanded.len() <= max_expansion {
+    selectors.0 = expanded.into();
+  }
+}
+
+/// Expands the first `:is()` or `:where()` in the given parse order components, and recursively
+/// expands the results into `dest`. Returns false if the selector could not be fully expanded.
+fn expand_is<'i>(
+  components: Vec<Component<'i, Selectors>>,
+  can_prefix: bool,
+  max_expansion: usize,
+  allow_specificity_changes: bool,
+  dest: &mut Vec<Selector<'i, Selectors>>,
+) -> bool {
+  let index = components.iter().position(|component| match component {
+    Component::Is(selectors) => {
+      let has_combinator = selectors.iter().any(|selector| selector.has_combinator());
+      let specificity = selectors[0].specificity();
+      (has_combinator || !can_prefix)
+        && (allow_specificity_changes || selectors.iter().all(|selector| selector.specificity() == specificity))
+    }
+    Component::Where(..) => allow_specificity_changes,
+    _ => false,
+  });
+
+  let index = match index {
+    Some(index) => index,
+    None => {
+      dest.push(Selector::from_vec2(components));
+      return dest.len() <= max_expansion;
+    }
+  };
+
+  let arguments = match &components[index] {
+    Component::Is(selectors) | Component::Where(selectors) => selectors.clone(),
+    _ => unreachable!(),
+  };
+
+  // The compound selector containing the :is().
+  let start = components[..index].iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+  let end = components[index..]
+    .iter()
+    .position(|c| c.is_combinator())
+    .map_or(components.len(), |i| index + i);
+  let has_type = components[start..end]
+    .iter()
+    .any(|c| is_type_selector(Some(c)) || is_namespace(Some(c)));
+
+  for argument in arguments.iter() {
+    let mut inner = to_parse_order(&argument.iter_raw_match_order().cloned().collect::<Vec<_>>());
+
+    // Arguments of :is() are matched independently of the rest of the selector, so complex
+    // arguments can only be inlined into the first compound selector, e.g. `:is(.a .b) .c`.
+    // Since a compound selector can only contain a single type selector, bail out on conflicts.
+    let compound_start = inner.iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+    if (compound_start > 0 && start > 0)
+      || inner
+        .iter()
+        .any(|c| matches!(c, Component::Combinator(Combinator::PseudoElement)))
+      || inner[compound_start..].iter().any(|c| is_namespace(Some(c)))
+      || (has_type && is_type_selector(inner.get(compound_start)))
+    {
+      return false;
+    }
+
+    let mut expanded = Vec::with_capacity(components.len() + inner.len());
+    expanded.extend(components[..start].iter().cloned());
+    let compound = inner.split_off(compound_start);
+    expanded.extend(inner);
+
+    // Type selectors must come first in a compound selector (after a leading nesting selector).
+    let (ty, compound) = match compound.split_first() {
+      Some((ty, rest)) if is_type_selector(Some(ty)) => (Some(ty.clone()), rest.to_vec()),
+      _ => (None, compound),
+    };
+    let mut outer = components[start..index].to_vec();
+    if let Some(ty) = ty {
+      let i = if matches!(outer.first(), Some(Component::Nesting)) {
+        1
+      } else {
+        0
+      };
+      outer.insert(i, ty);
+    }
+    expanded.extend(outer);
+    expanded.extend(compound);
+    expanded.extend(components[index + 1..].iter().cloned());
+
+    if !expand_is(expanded, can_prefix, max_expansion, allow_specificity_changes, dest) {
+      return false;
+    }
+  }
+
+  true
+}
+
 /// Downlevels the given selectors to be compatible with the given browser targets.
 /// Returns the necessary vendor prefixes.
 pub fn downlevel_selectors(selectors: &mut SelectorList<Selectors>, targets: Browsers) -> VendorPrefix {
   let mut necessary_prefixes = VendorPrefix::empty();
   for selector in &mut selectors.0 {
+    if !Feature::CssNotSelList.is_compatible(targets) {
+      downlevel_negations(selector);
+    }
+
     for component in selector.iter_mut_raw_match_order() {
       necessary_prefixes |= downlevel_component(component, targets);
     }
@@ -1597,6 +2273,101 @@ fn downlevel_component<'i>(component: &mut Component<'i, Selectors>, targets: Br
   }
 }
 
+/// Splits `:not()` pseudo classes with a list of compound selectors into a sequence
+/// of `:not()` pseudo classes, e.g. `:not(.a, .b)` -> `:not(.a):not(.b)`. Lists
+/// containing complex selectors cannot be split, and are left unchanged.
+fn downlevel_negations<'i>(selector: &mut Selector<'i, Selectors>) {
+  for component in selector.iter_mut_raw_match_order() {
+    match component {
+      Component::Is(selectors)
+      | Component::Where(selectors)
+      | Component::Negation(selectors)
+      | Component::Any(_, selectors)
+      | Component::Has(selectors) => {
+        for selector in selectors.iter_mut() {
+          downlevel_negations(selector);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  let is_splittable = |component: &Component<Selectors>| match component {
+    Component::Negation(selectors) => selectors.len() > 1 && selectors.iter().all(|s| !s.has_combinator()),
+    _ => false,
+  };
+
+  if !selector.iter_raw_match_order().any(is_splittable) {
+    return;
+  }
+
+  let mut components = Vec::with_capacity(selector.len());
+  for component in selector.iter_raw_match_order() {
+    match component {
+      Component::Negation(selectors) if is_splittable(component) => {
+        for selector in selectors.iter() {
+          components.push(Component::Negation(vec![selector.clone()].into_boxed_slice()));
+        }
+      }
+      _ => components.push(component.clone()),
+    }
+  }
+
+  *selector = Selector::from_vec2(to_parse_order(&components));
+}
+
+/// Returns whether any of the given selectors contain a `:has()` pseudo class.
+pub(crate) fn has_has_selector(selectors: &SelectorList<Selectors>) -> bool {
+  fn has(selector: &Selector<Selectors>) -> bool {
+    selector.iter_raw_match_order().any(|component| match component {
+      Component::Has(..) => true,
+      Component::Is(selectors)
+      | Component::Where(selectors)
+      | Component::Negation(selectors)
+      | Component::Any(_, selectors) => selectors.iter().any(has),
+      _ => false,
+    })
+  }
+
+  selectors.0.iter().any(has)
+}
+
+/// Returns whether any of the given selectors contain a view transition pseudo-element.
+pub(crate) fn has_view_transition_pseudo_element(selectors: &SelectorList<Selectors>) -> bool {
+  selectors.0.iter().any(|selector| {
+    selector.iter_raw_match_order().any(|component| {
+      matches!(
+        component,
+        Component::PseudoElement(
+          PseudoElement::ViewTransition
+            | PseudoElement::ViewTransitionGroup(_)
+            | PseudoElement::ViewTransitionImagePair(_)
+            | PseudoElement::ViewTransitionOld(_)
+            | PseudoElement::ViewTransitionNew(_)
+        )
+      )
+    })
+  })
+}
+
+/// Returns whether any of the given selectors contain a `:not()` pseudo class with a
+/// list of selectors, which could not be downleveled for the targets.
+pub(crate) fn has_negation_list(selectors: &SelectorList<Selectors>) -> bool {
+  fn has_list(selector: &Selector<Selectors>) -> bool {
+    selector.iter_raw_match_order().any(|component| match component {
+      Component::Negation(selectors) if selectors.len() > 1 => true,
+      Component::Is(selectors)
+      | Component::Where(selectors)
+      | Component::Negation(selectors)
+      | Component::Any(_, selectors)
+      | Component::Has(selectors) => selectors.iter().any(has_list),
+      _ => false,
+    })
+  }
+
+  selectors.0.iter().any(has_list)
+}
+
 fn lang_list_to_selectors<'i>(langs: &Vec<CowArcStr<'i>>) -> Box<[Selector<'i, Selectors>]> {
   langs
     .iter()
@@ -1649,7 +2420,7 @@ pub fn is_unused(
             return true;
           }
         }
-        Component::Nesting => {
+        Component::Nesting | Component::NestingSuffix(..) => {
           if parent_is_unused {
             return true;
           }
@@ -9,14 +9,17 @@ use crate::css_modules::{CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::Dependency;
 use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
-use crate::parser::TopLevelRuleParser;
+use crate::parser::{parse_comments, TopLevelRuleParser};
 use crate::printer::Printer;
+use crate::rules::layer::flatten_layers;
+use crate::rules::supports::wrap_has_rules;
 use crate::rules::{CssRule, CssRuleList, MinifyContext};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
 use cssparser::{Parser, ParserInput, RuleListParser};
 use parcel_sourcemap::SourceMap;
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
 
 pub use crate::parser::ParserOptions;
 pub use crate::printer::PrinterOptions;
@@ -74,13 +77,122 @@ pub struct StyleSheet<'i, 'o> {
 
 /// Options for the `minify` function of a [StyleSheet](StyleSheet)
 /// or [StyleAttribute](StyleAttribute).
-#[derive(Default)]
+///
+/// Each transform can be toggled individually, e.g. to disable one that breaks a legacy hack.
+/// The [safe](MinifyOptions::safe) and [aggressive](MinifyOptions::aggressive) presets are also
+/// available in addition to the default, which enables all transforms that preserve fallbacks.
+/// These toggles and presets are only available from the Rust API. The Node, C, and command line
+/// interfaces always use the default options.
+///
+/// # Example
+///
+/// ```
+/// use parcel_css::stylesheet::MinifyOptions;
+///
+/// let options = MinifyOptions {
+///   merge_longhands: false,
+///   ..MinifyOptions::default()
+/// };
+/// ```
+#[derive(Debug)]
 pub struct MinifyOptions {
   /// Browser targets to compile the CSS for.
   pub targets: Option<Browsers>,
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// Whether to merge adjacent style rules with the same selectors or declarations,
+  /// and adjacent at rules such as `@media` with the same condition.
+  pub merge_rules: bool,
+  /// Whether to combine longhand properties into shorthands, and remove
+  /// repeated declarations of the same property.
+  pub merge_longhands: bool,
+  /// Whether to remove declarations that are overridden by a later declaration
+  /// in the same block. Declarations that may act as a fallback for browsers that
+  /// do not support the later value are kept unless `remove_fallbacks` is also enabled.
+  pub remove_overridden: bool,
+  /// Whether to remove overridden declarations even when they may act as a fallback,
+  /// e.g. `display: flex` followed by `display: grid`.
+  pub remove_fallbacks: bool,
+  /// Whether to remove rules without any declarations or nested rules.
+  pub remove_empty_rules: bool,
+  /// Whether to remove duplicate selectors in a selector list, and simplify
+  /// selectors such as `:is()` with a single argument.
+  pub remove_duplicate_selectors: bool,
+  /// Whether to remove vendor prefixed declarations that are not needed
+  /// by the browser targets.
+  pub remove_prefixes: bool,
+  /// The maximum number of selectors that a selector list may be expanded to when compiling
+  /// `:is()` and `:where()` for browser targets that do not support them. Selector lists that
+  /// would exceed this limit are left unchanged.
+  pub max_selector_expansion: usize,
+  /// Whether transforms that may change the specificity of a selector are allowed. This enables
+  /// expanding `:where()`, and `:is()` with arguments of differing specificity, for browser
+  /// targets that do not support them.
+  pub allow_specificity_changes: bool,
+  /// Whether to move style rules that use `:has()` into an `@supports selector(:has(a))` rule
+  /// when it is not supported by all browser targets, so that other selectors in the same list
+  /// still apply in browsers without support. Otherwise, a warning is emitted.
+  pub wrap_unsupported_has: bool,
+  /// Whether to emit a fallback for `aspect-ratio` when it is not supported by all browser targets.
+  /// This uses the "padding-top hack": a floated `::before` pseudo element with a percentage
+  /// `padding-top` derived from the ratio sizes the box, and an `::after` pseudo element clears the
+  /// float. The pseudo elements are wrapped in `@supports not (aspect-ratio: 1/1)`. This only works
+  /// for boxes sized by their width. In browsers without `aspect-ratio` support, the generated rules
+  /// override the `content`, `float`, `clear`, `display`, and `padding-top` of any author `::before`
+  /// and `::after` rules for the same element, so it must be opted into.
+  pub aspect_ratio_fallback: bool,
+  /// A list that will be appended to when a warning occurs, e.g. when a selector
+  /// cannot be compiled for the browser targets.
+  pub warnings: Option<Arc<RwLock<Vec<Error<MinifyErrorKind>>>>>,
+}
+
+impl Default for MinifyOptions {
+  fn default() -> Self {
+    MinifyOptions {
+      targets: None,
+      unused_symbols: HashSet::new(),
+      merge_rules: true,
+      merge_longhands: true,
+      remove_overridden: true,
+      remove_fallbacks: false,
+      remove_empty_rules: true,
+      remove_duplicate_selectors: true,
+      remove_prefixes: true,
+      max_selector_expansion: 64,
+      allow_specificity_changes: false,
+      wrap_unsupported_has: false,
+      aspect_ratio_fallback: false,
+      warnings: None,
+    }
+  }
+}
+
+impl MinifyOptions {
+  /// Returns options that only enable transforms that preserve the structure of the
+  /// style sheet, i.e. rules and declarations are not merged, reordered, or removed.
+  /// Syntax is still lowered for the browser targets.
+  pub fn safe() -> Self {
+    MinifyOptions {
+      merge_rules: false,
+      merge_longhands: false,
+      remove_overridden: false,
+      remove_fallbacks: false,
+      remove_empty_rules: false,
+      remove_duplicate_selectors: false,
+      remove_prefixes: false,
+      ..MinifyOptions::default()
+    }
+  }
+
+  /// Returns options that enable all transforms, including removing overridden
+  /// declarations that may act as fallbacks for older browsers.
+  pub fn aggressive() -> Self {
+    MinifyOptions {
+      remove_fallbacks: true,
+      ..MinifyOptions::default()
+    }
+  }
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -115,10 +227,16 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
   pub fn parse(code: &'i str, options: ParserOptions<'o, 'i>) -> Result<Self, Error<ParserError<'i>>> {
     let mut input = ParserInput::new(&code);
     let mut parser = Parser::new(&mut input);
-    let rule_list_parser = RuleListParser::new_for_stylesheet(&mut parser, TopLevelRuleParser::new(&options));
+    let mut rule_list_parser = RuleListParser::new_for_stylesheet(&mut parser, TopLevelRuleParser::new(&options));
 
     let mut rules = vec![];
-    for rule in rule_list_parser {
+    loop {
+      parse_comments(rule_list_parser.input, options.source_index, &mut rules);
+      let rule = match rule_list_parser.next() {
+        Some(rule) => rule,
+        None => break,
+      };
+
       let rule = match rule {
         Ok((_, CssRule::Ignored)) => continue,
         Ok((_, rule)) => rule,
@@ -151,7 +269,7 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
 
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
-    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    let mut context = PropertyHandlerContext::new(&options);
     let mut handler = DeclarationHandler::new(options.targets);
     let mut important_handler = DeclarationHandler::new(options.targets);
 
@@ -172,13 +290,27 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       None
     };
 
+    // Cascade layers are flattened up front, since the layer order depends on every @layer rule
+    // in the style sheet, including those generated by the bundler for `@import layer()`.
+    if options.targets.is_some() && !Feature::CascadeLayers.is_compatible(options.targets.unwrap()) {
+      flatten_layers(&mut self.rules);
+    }
+
+    if options.wrap_unsupported_has
+      && options.targets.is_some()
+      && !Feature::CssHas.is_compatible(options.targets.unwrap())
+    {
+      wrap_has_rules(&mut self.rules, false);
+    }
+
     let mut ctx = MinifyContext {
       targets: &options.targets,
       handler: &mut handler,
       important_handler: &mut important_handler,
       handler_context: &mut context,
-      unused_symbols: &options.unused_symbols,
+      options: &options,
       custom_media,
+      warnings: Vec::new(),
     };
 
     self.rules.minify(&mut ctx, false).map_err(|e| Error {
@@ -189,6 +321,20 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       )),
     })?;
 
+    if let Some(warnings) = &options.warnings {
+      if let Ok(mut warnings) = warnings.write() {
+        for warning in ctx.warnings {
+          warnings.push(Error {
+            kind: warning.kind,
+            loc: Some(ErrorLocation::new(
+              warning.loc,
+              self.sources[warning.loc.source_index as usize].clone(),
+            )),
+          });
+        }
+      }
+    }
+
     Ok(())
   }
 
@@ -284,7 +430,7 @@ impl<'i> StyleAttribute<'i> {
 
   /// Minify and transform the style attribute for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) {
-    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    let mut context = PropertyHandlerContext::new(&options);
     let mut handler = DeclarationHandler::new(options.targets);
     let mut important_handler = DeclarationHandler::new(options.targets);
     context.context = DeclarationContext::StyleAttribute;
@@ -9,6 +9,7 @@ use crate::stylesheet::{ParserOptions, PrinterOptions};
 use crate::targets::Browsers;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// Trait for things that can be parsed from CSS syntax.
 pub trait Parse<'i>: Sized {
@@ -103,6 +104,19 @@ pub(crate) trait FallbackValues: Sized {
   fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self>;
 }
 
+/// Trait for values that may use features that are not supported by all browsers.
+pub(crate) trait IsCompatible {
+  /// Returns whether the value is supported by all of the given browser targets.
+  /// Without targets, values that use a feature that needs a fallback in some browsers are not compatible.
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool;
+}
+
+impl<T: IsCompatible> IsCompatible for SmallVec<[T; 1]> {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    self.iter().all(|v| v.is_compatible(targets))
+  }
+}
+
 /// Trait for shorthand properties.
 pub(crate) trait Shorthand<'i>: Sized {
   /// Returns a shorthand from the longhand properties defined in the given declaration block.
@@ -1,7 +1,7 @@
 //! CSS angle values.
 
 use super::calc::Calc;
-use super::length::serialize_dimension;
+use super::length::{serialize_dimension, serialize_shortest_dimension};
 use super::number::CSSNumber;
 use super::percentage::DimensionPercentage;
 use crate::error::{ParserError, PrinterError};
@@ -66,6 +66,21 @@ impl ToCss for Angle {
   where
     W: std::fmt::Write,
   {
+    if dest.minify && dest.convert_units {
+      let unit = match self {
+        Angle::Deg(_) => "deg",
+        Angle::Grad(_) => "grad",
+        Angle::Rad(_) => "rad",
+        Angle::Turn(_) => "turn",
+      };
+      return serialize_shortest_dimension(
+        self.to_degrees(),
+        unit,
+        &[("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / PI), ("turn", 360.0)],
+        dest,
+      );
+    }
+
     let (value, unit) = match self {
       Angle::Deg(val) => (*val, "deg"),
       Angle::Grad(val) => (*val, "grad"),
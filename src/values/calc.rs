@@ -4,8 +4,9 @@ use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
+use crate::targets::Browsers;
 use crate::traits::private::AddInternal;
-use crate::traits::{Parse, Sign, ToCss, TryMap, TryOp, TrySign};
+use crate::traits::{FallbackValues, IsCompatible, Parse, Sign, ToCss, TryMap, TryOp, TrySign};
 use cssparser::*;
 
 use super::angle::Angle;
@@ -271,12 +272,17 @@ impl<
       "calc" => {
         let calc = input.parse_nested_block(Calc::parse_sum)?;
         match calc {
-          Calc::Value(_) | Calc::Number(_) => Ok(calc),
+          // Other math functions do not need to be wrapped in calc(), e.g. calc(min(1px, 1em)) => min(1px, 1em).
+          Calc::Value(_) | Calc::Number(_) | Calc::Function(_) => Ok(calc),
           _ => Ok(Calc::Function(Box::new(MathFunction::Calc(calc))))
         }
       },
       "min" => {
-        let mut args = input.parse_nested_block(|input| input.parse_comma_separated(Calc::parse_sum))?;
+        let args = input.parse_nested_block(|input| input.parse_comma_separated(Calc::parse_sum))?;
+        let mut args = Calc::flatten_args(args, |f| match f {
+          MathFunction::Min(args) => Ok(args),
+          f => Err(f),
+        });
         let mut reduced = Calc::reduce_args(&mut args, std::cmp::Ordering::Less);
         if reduced.len() == 1 {
           return Ok(reduced.remove(0))
@@ -284,7 +290,11 @@ impl<
         Ok(Calc::Function(Box::new(MathFunction::Min(reduced))))
       },
       "max" => {
-        let mut args = input.parse_nested_block(|input| input.parse_comma_separated(Calc::parse_sum))?;
+        let args = input.parse_nested_block(|input| input.parse_comma_separated(Calc::parse_sum))?;
+        let mut args = Calc::flatten_args(args, |f| match f {
+          MathFunction::Max(args) => Ok(args),
+          f => Err(f),
+        });
         let mut reduced = Calc::reduce_args(&mut args, std::cmp::Ordering::Greater);
         if reduced.len() == 1 {
           return Ok(reduced.remove(0))
@@ -302,8 +312,14 @@ impl<
         })?;
 
         // According to the spec, the minimum should "win" over the maximum if they are in the wrong order.
-        let cmp = if let (Some(Calc::Value(max_val)), Calc::Value(center_val)) = (&max, &center) {
-          center_val.partial_cmp(&max_val)
+        if let (Some(min_val), Some(max_val)) = (&min, &max) {
+          if let Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal) = min_val.partial_cmp_value(max_val) {
+            return Ok(min.unwrap());
+          }
+        }
+
+        let cmp = if let Some(max_val) = &max {
+          center.partial_cmp_value(max_val)
         } else {
           None
         };
@@ -320,8 +336,8 @@ impl<
           None => {}
         }
 
-        let cmp = if let (Some(Calc::Value(min_val)), Calc::Value(center_val)) = (&min, &center) {
-          center_val.partial_cmp(&min_val)
+        let cmp = if let Some(min_val) = &min {
+          center.partial_cmp_value(min_val)
         } else {
           None
         };
@@ -572,6 +588,24 @@ impl<
     Err(input.new_error_for_next_token())
   }
 
+  fn flatten_args<F: Fn(MathFunction<V>) -> Result<Vec<Calc<V>>, MathFunction<V>>>(
+    args: Vec<Calc<V>>,
+    nested: F,
+  ) -> Vec<Calc<V>> {
+    // Flattens nested functions of the same type, e.g. min(1px, min(2em, 3px)) => min(1px, 2em, 3px)
+    let mut flattened = Vec::with_capacity(args.len());
+    for arg in args {
+      match arg {
+        Calc::Function(f) => match nested(*f) {
+          Ok(args) => flattened.extend(args),
+          Err(f) => flattened.push(Calc::Function(Box::new(f))),
+        },
+        arg => flattened.push(arg),
+      }
+    }
+    flattened
+  }
+
   fn reduce_args(args: &mut Vec<Calc<V>>, cmp: std::cmp::Ordering) -> Vec<Calc<V>> {
     // Reduces the arguments of a min() or max() expression, combining compatible values.
     // e.g. min(1px, 1em, 2px, 3in) => min(1px, 1em)
@@ -579,20 +613,18 @@ impl<
     for arg in args.drain(..) {
       let mut found = None;
       match &arg {
-        Calc::Value(val) => {
+        Calc::Value(_) | Calc::Number(_) => {
           for b in reduced.iter_mut() {
-            if let Calc::Value(v) = b {
-              match val.partial_cmp(v) {
-                Some(ord) if ord == cmp => {
-                  found = Some(Some(b));
-                  break;
-                }
-                Some(_) => {
-                  found = Some(None);
-                  break;
-                }
-                None => {}
+            match arg.partial_cmp_value(b) {
+              Some(ord) if ord == cmp => {
+                found = Some(Some(b));
+                break;
               }
+              Some(_) => {
+                found = Some(None);
+                break;
+              }
+              None => {}
             }
           }
         }
@@ -763,6 +795,158 @@ impl<
   }
 }
 
+impl<V: Clone + std::cmp::PartialOrd<V>> Calc<V> {
+  /// Returns a fallback for this expression that replaces math functions unsupported
+  /// by the given browser targets with a static value, or `None` if no fallback is needed.
+  ///
+  /// `clamp()` is converted to `max()` and `min()` when printing if those are supported,
+  /// otherwise it is replaced by its preferred value, or the minimum value if the preferred
+  /// value is itself a math function. `min()` and `max()` are replaced by their result when all
+  /// of their arguments can be compared, and `round()` by the value being rounded.
+  fn get_static_fallback(&self, targets: Browsers) -> Option<Calc<V>> {
+    match self {
+      Calc::Value(_) | Calc::Number(_) => None,
+      Calc::Sum(a, b) => match (a.get_static_fallback(targets), b.get_static_fallback(targets)) {
+        (None, None) => None,
+        (a_fallback, b_fallback) => Some(Calc::Sum(
+          Box::new(a_fallback.unwrap_or_else(|| (**a).clone())),
+          Box::new(b_fallback.unwrap_or_else(|| (**b).clone())),
+        )),
+      },
+      Calc::Product(num, calc) => calc
+        .get_static_fallback(targets)
+        .map(|calc| Calc::Product(*num, Box::new(calc))),
+      Calc::Function(f) => match &**f {
+        MathFunction::Calc(c) => c
+          .get_static_fallback(targets)
+          .map(|c| Calc::Function(Box::new(MathFunction::Calc(c)))),
+        MathFunction::Min(args) if !Feature::MinFunction.is_compatible(targets) => {
+          Self::static_extremum(args, std::cmp::Ordering::Less, targets)
+        }
+        MathFunction::Max(args) if !Feature::MaxFunction.is_compatible(targets) => {
+          Self::static_extremum(args, std::cmp::Ordering::Greater, targets)
+        }
+        MathFunction::Clamp(min, center, _)
+          if !Feature::Clamp.is_compatible(targets)
+            && !(Feature::MinFunction.is_compatible(targets) && Feature::MaxFunction.is_compatible(targets)) =>
+        {
+          if matches!(center, Calc::Function(..)) {
+            Some(min.static_value(targets))
+          } else {
+            Some(center.static_value(targets))
+          }
+        }
+        MathFunction::Round(_, value, _) if !Feature::RoundFunction.is_compatible(targets) => {
+          Some(value.static_value(targets))
+        }
+        _ => None,
+      },
+    }
+  }
+
+  fn static_value(&self, targets: Browsers) -> Calc<V> {
+    self.get_static_fallback(targets).unwrap_or_else(|| self.clone())
+  }
+
+  /// Returns the smallest or largest of the given `min()` or `max()` arguments, or `None`
+  /// if any of them cannot be compared with the others, e.g. when they have different units.
+  fn static_extremum(args: &[Calc<V>], cmp: std::cmp::Ordering, targets: Browsers) -> Option<Calc<V>> {
+    let mut iter = args.iter().map(|arg| arg.static_value(targets));
+    let mut result = iter.next()?;
+    for arg in iter {
+      if arg.partial_cmp_value(&result)? == cmp {
+        result = arg;
+      }
+    }
+    Some(result)
+  }
+
+  /// Compares two calc expressions, if they are both literal values or numbers
+  /// and can be compared without resolving units.
+  fn partial_cmp_value(&self, other: &Calc<V>) -> Option<std::cmp::Ordering> {
+    match (self, other) {
+      (Calc::Value(a), Calc::Value(b)) => (**a).partial_cmp(&**b),
+      (Calc::Number(a), Calc::Number(b)) => a.partial_cmp(b),
+      _ => None,
+    }
+  }
+
+  /// Evaluates the expression to a number, using the given function to resolve the
+  /// value of each literal. Returns `None` if any value cannot be resolved.
+  pub(crate) fn evaluate<F: Fn(&V) -> Option<f32> + Copy>(&self, resolve: F) -> Option<f32> {
+    let evaluate_args =
+      |args: &Vec<Calc<V>>| -> Option<Vec<f32>> { args.iter().map(|arg| arg.evaluate(resolve)).collect() };
+
+    Some(match self {
+      Calc::Value(v) => resolve(v)?,
+      Calc::Number(n) => *n,
+      Calc::Sum(a, b) => a.evaluate(resolve)? + b.evaluate(resolve)?,
+      Calc::Product(num, calc) => num * calc.evaluate(resolve)?,
+      Calc::Function(f) => match &**f {
+        MathFunction::Calc(c) => c.evaluate(resolve)?,
+        MathFunction::Min(args) => evaluate_args(args)?.into_iter().fold(f32::INFINITY, f32::min),
+        MathFunction::Max(args) => evaluate_args(args)?.into_iter().fold(-f32::INFINITY, f32::max),
+        MathFunction::Clamp(min, center, max) => center
+          .evaluate(resolve)?
+          .min(max.evaluate(resolve)?)
+          .max(min.evaluate(resolve)?),
+        MathFunction::Round(strategy, value, to) => {
+          round(value.evaluate(resolve)?, to.evaluate(resolve)?, *strategy)
+        }
+        MathFunction::Rem(a, b) => a.evaluate(resolve)? % b.evaluate(resolve)?,
+        MathFunction::Mod(a, b) => modulo(a.evaluate(resolve)?, b.evaluate(resolve)?),
+        MathFunction::Abs(v) => v.evaluate(resolve)?.abs(),
+        MathFunction::Sign(v) => v.evaluate(resolve)?.sign(),
+        MathFunction::Hypot(args) => evaluate_args(args)?.into_iter().map(|v| v * v).sum::<f32>().sqrt(),
+      },
+    })
+  }
+}
+
+impl<V: IsCompatible> IsCompatible for Calc<V> {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      Calc::Value(v) => v.is_compatible(targets),
+      Calc::Number(..) => true,
+      Calc::Sum(a, b) => a.is_compatible(targets) && b.is_compatible(targets),
+      Calc::Product(_, v) => v.is_compatible(targets),
+      Calc::Function(f) => f.is_compatible(targets),
+    }
+  }
+}
+
+impl<V: IsCompatible> IsCompatible for MathFunction<V> {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    let is_supported = |feature: Feature| targets.map_or(false, |targets| feature.is_compatible(targets));
+    match self {
+      MathFunction::Calc(c) => c.is_compatible(targets),
+      MathFunction::Min(args) => {
+        is_supported(Feature::MinFunction) && args.iter().all(|arg| arg.is_compatible(targets))
+      }
+      MathFunction::Max(args) => {
+        is_supported(Feature::MaxFunction) && args.iter().all(|arg| arg.is_compatible(targets))
+      }
+      MathFunction::Clamp(a, b, c) => {
+        is_supported(Feature::Clamp)
+          && a.is_compatible(targets)
+          && b.is_compatible(targets)
+          && c.is_compatible(targets)
+      }
+      MathFunction::Round(_, a, b) => {
+        is_supported(Feature::RoundFunction) && a.is_compatible(targets) && b.is_compatible(targets)
+      }
+      // The remaining functions have no compatibility data.
+      _ => false,
+    }
+  }
+}
+
+impl<V: Clone + std::cmp::PartialOrd<V>> FallbackValues for Calc<V> {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    self.get_static_fallback(targets).into_iter().collect()
+  }
+}
+
 impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
   type Output = Self;
 
@@ -772,6 +956,8 @@ impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
     }
 
     match self {
+      // Infinite and NaN values cannot be represented by a dimension, so keep the product.
+      Calc::Value(v) if !other.is_finite() => Calc::Product(other, Box::new(Calc::Value(v))),
       Calc::Value(v) => Calc::Value(Box::new(*v * other)),
       Calc::Number(n) => Calc::Number(n * other),
       Calc::Sum(a, b) => Calc::Sum(Box::new(*a * other), Box::new(*b * other)),
@@ -784,12 +970,37 @@ impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
       }
       Calc::Function(f) => match *f {
         MathFunction::Calc(c) => Calc::Function(Box::new(MathFunction::Calc(c * other))),
-        _ => Calc::Product(other, Box::new(Calc::Function(f))),
+        // These functions are homogeneous, so multiplying by a positive number can be
+        // distributed over the arguments. e.g. 2 * min(1px, 1em) => min(2px, 2em)
+        f if other > 0.0 && other.is_finite() => f * other,
+        f => Calc::Product(other, Box::new(Calc::Function(Box::new(f)))),
       },
     }
   }
 }
 
+impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for MathFunction<V> {
+  type Output = Calc<V>;
+
+  /// Multiplies the function by a positive number, distributing over the arguments where possible.
+  fn mul(self, other: f32) -> Calc<V> {
+    let map = |args: Vec<Calc<V>>| args.into_iter().map(|arg| arg * other).collect();
+    let f = match self {
+      MathFunction::Calc(c) => MathFunction::Calc(c * other),
+      MathFunction::Min(args) => MathFunction::Min(map(args)),
+      MathFunction::Max(args) => MathFunction::Max(map(args)),
+      MathFunction::Clamp(a, b, c) => MathFunction::Clamp(a * other, b * other, c * other),
+      MathFunction::Round(strategy, a, b) => MathFunction::Round(strategy, a * other, b * other),
+      MathFunction::Rem(a, b) => MathFunction::Rem(a * other, b * other),
+      MathFunction::Mod(a, b) => MathFunction::Mod(a * other, b * other),
+      MathFunction::Abs(v) => MathFunction::Abs(v * other),
+      MathFunction::Hypot(args) => MathFunction::Hypot(map(args)),
+      f @ MathFunction::Sign(_) => return Calc::Product(other, Box::new(Calc::Function(Box::new(f)))),
+    };
+    Calc::Function(Box::new(f))
+  }
+}
+
 impl<V: AddInternal + std::convert::Into<Calc<V>> + std::convert::From<Calc<V>> + std::fmt::Debug> AddInternal
   for Calc<V>
 {
@@ -816,12 +1027,12 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
 
     let res = match self {
       Calc::Value(v) => v.to_css(dest),
-      Calc::Number(n) => n.to_css(dest),
+      Calc::Number(n) => serialize_number(*n, dest),
       Calc::Sum(a, b) => {
         a.to_css(dest)?;
         // Whitespace is always required.
         let b = &**b;
-        if b.is_sign_negative() {
+        if b.is_sign_negative() || matches!(b, Calc::Product(num, _) if *num < 0.0) {
           dest.write_str(" - ")?;
           let b = b.clone() * -1.0;
           b.to_css(dest)
@@ -837,7 +1048,7 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
           dest.delim('/', true)?;
           div.to_css(dest)
         } else {
-          num.to_css(dest)?;
+          serialize_number(*num, dest)?;
           dest.delim('*', true)?;
           calc.to_css(dest)
         }
@@ -850,6 +1061,23 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
   }
 }
 
+/// Serializes a number within a calc() expression, including infinite and NaN values,
+/// which are represented by [constants](Constant).
+fn serialize_number<W>(n: f32, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if n.is_nan() {
+    Constant::Nan.to_css(dest)
+  } else if n == f32::INFINITY {
+    Constant::Infinity.to_css(dest)
+  } else if n == -f32::INFINITY {
+    Constant::NegativeInfinity.to_css(dest)
+  } else {
+    n.to_css(dest)
+  }
+}
+
 impl<V: TrySign> TrySign for Calc<V> {
   fn try_sign(&self) -> Option<f32> {
     match self {
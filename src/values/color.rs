@@ -1,6 +1,7 @@
 //! CSS color values.
 
 use super::angle::Angle;
+use super::calc::{Calc, MathFunction};
 use super::number::CSSNumber;
 use super::percentage::Percentage;
 use crate::compat::Feature;
@@ -9,7 +10,8 @@ use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::rules::supports::SupportsCondition;
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, ToCss};
+use crate::traits::private::AddInternal;
+use crate::traits::{FallbackValues, IsCompatible, Parse, Sign, ToCss, TryMap, TryOp, TrySign};
 use bitflags::bitflags;
 use cssparser::*;
 use std::any::TypeId;
@@ -42,6 +44,14 @@ pub enum CssColor {
   Predefined(Box<PredefinedColor>),
   /// A floating point representation of an RGB, HSL, or HWB color when it contains `none` components.
   Float(Box<FloatColor>),
+  /// A relative color whose origin color cannot be resolved at build time,
+  /// e.g. `rgb(from currentColor r g b / 50%)`.
+  Relative(Box<RelativeColor>),
+  /// The [`light-dark()`](https://drafts.csswg.org/css-color-5/#light-dark) function,
+  /// which selects between a light and a dark color based on the used `color-scheme`.
+  LightDark(Box<CssColor>, Box<CssColor>),
+  /// A [system color](https://www.w3.org/TR/css-color-4/#css-system-colors) keyword.
+  System(SystemColor),
 }
 
 /// A color in a LAB color space, including the `lab()`, `lch()`, `oklab()`, and `oklch()` functions.
@@ -128,6 +138,71 @@ bitflags! {
   }
 }
 
+enum_property! {
+  /// A [system color](https://www.w3.org/TR/css-color-4/#css-system-colors) keyword.
+  pub enum SystemColor {
+    /// The background of accented user interface controls.
+    AccentColor,
+    /// The text of accented user interface controls.
+    AccentColorText,
+    /// The text in active links.
+    ActiveText,
+    /// The base border color for controls.
+    ButtonBorder,
+    /// The background color for controls.
+    ButtonFace,
+    /// The text color for controls.
+    ButtonText,
+    /// The background of application content or documents.
+    Canvas,
+    /// The text color in application content or documents.
+    CanvasText,
+    /// The background of input fields.
+    Field,
+    /// The text in input fields.
+    FieldText,
+    /// The disabled text color.
+    GrayText,
+    /// The background of selected text.
+    Highlight,
+    /// The text color of selected text.
+    HighlightText,
+    /// The text in non-active, non-visited links.
+    LinkText,
+    /// The background of text that has been specially marked, e.g. by the `mark` element.
+    Mark,
+    /// The text that has been specially marked, e.g. by the `mark` element.
+    MarkText,
+    /// The background of selected items, e.g. a selected checkbox.
+    SelectedItem,
+    /// The text of selected items.
+    SelectedItemText,
+    /// The text in visited links.
+    VisitedText,
+  }
+}
+
+impl SystemColor {
+  /// Returns whether the system color is supported by all of the given browser targets.
+  pub fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      SystemColor::AccentColor | SystemColor::AccentColorText => {
+        Feature::AccentSystemColor.is_compatible(browsers)
+      }
+      _ => true,
+    }
+  }
+
+  /// Returns a widely supported system color to use as a fallback, if any.
+  fn fallback(&self) -> Option<SystemColor> {
+    match self {
+      SystemColor::AccentColor => Some(SystemColor::Highlight),
+      SystemColor::AccentColorText => Some(SystemColor::HighlightText),
+      _ => None,
+    }
+  }
+}
+
 enum_property! {
   /// A [color space](https://www.w3.org/TR/css-color-4/#interpolation-space) keyword
   /// used in interpolation functions such as `color-mix()`.
@@ -210,18 +285,35 @@ impl CssColor {
   }
 
   /// Converts the color to RGBA.
+  ///
+  /// Colors that cannot be resolved at build time, such as `currentColor` or system colors,
+  /// are returned as is. Both branches of `light-dark()` are converted.
   pub fn to_rgb(&self) -> CssColor {
-    RGBA::from(self).into()
+    self.map_static(|color| RGBA::from(color).into())
   }
 
   /// Converts the color to the LAB color space.
+  ///
+  /// Colors that cannot be resolved at build time are returned as is.
   pub fn to_lab(&self) -> CssColor {
-    LAB::from(self).into()
+    self.map_static(|color| LAB::from(color).into())
   }
 
   /// Converts the color to the P3 color space.
+  ///
+  /// Colors that cannot be resolved at build time are returned as is.
   pub fn to_p3(&self) -> CssColor {
-    P3::from(self).into()
+    self.map_static(|color| P3::from(color).into())
+  }
+
+  fn map_static<F: Fn(&CssColor) -> CssColor + Copy>(&self, f: F) -> CssColor {
+    match self {
+      CssColor::LightDark(light, dark) => {
+        CssColor::LightDark(Box::new(light.map_static(f)), Box::new(dark.map_static(f)))
+      }
+      _ if self.is_static() => f(self),
+      _ => self.clone(),
+    }
   }
 
   pub(crate) fn get_possible_fallbacks(&self, targets: Browsers) -> ColorFallbackKind {
@@ -229,7 +321,20 @@ impl CssColor {
     // below and including the authored color space, and remove the ones that aren't
     // compatible with our browser targets.
     let mut fallbacks = match self {
-      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) => return ColorFallbackKind::empty(),
+      CssColor::CurrentColor
+      | CssColor::RGBA(_)
+      | CssColor::Float(..)
+      | CssColor::Relative(..)
+      | CssColor::System(..) => return ColorFallbackKind::empty(),
+      CssColor::LightDark(light, dark) => {
+        // When light-dark() is compiled to var(), the declaration is always valid at parse time,
+        // so fallback declarations would never apply. The most compatible color is printed instead.
+        if !Feature::LightDark.is_compatible(targets) {
+          return ColorFallbackKind::empty();
+        }
+
+        return light.get_possible_fallbacks(targets) | dark.get_possible_fallbacks(targets);
+      }
       CssColor::LAB(lab) => match &**lab {
         LABColor::LAB(..) | LABColor::LCH(..) => ColorFallbackKind::LAB.and_below(),
         LABColor::OKLAB(..) | LABColor::OKLCH(..) => ColorFallbackKind::OKLAB.and_below(),
@@ -284,6 +389,15 @@ impl CssColor {
     fallbacks - fallbacks.highest()
   }
 
+  /// Returns whether the color can be resolved at build time, i.e. it does not depend
+  /// on the element it is applied to.
+  pub(crate) fn is_static(&self) -> bool {
+    !matches!(
+      self,
+      CssColor::CurrentColor | CssColor::Relative(..) | CssColor::LightDark(..) | CssColor::System(..)
+    )
+  }
+
   /// Returns a fallback color for the given fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> CssColor {
     if matches!(self, CssColor::RGBA(_)) {
@@ -301,6 +415,13 @@ impl CssColor {
 
 impl FallbackValues for CssColor {
   fn get_fallbacks(&mut self, targets: Browsers) -> Vec<CssColor> {
+    if let CssColor::System(system) = self {
+      return match system.fallback() {
+        Some(fallback) if !system.is_compatible(targets) => vec![CssColor::System(fallback)],
+        _ => Vec::new(),
+      };
+    }
+
     let fallbacks = self.get_necessary_fallbacks(targets);
 
     let mut res = Vec::new();
@@ -320,6 +441,34 @@ impl FallbackValues for CssColor {
   }
 }
 
+impl IsCompatible for CssColor {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    let feature = match self {
+      CssColor::CurrentColor | CssColor::RGBA(_) => return true,
+      CssColor::System(SystemColor::AccentColor | SystemColor::AccentColorText) => Feature::AccentSystemColor,
+      CssColor::System(..) => return true,
+      CssColor::LAB(lab) => match &**lab {
+        LABColor::LAB(..) | LABColor::LCH(..) => Feature::LabColors,
+        LABColor::OKLAB(..) | LABColor::OKLCH(..) => Feature::OklabColors,
+      },
+      CssColor::Predefined(predefined) => match &**predefined {
+        PredefinedColor::DisplayP3(..) => Feature::P3Colors,
+        _ => Feature::ColorFunction,
+      },
+      CssColor::LightDark(light, dark) => {
+        if !light.is_compatible(targets) || !dark.is_compatible(targets) {
+          return false;
+        }
+        Feature::LightDark
+      }
+      // `none` components and relative colors have no compatibility data.
+      CssColor::Float(..) | CssColor::Relative(..) => return false,
+    };
+
+    targets.map_or(false, |targets| feature.is_compatible(targets))
+  }
+}
+
 impl Default for CssColor {
   fn default() -> CssColor {
     CssColor::transparent()
@@ -342,6 +491,10 @@ impl<'i> Parse<'i> for CssColor {
       return Ok(color.into());
     }
 
+    if let Ok(system) = input.try_parse(SystemColor::parse) {
+      return Ok(CssColor::System(system));
+    }
+
     parse_color_function(input)
   }
 }
@@ -356,6 +509,11 @@ impl ToCss for CssColor {
       CssColor::RGBA(color) => {
         if color.alpha == 255 {
           let hex: u32 = ((color.red as u32) << 16) | ((color.green as u32) << 8) | (color.blue as u32);
+          if !dest.shorten_colors {
+            write!(dest, "#{:06x}", hex)?;
+            return Ok(());
+          }
+
           if let Some(name) = short_color_name(hex) {
             return dest.write_str(name);
           }
@@ -396,7 +554,7 @@ impl ToCss for CssColor {
             | ((color.blue as u32) << 8)
             | (color.alpha as u32);
           let compact = compact_hex(hex);
-          if hex == expand_hex(compact) {
+          if dest.shorten_colors && hex == expand_hex(compact) {
             write!(dest, "#{:04x}", compact)?;
           } else {
             write!(dest, "#{:08x}", hex)?;
@@ -416,6 +574,41 @@ impl ToCss for CssColor {
         let srgb = SRGB::from(**float);
         CssColor::from(srgb).to_css(dest)
       }
+      CssColor::Relative(relative) => relative.to_css(dest),
+      CssColor::LightDark(light, dark) => {
+        // If light-dark() is not supported, select between the two colors using custom
+        // properties that are toggled by the `color-scheme` property.
+        // See ColorSchemeHandler.
+        if let Some(targets) = dest.targets {
+          if !Feature::LightDark.is_compatible(targets) {
+            let kind = (light.get_possible_fallbacks(targets) | dark.get_possible_fallbacks(targets)).lowest();
+            let convert = |color: &CssColor| {
+              if kind.is_empty() {
+                color.clone()
+              } else {
+                color.get_fallback(kind)
+              }
+            };
+
+            dest.write_str("var(--parcel-light")?;
+            dest.delim(',', false)?;
+            convert(light).to_css(dest)?;
+            dest.write_char(')')?;
+            dest.whitespace()?;
+            dest.write_str("var(--parcel-dark")?;
+            dest.delim(',', false)?;
+            convert(dark).to_css(dest)?;
+            return dest.write_char(')');
+          }
+        }
+
+        dest.write_str("light-dark(")?;
+        light.to_css(dest)?;
+        dest.delim(',', false)?;
+        dark.to_css(dest)?;
+        dest.write_char(')')
+      }
+      CssColor::System(system) => system.to_css(dest),
     }
   }
 }
@@ -538,9 +731,15 @@ impl<'i> ColorComponentParser<'i> for ComponentParser {
 // https://www.w3.org/TR/css-color-4/#lab-colors
 fn parse_color_function<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
   let location = input.current_source_location();
-  let function = input.expect_function()?;
+  let function = input.expect_function()?.clone();
   let parser = ComponentParser { allow_none: true };
 
+  if let Ok(color) =
+    input.try_parse(|input| input.parse_nested_block(|input| parse_relative_color(&function, input)))
+  {
+    return Ok(color);
+  }
+
   match_ignore_ascii_case! {&*function,
     "lab" => {
       let (l, a, b, alpha) = parse_lab(input, &parser)?;
@@ -581,6 +780,17 @@ fn parse_color_function<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor,
     "color-mix" => {
       input.parse_nested_block(parse_color_mix)
     },
+    "light-dark" => {
+      input.parse_nested_block(|input| {
+        let light = CssColor::parse(input)?;
+        input.expect_comma()?;
+        let dark = CssColor::parse(input)?;
+        if light == dark {
+          return Ok(light);
+        }
+        Ok(CssColor::LightDark(Box::new(light), Box::new(dark)))
+      })
+    },
     _ => Err(location.new_unexpected_token_error(
       cssparser::Token::Ident(function.clone())
     ))
@@ -826,7 +1036,7 @@ where
   if c.is_nan() {
     dest.write_str("none")?;
   } else {
-    c.to_css(dest)?;
+    dest.round(c).to_css(dest)?;
   }
   Ok(())
 }
@@ -2166,7 +2376,9 @@ macro_rules! color_space {
           CssColor::LAB(lab) => (**lab).into(),
           CssColor::Predefined(predefined) => (**predefined).into(),
           CssColor::Float(float) => (**float).into(),
-          CssColor::CurrentColor => unreachable!(),
+          CssColor::CurrentColor | CssColor::Relative(..) | CssColor::LightDark(..) | CssColor::System(..) => {
+            unreachable!()
+          }
         }
       }
     }
@@ -2447,7 +2659,7 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
     (p1, p2)
   };
 
-  if (p1 + p2) == 0.0 {
+  if (p1 + p2) == 0.0 || !first_color.is_static() || !second_color.is_static() {
     return Err(input.new_custom_error(ParserError::InvalidValue));
   }
 
@@ -2465,10 +2677,646 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
   })
 }
 
+enum_property! {
+  /// A channel keyword that refers to a component of the origin color in a
+  /// [relative color](https://drafts.csswg.org/css-color-5/#relative-colors).
+  ///
+  /// The meaning of each keyword depends on the color function it is used in.
+  pub enum ColorChannel {
+    /// The red channel, in `rgb()` and `color()`.
+    "r": R,
+    /// The green channel, in `rgb()` and `color()`.
+    "g": G,
+    /// The blue channel in `rgb()` and `color()`, the blackness channel in `hwb()`,
+    /// or the b axis in `lab()` and `oklab()`.
+    "b": B,
+    /// The hue channel, in `hsl()`, `hwb()`, `lch()` and `oklch()`.
+    "h": H,
+    /// The saturation channel, in `hsl()`.
+    "s": S,
+    /// The lightness channel, in `hsl()`, `lab()`, `lch()`, `oklab()` and `oklch()`.
+    "l": L,
+    /// The whiteness channel, in `hwb()`.
+    "w": W,
+    /// The a axis, in `lab()` and `oklab()`.
+    "a": A,
+    /// The chroma channel, in `lch()` and `oklch()`.
+    "c": C,
+    /// The x channel, in `color()` with an xyz color space.
+    "x": X,
+    /// The y channel, in `color()` with an xyz color space.
+    "y": Y,
+    /// The z channel, in `color()` with an xyz color space.
+    "z": Z,
+    /// The alpha channel.
+    "alpha": Alpha,
+  }
+}
+
+/// The color function of a [relative color](https://drafts.csswg.org/css-color-5/#relative-colors),
+/// which determines the channels that can be referenced.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+pub enum RelativeColorFunction {
+  /// The `rgb()` function.
+  RGB,
+  /// The `hsl()` function.
+  HSL,
+  /// The `hwb()` function.
+  HWB,
+  /// The `lab()` function.
+  LAB,
+  /// The `lch()` function.
+  LCH,
+  /// The `oklab()` function.
+  OKLAB,
+  /// The `oklch()` function.
+  OKLCH,
+  /// The `color()` function with the `srgb` color space.
+  SRGB,
+  /// The `color()` function with the `srgb-linear` color space.
+  SRGBLinear,
+  /// The `color()` function with the `display-p3` color space.
+  DisplayP3,
+  /// The `color()` function with the `a98-rgb` color space.
+  A98,
+  /// The `color()` function with the `prophoto-rgb` color space.
+  ProPhoto,
+  /// The `color()` function with the `rec2020` color space.
+  Rec2020,
+  /// The `color()` function with the `xyz-d50` color space.
+  XYZd50,
+  /// The `color()` function with the `xyz-d65` color space.
+  XYZd65,
+}
+
+impl RelativeColorFunction {
+  /// Returns the channel keywords for the three color components.
+  fn channels(&self) -> [ColorChannel; 3] {
+    use ColorChannel::*;
+    match self {
+      RelativeColorFunction::HSL => [H, S, L],
+      RelativeColorFunction::HWB => [H, W, B],
+      RelativeColorFunction::LAB | RelativeColorFunction::OKLAB => [L, A, B],
+      RelativeColorFunction::LCH | RelativeColorFunction::OKLCH => [L, C, H],
+      RelativeColorFunction::XYZd50 | RelativeColorFunction::XYZd65 => [X, Y, Z],
+      _ => [R, G, B],
+    }
+  }
+
+  /// Returns the index of the hue component, if any.
+  fn hue_index(&self) -> Option<usize> {
+    match self {
+      RelativeColorFunction::HSL | RelativeColorFunction::HWB => Some(0),
+      RelativeColorFunction::LCH | RelativeColorFunction::OKLCH => Some(2),
+      _ => None,
+    }
+  }
+
+  /// Returns the number that 100% corresponds to for the component at the given index.
+  fn percentage_reference(&self, index: usize) -> f32 {
+    match (self, index) {
+      (_, 3) => 1.0,
+      (RelativeColorFunction::RGB, _) => 255.0,
+      (RelativeColorFunction::HSL | RelativeColorFunction::HWB, _) => 100.0,
+      (RelativeColorFunction::LAB | RelativeColorFunction::LCH, 0) => 100.0,
+      (RelativeColorFunction::LAB, _) => 125.0,
+      (RelativeColorFunction::LCH, _) => 150.0,
+      (RelativeColorFunction::OKLAB | RelativeColorFunction::OKLCH, 0) => 1.0,
+      (RelativeColorFunction::OKLAB | RelativeColorFunction::OKLCH, _) => 0.4,
+      _ => 1.0,
+    }
+  }
+
+  /// Returns the name of the color space used in the `color()` function, if any.
+  fn color_space(&self) -> Option<&'static str> {
+    Some(match self {
+      RelativeColorFunction::SRGB => "srgb",
+      RelativeColorFunction::SRGBLinear => "srgb-linear",
+      RelativeColorFunction::DisplayP3 => "display-p3",
+      RelativeColorFunction::A98 => "a98-rgb",
+      RelativeColorFunction::ProPhoto => "prophoto-rgb",
+      RelativeColorFunction::Rec2020 => "rec2020",
+      RelativeColorFunction::XYZd50 => "xyz-d50",
+      RelativeColorFunction::XYZd65 => "xyz",
+      _ => return None,
+    })
+  }
+
+  /// Converts the given color to this color space, and returns the values of its channels,
+  /// with missing components replaced by zero.
+  fn resolve_channels(&self, color: &CssColor) -> [f32; 4] {
+    let values = match self {
+      RelativeColorFunction::RGB => {
+        let rgb = SRGB::from(color);
+        [rgb.r * 255.0, rgb.g * 255.0, rgb.b * 255.0, rgb.alpha]
+      }
+      RelativeColorFunction::HSL => {
+        let hsl = HSL::from(color);
+        [hsl.h, hsl.s * 100.0, hsl.l * 100.0, hsl.alpha]
+      }
+      RelativeColorFunction::HWB => {
+        let hwb = HWB::from(color);
+        [hwb.h, hwb.w * 100.0, hwb.b * 100.0, hwb.alpha]
+      }
+      RelativeColorFunction::LAB => {
+        let lab = LAB::from(color);
+        [lab.l * 100.0, lab.a, lab.b, lab.alpha]
+      }
+      RelativeColorFunction::LCH => {
+        let lch = LCH::from(color);
+        [lch.l * 100.0, lch.c, lch.h, lch.alpha]
+      }
+      RelativeColorFunction::OKLAB => {
+        let lab = OKLAB::from(color);
+        [lab.l, lab.a, lab.b, lab.alpha]
+      }
+      RelativeColorFunction::OKLCH => {
+        let lch = OKLCH::from(color);
+        [lch.l, lch.c, lch.h, lch.alpha]
+      }
+      RelativeColorFunction::SRGB => {
+        let rgb = SRGB::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::SRGBLinear => {
+        let rgb = SRGBLinear::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::DisplayP3 => {
+        let rgb = P3::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::A98 => {
+        let rgb = A98::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::ProPhoto => {
+        let rgb = ProPhoto::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::Rec2020 => {
+        let rgb = Rec2020::from(color);
+        [rgb.r, rgb.g, rgb.b, rgb.alpha]
+      }
+      RelativeColorFunction::XYZd50 => {
+        let xyz = XYZd50::from(color);
+        [xyz.x, xyz.y, xyz.z, xyz.alpha]
+      }
+      RelativeColorFunction::XYZd65 => {
+        let xyz = XYZd65::from(color);
+        [xyz.x, xyz.y, xyz.z, xyz.alpha]
+      }
+    };
+
+    values.map(|v| if v.is_nan() { 0.0 } else { v })
+  }
+
+  /// Creates a color in this color space from channel values.
+  fn color_from_channels(&self, a: f32, b: f32, c: f32, alpha: f32) -> CssColor {
+    // f32::max() does not propagate NaN, so use clamp instead.
+    let alpha = alpha.clamp(0.0, 1.0);
+    match self {
+      RelativeColorFunction::RGB => CssColor::Float(Box::new(FloatColor::RGB(SRGB {
+        r: (a / 255.0).clamp(0.0, 1.0),
+        g: (b / 255.0).clamp(0.0, 1.0),
+        b: (c / 255.0).clamp(0.0, 1.0),
+        alpha,
+      }))),
+      RelativeColorFunction::HSL => CssColor::Float(Box::new(FloatColor::HSL(HSL {
+        h: a,
+        s: (b / 100.0).clamp(0.0, 1.0),
+        l: (c / 100.0).clamp(0.0, 1.0),
+        alpha,
+      }))),
+      RelativeColorFunction::HWB => CssColor::Float(Box::new(FloatColor::HWB(HWB {
+        h: a,
+        w: (b / 100.0).clamp(0.0, 1.0),
+        b: (c / 100.0).clamp(0.0, 1.0),
+        alpha,
+      }))),
+      RelativeColorFunction::LAB => LAB {
+        l: (a / 100.0).clamp(0.0, f32::MAX),
+        a: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::LCH => LCH {
+        l: (a / 100.0).clamp(0.0, f32::MAX),
+        c: b.clamp(0.0, f32::MAX),
+        h: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::OKLAB => OKLAB {
+        l: a.clamp(0.0, f32::MAX),
+        a: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::OKLCH => OKLCH {
+        l: a.clamp(0.0, f32::MAX),
+        c: b.clamp(0.0, f32::MAX),
+        h: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::SRGB => CssColor::Predefined(Box::new(PredefinedColor::SRGB(SRGB {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }))),
+      RelativeColorFunction::SRGBLinear => SRGBLinear {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::DisplayP3 => P3 {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::A98 => A98 {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::ProPhoto => ProPhoto {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::Rec2020 => Rec2020 {
+        r: a,
+        g: b,
+        b: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::XYZd50 => XYZd50 {
+        x: a,
+        y: b,
+        z: c,
+        alpha,
+      }
+      .into(),
+      RelativeColorFunction::XYZd65 => XYZd65 {
+        x: a,
+        y: b,
+        z: c,
+        alpha,
+      }
+      .into(),
+    }
+  }
+}
+
+/// A component of a [relative color](https://drafts.csswg.org/css-color-5/#relative-colors),
+/// which may reference the channels of the origin color.
+///
+/// Numbers are stored in the reference range of the channel, e.g. 0-255 for `rgb()`,
+/// so percentages are converted to numbers while parsing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum RelativeComponent {
+  /// A literal number, or `none`, which is represented as NaN.
+  Number(f32),
+  /// A channel keyword.
+  Channel(ColorChannel),
+  /// A `calc()` expression, which may reference channel keywords.
+  Calc(Box<Calc<RelativeComponent>>),
+}
+
+impl RelativeComponent {
+  fn parse_component<'i, 't>(
+    input: &mut Parser<'i, 't>,
+    function: RelativeColorFunction,
+    index: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let component = if let Ok(calc) = input.try_parse(Calc::parse) {
+      RelativeComponent::from_calc(calc)
+    } else if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      RelativeComponent::Number(f32::NAN)
+    } else if let Ok(channel) = input.try_parse(ColorChannel::parse) {
+      RelativeComponent::Channel(channel)
+    } else if let Ok(number) = input.try_parse(|input| input.expect_number()) {
+      RelativeComponent::Number(number)
+    } else if function.hue_index() == Some(index) {
+      RelativeComponent::Number(Angle::parse(input)?.to_degrees())
+    } else {
+      RelativeComponent::Number(Percentage::parse(input)?.0 * function.percentage_reference(index))
+    };
+
+    // Channel keywords must be valid for the color function.
+    let channels = function.channels();
+    if component
+      .evaluate(|channel| {
+        if *channel == ColorChannel::Alpha || channels.contains(channel) {
+          Some(0.0)
+        } else {
+          None
+        }
+      })
+      .is_none()
+    {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(component)
+  }
+
+  fn from_calc(calc: Calc<RelativeComponent>) -> RelativeComponent {
+    match calc {
+      Calc::Value(v) => match *v {
+        RelativeComponent::Calc(c) => RelativeComponent::from_calc(*c),
+        v => v,
+      },
+      Calc::Number(n) => RelativeComponent::Number(n),
+      Calc::Function(f) => RelativeComponent::Calc(Box::new(Calc::Function(f))),
+      c => RelativeComponent::Calc(Box::new(Calc::Function(Box::new(MathFunction::Calc(c))))),
+    }
+  }
+
+  /// Evaluates the component to a number, using the given function to resolve channel keywords.
+  fn evaluate<F: Fn(&ColorChannel) -> Option<f32> + Copy>(&self, resolve: F) -> Option<f32> {
+    match self {
+      RelativeComponent::Number(n) => Some(*n),
+      RelativeComponent::Channel(channel) => resolve(channel),
+      RelativeComponent::Calc(calc) => calc.evaluate(|v| v.evaluate(resolve)),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for RelativeComponent {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // Only channel keywords are parsed here. Numbers within calc() are handled by Calc itself.
+    Ok(RelativeComponent::Channel(ColorChannel::parse(input)?))
+  }
+}
+
+impl ToCss for RelativeComponent {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      RelativeComponent::Number(n) => write_component(*n, dest),
+      RelativeComponent::Channel(channel) => channel.to_css(dest),
+      RelativeComponent::Calc(calc) => calc.to_css(dest),
+    }
+  }
+}
+
+impl std::ops::Mul<f32> for RelativeComponent {
+  type Output = Self;
+
+  fn mul(self, other: f32) -> Self {
+    match self {
+      RelativeComponent::Number(n) => RelativeComponent::Number(n * other),
+      RelativeComponent::Channel(_) => {
+        RelativeComponent::Calc(Box::new(Calc::Product(other, Box::new(self.into()))))
+      }
+      RelativeComponent::Calc(calc) => RelativeComponent::Calc(Box::new(*calc * other)),
+    }
+  }
+}
+
+impl AddInternal for RelativeComponent {
+  fn add(self, other: Self) -> Self {
+    match (self, other) {
+      (RelativeComponent::Number(a), RelativeComponent::Number(b)) => RelativeComponent::Number(a + b),
+      (a, b) => RelativeComponent::Calc(Box::new(Calc::Sum(Box::new(a.into()), Box::new(b.into())))),
+    }
+  }
+}
+
+impl std::convert::Into<Calc<RelativeComponent>> for RelativeComponent {
+  fn into(self) -> Calc<RelativeComponent> {
+    match self {
+      RelativeComponent::Calc(c) => *c,
+      RelativeComponent::Number(n) => Calc::Number(n),
+      v => Calc::Value(Box::new(v)),
+    }
+  }
+}
+
+impl std::convert::From<Calc<RelativeComponent>> for RelativeComponent {
+  fn from(calc: Calc<RelativeComponent>) -> RelativeComponent {
+    match calc {
+      Calc::Value(v) => *v,
+      Calc::Number(n) => RelativeComponent::Number(n),
+      c => RelativeComponent::Calc(Box::new(c)),
+    }
+  }
+}
+
+impl TryOp for RelativeComponent {
+  fn try_op<F: FnOnce(f32, f32) -> f32>(&self, rhs: &Self, op: F) -> Option<Self> {
+    self.try_op_to(rhs, op).map(RelativeComponent::Number)
+  }
+
+  fn try_op_to<T, F: FnOnce(f32, f32) -> T>(&self, rhs: &Self, op: F) -> Option<T> {
+    match (self, rhs) {
+      (RelativeComponent::Number(a), RelativeComponent::Number(b)) => Some(op(*a, *b)),
+      _ => None,
+    }
+  }
+}
+
+impl TryMap for RelativeComponent {
+  fn try_map<F: FnOnce(f32) -> f32>(&self, op: F) -> Option<Self> {
+    match self {
+      RelativeComponent::Number(n) => Some(RelativeComponent::Number(op(*n))),
+      _ => None,
+    }
+  }
+}
+
+impl TrySign for RelativeComponent {
+  fn try_sign(&self) -> Option<f32> {
+    match self {
+      RelativeComponent::Number(n) => Some(n.sign()),
+      RelativeComponent::Channel(_) => None,
+      RelativeComponent::Calc(calc) => calc.try_sign(),
+    }
+  }
+}
+
+impl std::cmp::PartialOrd<RelativeComponent> for RelativeComponent {
+  fn partial_cmp(&self, other: &RelativeComponent) -> Option<std::cmp::Ordering> {
+    match (self, other) {
+      (RelativeComponent::Number(a), RelativeComponent::Number(b)) => a.partial_cmp(b),
+      _ => None,
+    }
+  }
+}
+
+impl TryFrom<Angle> for RelativeComponent {
+  type Error = ();
+
+  fn try_from(angle: Angle) -> Result<Self, Self::Error> {
+    Ok(RelativeComponent::Number(angle.to_degrees()))
+  }
+}
+
+/// A [relative color](https://drafts.csswg.org/css-color-5/#relative-colors) whose origin color
+/// cannot be resolved at build time, e.g. `rgb(from currentColor r g b / 50%)`.
+///
+/// Relative colors with a static origin color are resolved to a regular color while parsing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeColor {
+  /// The color function.
+  pub function: RelativeColorFunction,
+  /// The origin color.
+  pub origin: CssColor,
+  /// The three color components.
+  pub components: [RelativeComponent; 3],
+  /// The alpha component.
+  pub alpha: RelativeComponent,
+}
+
+impl RelativeColor {
+  /// Resolves the relative color to a regular color if the origin color is static.
+  fn resolve(&self) -> Option<CssColor> {
+    if !self.origin.is_static() {
+      return None;
+    }
+
+    let values = self.function.resolve_channels(&self.origin);
+    let channels = self.function.channels();
+    let resolve = |channel: &ColorChannel| {
+      if *channel == ColorChannel::Alpha {
+        return Some(values[3]);
+      }
+      channels.iter().position(|c| c == channel).map(|i| values[i])
+    };
+
+    Some(self.function.color_from_channels(
+      self.components[0].evaluate(resolve)?,
+      self.components[1].evaluate(resolve)?,
+      self.components[2].evaluate(resolve)?,
+      self.alpha.evaluate(resolve)?,
+    ))
+  }
+}
+
+impl ToCss for RelativeColor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let color_space = self.function.color_space();
+    dest.write_str(match self.function {
+      RelativeColorFunction::RGB => "rgb",
+      RelativeColorFunction::HSL => "hsl",
+      RelativeColorFunction::HWB => "hwb",
+      RelativeColorFunction::LAB => "lab",
+      RelativeColorFunction::LCH => "lch",
+      RelativeColorFunction::OKLAB => "oklab",
+      RelativeColorFunction::OKLCH => "oklch",
+      _ => "color",
+    })?;
+    dest.write_str("(from ")?;
+    self.origin.to_css(dest)?;
+    if let Some(color_space) = color_space {
+      dest.write_char(' ')?;
+      dest.write_str(color_space)?;
+    }
+
+    for component in &self.components {
+      dest.write_char(' ')?;
+      component.to_css(dest)?;
+    }
+
+    if self.alpha != RelativeComponent::Channel(ColorChannel::Alpha) {
+      dest.delim('/', true)?;
+      self.alpha.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// Parses the arguments of a relative color function, e.g. `rgb(from #f00 r g b / 50%)`.
+fn parse_relative_color<'i, 't>(
+  function: &str,
+  input: &mut Parser<'i, 't>,
+) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  // https://drafts.csswg.org/css-color-5/#relative-colors
+  input.expect_ident_matching("from")?;
+  let origin = CssColor::parse(input)?;
+  let function = match_ignore_ascii_case! { function,
+    "rgb" | "rgba" => RelativeColorFunction::RGB,
+    "hsl" | "hsla" => RelativeColorFunction::HSL,
+    "hwb" => RelativeColorFunction::HWB,
+    "lab" => RelativeColorFunction::LAB,
+    "lch" => RelativeColorFunction::LCH,
+    "oklab" => RelativeColorFunction::OKLAB,
+    "oklch" => RelativeColorFunction::OKLCH,
+    "color" => {
+      let location = input.current_source_location();
+      let color_space = input.expect_ident_cloned()?;
+      match_ignore_ascii_case! { &*color_space,
+        "srgb" => RelativeColorFunction::SRGB,
+        "srgb-linear" => RelativeColorFunction::SRGBLinear,
+        "display-p3" => RelativeColorFunction::DisplayP3,
+        "a98-rgb" => RelativeColorFunction::A98,
+        "prophoto-rgb" => RelativeColorFunction::ProPhoto,
+        "rec2020" => RelativeColorFunction::Rec2020,
+        "xyz-d50" => RelativeColorFunction::XYZd50,
+        "xyz" | "xyz-d65" => RelativeColorFunction::XYZd65,
+        _ => return Err(location.new_unexpected_token_error(
+          cssparser::Token::Ident(color_space.clone())
+        ))
+      }
+    },
+    _ => return Err(input.new_custom_error(ParserError::InvalidValue))
+  };
+
+  let a = RelativeComponent::parse_component(input, function, 0)?;
+  let b = RelativeComponent::parse_component(input, function, 1)?;
+  let c = RelativeComponent::parse_component(input, function, 2)?;
+  let alpha = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+    RelativeComponent::parse_component(input, function, 3)?
+  } else {
+    RelativeComponent::Channel(ColorChannel::Alpha)
+  };
+
+  let color = RelativeColor {
+    function,
+    origin,
+    components: [a, b, c],
+    alpha,
+  };
+
+  Ok(color.resolve().unwrap_or_else(|| CssColor::Relative(Box::new(color))))
+}
+
 impl CssColor {
   fn get_type_id(&self) -> TypeId {
     match self {
-      CssColor::RGBA(..) => TypeId::of::<SRGB>(),
+      CssColor::RGBA(_) => TypeId::of::<SRGB>(),
       CssColor::LAB(lab) => match &**lab {
         LABColor::LAB(..) => TypeId::of::<LAB>(),
         LABColor::LCH(..) => TypeId::of::<LCH>(),
@@ -9,7 +9,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::prefixes::{is_webkit_gradient, Feature};
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, ToCss};
+use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
 use crate::vendor_prefix::VendorPrefix;
@@ -153,6 +153,21 @@ impl<'i> ImageFallback<'i> for Image<'i> {
   }
 }
 
+impl<'i> IsCompatible for Image<'i> {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      Image::None | Image::Url(..) => true,
+      _ => {
+        !self.has_vendor_prefix()
+          && targets.map_or(false, |targets| {
+            self.get_necessary_prefixes(targets) == VendorPrefix::None
+              && self.get_necessary_fallbacks(targets).is_empty()
+          })
+      }
+    }
+  }
+}
+
 impl<'i> FallbackValues for Image<'i> {
   fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
     // Determine which prefixes and color fallbacks are needed.
@@ -4,12 +4,14 @@ use super::angle::impl_try_from_angle;
 use super::calc::{Calc, MathFunction};
 use super::number::CSSNumber;
 use super::percentage::DimensionPercentage;
+use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::targets::Browsers;
 use crate::traits::TrySign;
 use crate::traits::{
   private::{AddInternal, TryAdd},
-  Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
+  FallbackValues, IsCompatible, Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
 };
 use const_str;
 use cssparser::*;
@@ -76,6 +78,28 @@ impl ToCss for LengthPercentageOrAuto {
   }
 }
 
+impl FallbackValues for LengthPercentageOrAuto {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      LengthPercentageOrAuto::LengthPercentage(l) => l
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(LengthPercentageOrAuto::LengthPercentage)
+        .collect(),
+      LengthPercentageOrAuto::Auto => Vec::new(),
+    }
+  }
+}
+
+impl IsCompatible for LengthPercentageOrAuto {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      LengthPercentageOrAuto::LengthPercentage(l) => l.is_compatible(targets),
+      LengthPercentageOrAuto::Auto => true,
+    }
+  }
+}
+
 const PX_PER_IN: f32 = 96.0;
 const PX_PER_CM: f32 = PX_PER_IN / 2.54;
 const PX_PER_MM: f32 = PX_PER_CM / 10.0;
@@ -388,27 +412,90 @@ impl ToCss for LengthValue {
 
     // The unit can be omitted if the value is zero, except inside calc()
     // expressions, where unitless numbers won't be parsed as dimensions.
-    if !dest.in_calc && value == 0.0 {
+    if !dest.in_calc && dest.round(value) == 0.0 {
       return dest.write_char('0');
     }
 
+    if dest.minify && dest.convert_units {
+      if let Some(px) = self.to_px() {
+        return serialize_shortest_dimension(px, unit, ABSOLUTE_UNITS, dest);
+      }
+    }
+
     serialize_dimension(value, unit, dest)
   }
 }
 
+/// The absolute length units, with the number of pixels in each.
+const ABSOLUTE_UNITS: &[(&str, f32)] = &[
+  ("px", 1.0),
+  ("in", PX_PER_IN),
+  ("cm", PX_PER_CM),
+  ("mm", PX_PER_MM),
+  ("Q", PX_PER_Q),
+  ("pt", PX_PER_PT),
+  ("pc", PX_PER_PC),
+];
+
 impl LengthValue {
   pub(crate) fn to_css_unitless<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
     match self {
-      LengthValue::Px(value) => value.to_css(dest),
+      LengthValue::Px(value) => dest.round(*value).to_css(dest),
       _ => self.to_css(dest),
     }
   }
 }
 
 pub(crate) fn serialize_dimension<W>(value: f32, unit: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  write_dimension(dest.round(value), unit, dest)?;
+  Ok(())
+}
+
+/// Serializes a dimension in whichever of the given equivalent units produces the shortest output.
+/// Each unit is paired with its size in terms of a common base unit, and `value` is given in the base unit.
+/// Another unit is only chosen if converting it back produces the same output as the original unit.
+pub(crate) fn serialize_shortest_dimension<W>(
+  value: f32,
+  unit: &str,
+  units: &[(&str, f32)],
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let convert = |value: f32, factor: f32| dest.round((value as f64 / factor as f64) as f32);
+  let factor = units
+    .iter()
+    .find(|(u, _)| u.eq_ignore_ascii_case(unit))
+    .map_or(1.0, |(_, factor)| *factor);
+  let original = dimension_to_string(convert(value, factor), unit)?;
+  let mut shortest = None;
+  for (u, f) in units {
+    let s = dimension_to_string(convert(value, *f), u)?;
+    if s.len() < shortest.as_ref().unwrap_or(&original).len() {
+      let roundtrip = convert(convert(value, *f) * f, factor);
+      if dimension_to_string(roundtrip, unit)? == original {
+        shortest = Some(s);
+      }
+    }
+  }
+
+  dest.write_str(shortest.as_ref().unwrap_or(&original))
+}
+
+fn dimension_to_string(value: f32, unit: &str) -> Result<String, std::fmt::Error> {
+  let mut s = String::new();
+  write_dimension(value, unit, &mut s)?;
+  Ok(s)
+}
+
+fn write_dimension<W>(value: f32, unit: &str, dest: &mut W) -> std::fmt::Result
 where
   W: std::fmt::Write,
 {
@@ -430,8 +517,7 @@ where
       dest.write_str(s.trim_start_matches('0'))
     }
   } else {
-    token.to_css(dest)?;
-    Ok(())
+    token.to_css(dest)
   }
 }
 
@@ -669,6 +755,64 @@ impl std::convert::From<Calc<Length>> for Length {
   }
 }
 
+impl IsCompatible for LengthValue {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    use LengthValue::*;
+    match self {
+      Cqw(..) | Cqh(..) | Cqi(..) | Cqb(..) | Cqmin(..) | Cqmax(..) => targets.map_or(false, |targets| {
+        Feature::ContainerQueryLengthUnits.is_compatible(targets)
+      }),
+      _ => true,
+    }
+  }
+}
+
+impl FallbackValues for LengthValue {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    // Container query units resolve against the small viewport when there is no container,
+    // so fall back to the equivalent viewport unit (assuming a horizontal writing mode).
+    use LengthValue::*;
+    let fallback = match *self {
+      Cqw(v) | Cqi(v) => Vw(v),
+      Cqh(v) | Cqb(v) => Vh(v),
+      Cqmin(v) => Vmin(v),
+      Cqmax(v) => Vmax(v),
+      _ => return Vec::new(),
+    };
+
+    if Feature::ContainerQueryLengthUnits.is_compatible(targets) {
+      return Vec::new();
+    }
+
+    vec![fallback]
+  }
+}
+
+impl IsCompatible for Length {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      Length::Value(v) => v.is_compatible(targets),
+      Length::Calc(c) => c.is_compatible(targets),
+    }
+  }
+}
+
+impl FallbackValues for Length {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      Length::Calc(c) => c
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(|c| match c {
+          Calc::Value(v) => *v,
+          c => Length::Calc(Box::new(c)),
+        })
+        .collect(),
+      Length::Value(v) => v.get_fallbacks(targets).into_iter().map(Length::Value).collect(),
+    }
+  }
+}
+
 impl std::cmp::PartialOrd<Length> for Length {
   fn partial_cmp(&self, other: &Length) -> Option<std::cmp::Ordering> {
     match (self, other) {
@@ -5,8 +5,11 @@ use super::calc::{Calc, MathFunction};
 use super::number::CSSNumber;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::targets::Browsers;
 use crate::traits::private::AddInternal;
-use crate::traits::{impl_op, private::TryAdd, Op, Parse, Sign, ToCss, TryMap, TryOp, TrySign, Zero};
+use crate::traits::{
+  impl_op, private::TryAdd, FallbackValues, IsCompatible, Op, Parse, Sign, ToCss, TryMap, TryOp, TrySign, Zero,
+};
 use cssparser::*;
 
 /// A CSS [`<percentage>`](https://www.w3.org/TR/css-values-4/#percentages) value.
@@ -37,20 +40,26 @@ impl ToCss for Percentage {
     W: std::fmt::Write,
   {
     use cssparser::ToCss;
-    let int_value = if (self.0 * 100.0).fract() == 0.0 {
-      Some(self.0 as i32)
+    // Percentages are stored as a fraction, so round the printed value instead.
+    let value = if dest.precision.is_some() {
+      dest.round(self.0 * 100.0) / 100.0
+    } else {
+      self.0
+    };
+    let int_value = if (value * 100.0).fract() == 0.0 {
+      Some(value as i32)
     } else {
       None
     };
     let percent = Token::Percentage {
-      has_sign: self.0 < 0.0,
-      unit_value: self.0,
+      has_sign: value < 0.0,
+      unit_value: value,
       int_value,
     };
-    if self.0 != 0.0 && self.0.abs() < 0.01 {
+    if value != 0.0 && value.abs() < 0.01 {
       let mut s = String::new();
       percent.to_css(&mut s)?;
-      if self.0 < 0.0 {
+      if value < 0.0 {
         dest.write_char('-')?;
         dest.write_str(s.trim_start_matches("-0"))
       } else {
@@ -399,6 +408,37 @@ impl<D> std::convert::From<Calc<DimensionPercentage<D>>> for DimensionPercentage
   }
 }
 
+impl<D: Clone + std::cmp::PartialOrd<D> + FallbackValues> FallbackValues for DimensionPercentage<D> {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      DimensionPercentage::Dimension(d) => d
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(DimensionPercentage::Dimension)
+        .collect(),
+      DimensionPercentage::Calc(c) => c
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(|c| match c {
+          Calc::Value(v) => *v,
+          c => DimensionPercentage::Calc(Box::new(c)),
+        })
+        .collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
+impl<D: IsCompatible> IsCompatible for DimensionPercentage<D> {
+  fn is_compatible(&self, targets: Option<Browsers>) -> bool {
+    match self {
+      DimensionPercentage::Dimension(d) => d.is_compatible(targets),
+      DimensionPercentage::Percentage(..) => true,
+      DimensionPercentage::Calc(c) => c.is_compatible(targets),
+    }
+  }
+}
+
 impl<D: std::cmp::PartialOrd<D>> std::cmp::PartialOrd<DimensionPercentage<D>> for DimensionPercentage<D> {
   fn partial_cmp(&self, other: &DimensionPercentage<D>) -> Option<std::cmp::Ordering> {
     match (self, other) {